@@ -299,13 +299,13 @@ fn main() {
             alpha_den: denominator,
         },
         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-        centaur_technical_indicators::ConstantModelType::SimpleMovingMode,
+        centaur_technical_indicators::ConstantModelType::SimpleMovingMode { precision: 1.0 },
     ];
     let available_deviations = vec![
         centaur_technical_indicators::DeviationModel::StandardDeviation,
         centaur_technical_indicators::DeviationModel::MeanAbsoluteDeviation,
         centaur_technical_indicators::DeviationModel::MedianAbsoluteDeviation,
-        centaur_technical_indicators::DeviationModel::ModeAbsoluteDeviation,
+        centaur_technical_indicators::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
         centaur_technical_indicators::DeviationModel::UlcerIndex,
     ];
     let available_moving_averages = vec![
@@ -318,14 +318,62 @@ fn main() {
         },
     ];
 
+    // Bars
+    let timestamps: Vec<i64> = (0..close.len() as i64).collect();
+    let time_bars =
+        centaur_technical_indicators::bars::bulk::time_bars(&timestamps, &close, &volume, 5);
+    println!("Time Bars: {:?}", time_bars);
+
+    let tick_bars =
+        centaur_technical_indicators::bars::bulk::tick_bars(&timestamps, &close, &volume, period);
+    println!("Tick Bars: {:?}", tick_bars);
+
+    let average_volume = volume.iter().sum::<f64>() / volume.len() as f64;
+    let volume_bars = centaur_technical_indicators::bars::bulk::volume_bars(
+        &timestamps,
+        &close,
+        &volume,
+        average_volume * 5.0,
+    );
+    println!("Volume Bars: {:?}", volume_bars);
+
+    let dollar_bars = centaur_technical_indicators::bars::bulk::dollar_bars(
+        &timestamps,
+        &close,
+        &volume,
+        average_volume * 5.0 * close[0],
+    );
+    println!("Dollar Bars: {:?}", dollar_bars);
+
+    let range_bars =
+        centaur_technical_indicators::bars::bulk::range_bars(&timestamps, &close, &volume, 100.0);
+    println!("Range Bars: {:?}", range_bars);
+
+    let imbalance_bars = centaur_technical_indicators::bars::bulk::imbalance_bars(
+        &timestamps,
+        &close,
+        &volume,
+        average_volume * 3.0,
+    );
+    println!("Imbalance Bars: {:?}", imbalance_bars);
+
     // Basic indicators
     let median =
         centaur_technical_indicators::basic_indicators::bulk::median(&typical_price, period);
     println!("Median: {:?}", median);
 
-    let mode = centaur_technical_indicators::basic_indicators::bulk::mode(&typical_price, period);
+    let mode =
+        centaur_technical_indicators::basic_indicators::bulk::mode(&typical_price, period, 1.0);
     println!("Mode: {:?}", mode);
 
+    let kernel_density_mode =
+        centaur_technical_indicators::basic_indicators::bulk::kernel_density_mode(
+            &typical_price,
+            period,
+            None,
+        );
+    println!("Kernel Density Mode: {:?}", kernel_density_mode);
+
     let log = centaur_technical_indicators::basic_indicators::bulk::log(&typical_price);
     println!("Log: {:?}", log);
 
@@ -367,8 +415,8 @@ fn main() {
         &typical_price,
         period,
         AbsDevConfig {
-            center: CentralPoint::Mode,
-            aggregate: DeviationAggregate::Mode,
+            center: CentralPoint::Mode { precision: 1.0 },
+            aggregate: DeviationAggregate::Mode { precision: 1.0 },
         },
     );
     println!("Mode Absolute Deviation: {:?}", mode_ad);
@@ -407,6 +455,16 @@ fn main() {
                     period,
                 );
             println!("{:?} Band, {:?}: {:?}", model, deviation, bands);
+            if let Ok(bands) = bands {
+                let percent_b = centaur_technical_indicators::candle_indicators::bulk::percent_b(
+                    &typical_price,
+                    &bands,
+                );
+                let bandwidth =
+                    centaur_technical_indicators::candle_indicators::bulk::bandwidth(&bands);
+                println!("{:?} Band %B, {:?}: {:?}", model, deviation, percent_b);
+                println!("{:?} Bandwidth, {:?}: {:?}", model, deviation, bandwidth);
+            }
         }
     }
 
@@ -427,6 +485,12 @@ fn main() {
     );
     println!("Ichimoku cloud: {:?}", ichimoku_cloud);
 
+    let displaced_ichimoku_cloud =
+        centaur_technical_indicators::candle_indicators::bulk::displaced_ichimoku_cloud(
+            &high, &low, &close, 9, 26, 52, 26,
+        );
+    println!("Displaced Ichimoku cloud: {:?}", displaced_ichimoku_cloud);
+
     let donchian_channels =
         centaur_technical_indicators::candle_indicators::bulk::donchian_channels(
             &high, &low, period,
@@ -460,6 +524,125 @@ fn main() {
         println!("Supertrend {:?}: {:?}", model, supertrend);
     }
 
+    for model in &available_models {
+        for deviation in &available_deviations {
+            let squeeze = centaur_technical_indicators::candle_indicators::bulk::squeeze_momentum(
+                &high, &low, &close, *model, *deviation, 2.0, *model, 1.5, period,
+            );
+            println!("Squeeze {:?} {:?}: {:?}", model, deviation, squeeze);
+        }
+    }
+
+    for model in &available_models {
+        for source in [
+            centaur_technical_indicators::PriceSource::Close,
+            centaur_technical_indicators::PriceSource::MedianPrice,
+            centaur_technical_indicators::PriceSource::TypicalPrice,
+        ] {
+            let extended_supertrend =
+                centaur_technical_indicators::candle_indicators::bulk::extended_supertrend(
+                    &high, &low, &close, *model, multiplier, period, source,
+                );
+            println!(
+                "Extended Supertrend {:?} {:?}: {:?}",
+                model, source, extended_supertrend
+            );
+        }
+    }
+
+    // Correlation indicators
+    let assets = vec![close.clone(), high.clone(), low.clone()];
+    for model in &available_models {
+        for deviation in &available_deviations {
+            let correlation_matrix =
+                centaur_technical_indicators::correlation_indicators::bulk::correlation_matrix(
+                    &assets,
+                    *model,
+                    *deviation,
+                    long_period,
+                );
+            println!(
+                "{:?} {:?} Correlation Matrix: {:?}",
+                model, deviation, correlation_matrix
+            );
+        }
+        let covariance_matrix =
+            centaur_technical_indicators::correlation_indicators::bulk::covariance_matrix(
+                &assets,
+                *model,
+                long_period,
+            );
+        println!("{:?} Covariance Matrix: {:?}", model, covariance_matrix);
+    }
+
+    let ledoit_wolf =
+        centaur_technical_indicators::correlation_indicators::single::ledoit_wolf_covariance(
+            &assets,
+        );
+    println!("Ledoit-Wolf Covariance: {:?}", ledoit_wolf);
+
+    let pairwise = vec![
+        vec![1.0, 0.9, -0.9],
+        vec![0.9, 1.0, 0.9],
+        vec![-0.9, 0.9, 1.0],
+    ];
+    let psd =
+        centaur_technical_indicators::correlation_indicators::single::is_positive_semi_definite(
+            &pairwise, 1e-10,
+        );
+    println!("Positive Semi-Definite: {:?}", psd);
+
+    let nearest =
+        centaur_technical_indicators::correlation_indicators::single::nearest_correlation_matrix(
+            &pairwise, 100, 1e-10,
+        );
+    println!("Nearest Correlation Matrix: {:?}", nearest);
+
+    // Kalman filter
+    let local_level =
+        centaur_technical_indicators::kalman_filter::bulk::local_level(&close, 1.0, 10.0);
+    println!("Kalman Local Level: {:?}", local_level);
+
+    let local_trend =
+        centaur_technical_indicators::kalman_filter::bulk::local_trend(&close, 1.0, 0.1, 10.0);
+    println!("Kalman Local Trend: {:?}", local_trend);
+
+    let kalman_hedge_ratio = centaur_technical_indicators::kalman_filter::bulk::hedge_ratio(
+        &close,
+        &typical_price,
+        0.0001,
+        0.001,
+    );
+    println!("Kalman Hedge Ratio: {:?}", kalman_hedge_ratio);
+
+    // Microstructure
+    let trade_sides =
+        centaur_technical_indicators::microstructure::bulk::tick_rule(&close).unwrap();
+    println!("Tick Rule: {:?}", trade_sides);
+
+    let volume_delta = centaur_technical_indicators::microstructure::bulk::cumulative_volume_delta(
+        &volume,
+        &trade_sides,
+    );
+    println!("Cumulative Volume Delta: {:?}", volume_delta);
+
+    let vpin = centaur_technical_indicators::microstructure::bulk::vpin(
+        &volume,
+        &trade_sides,
+        volume.iter().sum::<f64>() / 20.0,
+        period,
+    );
+    println!("VPIN: {:?}", vpin);
+
+    let amihud = centaur_technical_indicators::microstructure::bulk::amihud_illiquidity(
+        &close, &volume, period,
+    );
+    println!("Amihud Illiquidity: {:?}", amihud);
+
+    let roll_spread =
+        centaur_technical_indicators::microstructure::bulk::roll_spread(&close, long_period);
+    println!("Roll Spread: {:?}", roll_spread);
+
     // Momentum indicators
     for model in &available_models {
         let rsi = centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
@@ -626,6 +809,95 @@ fn main() {
     );
     println!("Chande Momentum Oscillator: {:?}", cmo);
 
+    for model in &available_models {
+        let stochastic_rsi =
+            centaur_technical_indicators::momentum_indicators::bulk::stochastic_rsi(
+                &close, *model, period, period,
+            );
+        println!("{:?} Stochastic RSI: {:?}", model, stochastic_rsi);
+
+        let awesome_oscillator =
+            centaur_technical_indicators::momentum_indicators::bulk::awesome_oscillator(
+                &high,
+                &low,
+                period,
+                long_period,
+                *model,
+            );
+        println!("{:?} Awesome Oscillator: {:?}", model, awesome_oscillator);
+
+        let accelerator_oscillator =
+            centaur_technical_indicators::momentum_indicators::bulk::accelerator_oscillator(
+                &high,
+                &low,
+                period,
+                long_period,
+                period,
+                *model,
+            );
+        println!(
+            "{:?} Accelerator Oscillator: {:?}",
+            model, accelerator_oscillator
+        );
+
+        let connors_rsi = centaur_technical_indicators::momentum_indicators::bulk::connors_rsi(
+            &close,
+            *model,
+            3,
+            2,
+            long_period,
+        );
+        println!("{:?} Connors RSI: {:?}", model, connors_rsi);
+    }
+
+    let ultimate_oscillator =
+        centaur_technical_indicators::momentum_indicators::bulk::ultimate_oscillator(
+            &high,
+            &low,
+            &close,
+            period,
+            period * 2,
+            long_period,
+        );
+    println!("Ultimate Oscillator: {:?}", ultimate_oscillator);
+
+    for model in &available_models {
+        let trix =
+            centaur_technical_indicators::momentum_indicators::bulk::trix(&close, period, *model);
+        println!("{:?} TRIX: {:?}", model, trix);
+
+        let know_sure_thing =
+            centaur_technical_indicators::momentum_indicators::bulk::know_sure_thing(
+                &close,
+                [10, 15, 20, 30],
+                [10, 10, 10, 15],
+                *model,
+            );
+        println!("{:?} Know Sure Thing: {:?}", model, know_sure_thing);
+
+        let coppock_curve = centaur_technical_indicators::momentum_indicators::bulk::coppock_curve(
+            &close, 14, 11, 10, *model,
+        );
+        println!("{:?} Coppock Curve: {:?}", model, coppock_curve);
+
+        let detrended_price_oscillator =
+            centaur_technical_indicators::momentum_indicators::bulk::detrended_price_oscillator(
+                &close,
+                long_period,
+                *model,
+            );
+        println!(
+            "{:?} Detrended Price Oscillator: {:?}",
+            model, detrended_price_oscillator
+        );
+
+        let schaff_trend_cycle =
+            centaur_technical_indicators::momentum_indicators::bulk::schaff_trend_cycle(
+                &close, 23, 50, 10, 3, *model,
+            );
+        println!("{:?} Schaff Trend Cycle: {:?}", model, schaff_trend_cycle);
+    }
+
     // Moving Averages
     for moving_average in &available_moving_averages {
         let ma = centaur_technical_indicators::moving_average::bulk::moving_average(
@@ -671,6 +943,124 @@ fn main() {
         println!("{:?} Positivity Index: {:?}", model, pi);
     }
 
+    // Pairs
+    let (hedge_ratio, intercept) =
+        centaur_technical_indicators::pairs::single::hedge_ratio(&close, &typical_price).unwrap();
+    println!("Hedge Ratio: {:?}, Intercept: {:?}", hedge_ratio, intercept);
+
+    let rolling_hedge_ratio =
+        centaur_technical_indicators::pairs::bulk::hedge_ratio(&close, &typical_price, long_period);
+    println!("Rolling Hedge Ratio: {:?}", rolling_hedge_ratio);
+
+    let spread = centaur_technical_indicators::pairs::bulk::spread(
+        &close,
+        &typical_price,
+        hedge_ratio,
+        intercept,
+    )
+    .unwrap();
+    println!("Spread: {:?}", spread);
+
+    for model in &available_models {
+        for deviation in &available_deviations {
+            let z_score = centaur_technical_indicators::pairs::bulk::spread_z_score(
+                &spread,
+                *model,
+                *deviation,
+                long_period,
+            );
+            println!("{:?} {:?} Spread Z-Score: {:?}", model, deviation, z_score);
+        }
+    }
+
+    let half_life = centaur_technical_indicators::pairs::bulk::half_life(&spread, long_period);
+    println!("Half-Life: {:?}", half_life);
+
+    let adf = centaur_technical_indicators::pairs::single::augmented_dickey_fuller(&spread, 1);
+    println!("Augmented Dickey-Fuller: {:?}", adf);
+
+    let engle_granger =
+        centaur_technical_indicators::pairs::single::engle_granger(&close, &typical_price, 1);
+    println!("Engle-Granger: {:?}", engle_granger);
+
+    // Regression indicators
+    let regression_slope =
+        centaur_technical_indicators::regression_indicators::bulk::slope(&close, period);
+    println!("Regression Slope: {:?}", regression_slope);
+
+    let regression_intercept =
+        centaur_technical_indicators::regression_indicators::bulk::intercept(&close, period);
+    println!("Regression Intercept: {:?}", regression_intercept);
+
+    let r_squared =
+        centaur_technical_indicators::regression_indicators::bulk::r_squared(&close, period);
+    println!("R Squared: {:?}", r_squared);
+
+    let forecast =
+        centaur_technical_indicators::regression_indicators::bulk::forecast(&close, period, 1);
+    println!("Time Series Forecast: {:?}", forecast);
+
+    let standard_error_bands =
+        centaur_technical_indicators::regression_indicators::bulk::standard_error_bands(
+            &close, period, 2.0,
+        );
+    println!("Standard Error Bands: {:?}", standard_error_bands);
+
+    let regression_channel =
+        centaur_technical_indicators::regression_indicators::bulk::regression_channel(
+            &close, period,
+        );
+    println!("Regression Channel: {:?}", regression_channel);
+
+    // Sessions
+    let session = centaur_technical_indicators::sessions::Session::default();
+    let daily_timestamps: Vec<i64> = timestamps
+        .iter()
+        .map(|day| day * centaur_technical_indicators::sessions::SECONDS_PER_DAY)
+        .collect();
+    let sessions =
+        centaur_technical_indicators::sessions::bulk::split_by_session(&daily_timestamps, &session);
+    println!("Sessions: {:?}", sessions);
+
+    let session_gaps = centaur_technical_indicators::sessions::bulk::session_gaps(
+        &daily_timestamps,
+        &open,
+        &close,
+        &session,
+    );
+    println!("Session Gaps: {:?}", session_gaps);
+
+    let session_vwap = centaur_technical_indicators::sessions::bulk::session_vwap(
+        &daily_timestamps,
+        &high,
+        &low,
+        &close,
+        &volume,
+        &session,
+    );
+    println!("Session VWAP: {:?}", session_vwap);
+
+    let session_pivots = centaur_technical_indicators::sessions::bulk::session_pivots(
+        &daily_timestamps,
+        &high,
+        &low,
+        &close,
+        &session,
+    );
+    println!("Session Pivots: {:?}", session_pivots);
+
+    let session_change = centaur_technical_indicators::sessions::bulk::anchor_to_session(
+        &daily_timestamps,
+        &open,
+        &high,
+        &low,
+        &close,
+        &volume,
+        &session,
+        |bars| Ok(bars.close.iter().map(|c| c - bars.open[0]).collect()),
+    );
+    println!("Change Since Session Open: {:?}", session_change);
+
     // Strength indicators
     let ad = centaur_technical_indicators::strength_indicators::bulk::accumulation_distribution(
         &high, &low, &close, &volume, 0.0,
@@ -704,6 +1094,96 @@ fn main() {
         }
     }
 
+    let chaikin_money_flow =
+        centaur_technical_indicators::strength_indicators::bulk::chaikin_money_flow(
+            &high,
+            &low,
+            &close,
+            &volume,
+            long_period,
+        );
+    println!("Chaikin Money Flow: {:?}", chaikin_money_flow);
+
+    for model in &available_models {
+        let force_index = centaur_technical_indicators::strength_indicators::bulk::force_index(
+            &close, &volume, period, *model,
+        );
+        println!("{:?} Force Index: {:?}", model, force_index);
+
+        let elder_ray = centaur_technical_indicators::strength_indicators::bulk::elder_ray(
+            &high, &low, &close, period, *model,
+        );
+        println!("{:?} Elder Ray: {:?}", model, elder_ray);
+
+        let ease_of_movement =
+            centaur_technical_indicators::strength_indicators::bulk::ease_of_movement(
+                &high,
+                &low,
+                &volume,
+                100_000_000.0,
+                period,
+                *model,
+            );
+        println!("{:?} Ease of Movement: {:?}", model, ease_of_movement);
+
+        let klinger_volume_oscillator =
+            centaur_technical_indicators::strength_indicators::bulk::klinger_volume_oscillator(
+                &high,
+                &low,
+                &close,
+                &volume,
+                period,
+                long_period,
+                *model,
+            );
+        println!(
+            "{:?} Klinger Volume Oscillator: {:?}",
+            model, klinger_volume_oscillator
+        );
+
+        let volume_oscillator =
+            centaur_technical_indicators::strength_indicators::bulk::volume_oscillator(
+                &volume,
+                period,
+                long_period,
+                *model,
+            );
+        println!("{:?} Volume Oscillator: {:?}", model, volume_oscillator);
+    }
+
+    // Timeframes
+    let weekly = centaur_technical_indicators::timeframes::bulk::resample(
+        &timestamps,
+        &open,
+        &high,
+        &low,
+        &close,
+        &volume,
+        5,
+    );
+    println!("Resampled: {:?}", weekly);
+
+    let weekly_moving_average = centaur_technical_indicators::timeframes::bulk::multi_timeframe(
+        &timestamps,
+        &open,
+        &high,
+        &low,
+        &close,
+        &volume,
+        5,
+        |bars| {
+            centaur_technical_indicators::moving_average::bulk::moving_average(
+                &bars.close,
+                centaur_technical_indicators::MovingAverageType::Simple,
+                period,
+            )
+        },
+    );
+    println!(
+        "Multi Timeframe Moving Average: {:?}",
+        weekly_moving_average
+    );
+
     // Trend indicators
     let aroon_indicator =
         centaur_technical_indicators::trend_indicators::bulk::aroon_indicator(&high, &low, period);
@@ -760,6 +1240,35 @@ fn main() {
         }
     }
 
+    let vortex = centaur_technical_indicators::trend_indicators::bulk::vortex_indicator(
+        &high, &low, &close, period,
+    );
+    println!("Vortex Indicator: {:?}", vortex);
+
+    let choppiness_index = centaur_technical_indicators::trend_indicators::bulk::choppiness_index(
+        &high, &low, &close, period,
+    );
+    println!("Choppiness Index: {:?}", choppiness_index);
+
+    for model in &available_models {
+        let mass_index = centaur_technical_indicators::trend_indicators::bulk::mass_index(
+            &high, &low, 9, 25, *model,
+        );
+        println!("{:?} Mass Index: {:?}", model, mass_index);
+
+        let balance_of_power =
+            centaur_technical_indicators::trend_indicators::bulk::balance_of_power(
+                &open, &high, &low, &close, period, *model,
+            );
+        println!("{:?} Balance of Power: {:?}", model, balance_of_power);
+
+        let random_walk_index =
+            centaur_technical_indicators::trend_indicators::bulk::random_walk_index(
+                &high, &low, &close, period, *model,
+            );
+        println!("{:?} Random Walk Index: {:?}", model, random_walk_index);
+    }
+
     // Volatility indicators
     let ulcer_index = centaur_technical_indicators::volatility_indicators::bulk::ulcer_index(
         &typical_price,
@@ -778,8 +1287,59 @@ fn main() {
             *model,
         );
         println!("{:?} Volatility System: {:?}", model, vs);
+
+        let chandelier_exit =
+            centaur_technical_indicators::volatility_indicators::bulk::chandelier_exit(
+                &high,
+                &low,
+                &close,
+                long_period,
+                constant_multiplier,
+                *model,
+            );
+        println!("{:?} Chandelier Exit: {:?}", model, chandelier_exit);
+
+        let chande_kroll_stop =
+            centaur_technical_indicators::volatility_indicators::bulk::chande_kroll_stop(
+                &high, &low, &close, 10, 1.0, 9, *model,
+            );
+        println!("{:?} Chande Kroll Stop: {:?}", model, chande_kroll_stop);
+
+        let atr_trailing_stop =
+            centaur_technical_indicators::volatility_indicators::bulk::atr_trailing_stop(
+                &high,
+                &low,
+                &close,
+                period,
+                constant_multiplier,
+                *model,
+            );
+        println!("{:?} ATR Trailing Stop: {:?}", model, atr_trailing_stop);
     }
 
+    // Chart transforms
+    let heikin_ashi = centaur_technical_indicators::chart_transforms::bulk::heikin_ashi(
+        &open, &high, &low, &close,
+    );
+    println!("Heikin-Ashi: {:?}", heikin_ashi);
+
+    let renko = centaur_technical_indicators::chart_transforms::bulk::renko(&close, 50.0);
+    println!("Renko: {:?}", renko);
+
+    for model in &available_models {
+        let renko_atr = centaur_technical_indicators::chart_transforms::bulk::renko_atr(
+            &close, &high, &low, *model, period,
+        );
+        println!("{:?} Renko ATR: {:?}", model, renko_atr);
+    }
+
+    let kagi = centaur_technical_indicators::chart_transforms::bulk::kagi(&close, 50.0);
+    println!("Kagi: {:?}", kagi);
+
+    let point_and_figure =
+        centaur_technical_indicators::chart_transforms::bulk::point_and_figure(&close, 25.0, 3);
+    println!("Point and Figure: {:?}", point_and_figure);
+
     // Chart trends (truncated, see documentation for argument meaning)
     let trend_break_config =
         centaur_technical_indicators::chart_trends::TrendBreakConfig::default();
@@ -787,12 +1347,105 @@ fn main() {
         centaur_technical_indicators::chart_trends::break_down_trends(&close, trend_break_config);
     println!("Broken down trends: {:?}", break_down_trends);
 
+    let trend_segments =
+        centaur_technical_indicators::chart_trends::break_down_trends_with_diagnostics(
+            &close,
+            trend_break_config,
+        );
+    println!("Trend segments with diagnostics: {:?}", trend_segments);
+
     let valleys = centaur_technical_indicators::chart_trends::valleys(&close, 30, 5);
     println!("Valleys: {:?}", valleys);
 
     let peaks = centaur_technical_indicators::chart_trends::peaks(&close, 30, 5);
     println!("Peaks: {:?}", peaks);
 
+    let peak_config = centaur_technical_indicators::chart_trends::PeakConfig {
+        prominence: Some(50.0),
+        distance: Some(5),
+        ..Default::default()
+    };
+    let prominent_peaks =
+        centaur_technical_indicators::chart_trends::find_peaks(&close, peak_config);
+    println!("Prominent peaks: {:?}", prominent_peaks);
+
+    let prominent_valleys =
+        centaur_technical_indicators::chart_trends::find_valleys(&close, peak_config);
+    println!("Prominent valleys: {:?}", prominent_valleys);
+
+    let peak_trend =
+        centaur_technical_indicators::chart_trends::peak_trend_with_config(&close, peak_config);
+    println!("Prominent peak trend: {:?}", peak_trend);
+
+    let valley_trend =
+        centaur_technical_indicators::chart_trends::valley_trend_with_config(&close, peak_config);
+    println!("Prominent valley trend: {:?}", valley_trend);
+
+    let zigzag = centaur_technical_indicators::chart_trends::zigzag(
+        &high,
+        &low,
+        &close,
+        centaur_technical_indicators::chart_trends::ZigZagThreshold::AverageTrueRange {
+            period: 5,
+            multiplier: 3.0,
+        },
+        centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    )
+    .unwrap();
+    println!("ZigZag swings: {:?}", zigzag);
+
+    let structure_breaks =
+        centaur_technical_indicators::chart_trends::break_of_structure(&close, &zigzag);
+    println!("Breaks of structure: {:?}", structure_breaks);
+
+    let zones = centaur_technical_indicators::chart_trends::support_resistance_zones(
+        &high, &low, None, 5, 1, 25.0,
+    );
+    println!("Support and resistance zones: {:?}", zones);
+
+    let trend_lines = centaur_technical_indicators::chart_trends::break_down_trends(
+        &close,
+        centaur_technical_indicators::chart_trends::TrendBreakConfig::default(),
+    )
+    .unwrap();
+    let trend_line_touches = centaur_technical_indicators::chart_trends::trend_line_touches(
+        &high,
+        &low,
+        None,
+        &trend_lines,
+        5,
+        1,
+        0.005,
+    );
+    println!("Trend line touches: {:?}", trend_line_touches);
+
+    // Chart patterns
+    let chart_patterns = centaur_technical_indicators::chart_patterns::find_chart_patterns(
+        &high,
+        &low,
+        &close,
+        centaur_technical_indicators::chart_patterns::ChartPatternConfig::default(),
+    );
+    println!("Chart patterns: {:?}", chart_patterns);
+
+    // Change points
+    let cusum = centaur_technical_indicators::change_points::cusum(&close, 50.0, 5.0);
+    println!("CUSUM segments: {:?}", cusum);
+
+    let penalty = 2.0 * (close.len() as f64).ln();
+    for cost in [
+        centaur_technical_indicators::change_points::ChangePointCost::Mean,
+        centaur_technical_indicators::change_points::ChangePointCost::Variance,
+        centaur_technical_indicators::change_points::ChangePointCost::LinearTrend,
+    ] {
+        let pelt = centaur_technical_indicators::change_points::pelt(&close, cost, penalty, 5);
+        println!("PELT {:?} segments: {:?}", cost, pelt);
+    }
+
+    let bayesian_online =
+        centaur_technical_indicators::change_points::bayesian_online(&close, 1.0 / 250.0, 20);
+    println!("Bayesian online segments: {:?}", bayesian_online);
+
     let elapsed = now.elapsed();
     println!("\nElapsed: {:.2?}", elapsed);
 }
@@ -0,0 +1,699 @@
+//! # Bars
+//!
+//! The `bars` module aggregates raw trades into OHLCV bars so that every other
+//! indicator in the crate can be run on tick data.
+//!
+//! ## When to Use
+//! Use bar functions when you want to:
+//! - Build classic time bars from trades
+//! - Sample by activity instead of time (tick, volume and dollar bars)
+//! - Sample by price movement (range bars) or by order flow (imbalance bars)
+//! - Build bars live, one trade at a time, with [`BarBuilder`]
+//!
+//! ## Structure
+//! - [`BarBuilder`]: Streaming builder that emits a [`Bar`] whenever one completes.
+//! - **bulk**: Functions that aggregate a slice of trades and return [`Bars`].
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`dollar_bars`](bulk::dollar_bars): Bars of equal traded value
+//! - [`imbalance_bars`](bulk::imbalance_bars): Bars of equal signed volume imbalance
+//! - [`range_bars`](bulk::range_bars): Bars of equal high-low range
+//! - [`tick_bars`](bulk::tick_bars): Bars of equal trade count
+//! - [`time_bars`](bulk::time_bars): Bars of equal duration
+//! - [`volume_bars`](bulk::volume_bars): Bars of equal traded volume
+//!
+//! ## API Details
+//! - Trades are given as aligned slices of timestamps, prices and sizes. Timestamps are integers
+//!   in any unit (seconds, milliseconds, ...) and must not decrease.
+//! - Trades are never split: the trade that reaches a threshold is the last trade of its bar.
+//! - Time bars are aligned to multiples of the interval; intervals without trades produce no bar.
+//! - [`Bars`] holds one vector per field so `high`, `low`, `close` and `volume` can be passed
+//!   straight to the other indicators.
+//! - The bulk functions return the last bar even if it is incomplete.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+use crate::microstructure::single::{signed, tick_rule};
+use crate::validation::{assert_non_negative, assert_positive, assert_positive_usize};
+use crate::{TechnicalIndicatorError, TradeSide};
+
+/// Rule that decides when a bar is complete.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BarType {
+    /// Bars covering `interval` timestamp units, aligned to multiples of `interval`
+    Time { interval: i64 },
+    /// Bars of `count` trades
+    Tick { count: usize },
+    /// Bars closing once the traded size reaches `threshold`
+    Volume { threshold: f64 },
+    /// Bars closing once the traded value (price * size) reaches `threshold`
+    Dollar { threshold: f64 },
+    /// Bars closing once the high-low range reaches `range`
+    Range { range: f64 },
+    /// Bars closing once the absolute tick rule signed volume reaches `threshold`
+    Imbalance { threshold: f64 },
+}
+
+/// A single OHLCV bar.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Bar {
+    /// Start of the bar: the interval start for time bars, the first trade otherwise
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    /// Number of trades in the bar (or of source bars when resampling)
+    pub trades: usize,
+}
+
+/// A series of bars stored as one vector per field.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bars {
+    pub timestamps: Vec<i64>,
+    pub open: Vec<f64>,
+    pub high: Vec<f64>,
+    pub low: Vec<f64>,
+    pub close: Vec<f64>,
+    pub volume: Vec<f64>,
+    pub trades: Vec<usize>,
+}
+
+impl Bars {
+    /// Appends a bar to the series
+    pub fn push(&mut self, bar: Bar) {
+        self.timestamps.push(bar.timestamp);
+        self.open.push(bar.open);
+        self.high.push(bar.high);
+        self.low.push(bar.low);
+        self.close.push(bar.close);
+        self.volume.push(bar.volume);
+        self.trades.push(bar.trades);
+    }
+
+    /// Number of bars in the series
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    /// Whether the series has no bars
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+}
+
+impl FromIterator<Bar> for Bars {
+    fn from_iter<I: IntoIterator<Item = Bar>>(iter: I) -> Self {
+        let mut bars = Bars::default();
+        for bar in iter {
+            bars.push(bar);
+        }
+        bars
+    }
+}
+
+/// Streaming bar builder for live trades.
+///
+/// Feed trades in order with [`update`](BarBuilder::update); a completed bar is
+/// returned as soon as it closes. Time bars close when the first trade of the
+/// next interval arrives, every other bar type closes on the trade that reaches
+/// its threshold.
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::bars::{BarBuilder, BarType};
+///
+/// let mut builder = BarBuilder::new(BarType::Tick { count: 2 }).unwrap();
+/// assert_eq!(None, builder.update(1, 100.0, 5.0).unwrap());
+///
+/// let bar = builder.update(2, 101.0, 3.0).unwrap().unwrap();
+/// assert_eq!((100.0, 101.0, 8.0), (bar.open, bar.close, bar.volume));
+/// assert_eq!(None, builder.flush());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarBuilder {
+    bar_type: BarType,
+    current: Option<Bar>,
+    imbalance: f64,
+    value: f64,
+    last_timestamp: Option<i64>,
+    last_price: f64,
+    last_side: TradeSide,
+}
+
+impl BarBuilder {
+    /// Creates a builder for the given bar type
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval, count, threshold or range is not positive
+    pub fn new(bar_type: BarType) -> crate::Result<Self> {
+        match bar_type {
+            BarType::Time { interval } => {
+                if interval <= 0 {
+                    return Err(TechnicalIndicatorError::InvalidValue {
+                        name: "interval".to_string(),
+                        value: interval as f64,
+                        reason: "must be positive".to_string(),
+                    });
+                }
+            }
+            BarType::Tick { count } => assert_positive_usize("count", count)?,
+            BarType::Volume { threshold }
+            | BarType::Dollar { threshold }
+            | BarType::Imbalance { threshold } => assert_positive("threshold", threshold)?,
+            BarType::Range { range } => assert_positive("range", range)?,
+        }
+        Ok(Self {
+            bar_type,
+            current: None,
+            imbalance: 0.0,
+            value: 0.0,
+            last_timestamp: None,
+            last_price: 0.0,
+            last_side: TradeSide::Unknown,
+        })
+    }
+
+    /// Adds a trade and returns the bar it completed, if any
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - Trade timestamp
+    /// * `price` - Trade price
+    /// * `size` - Trade size
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamp` is earlier than the previous trade
+    /// * `price` <= 0.0
+    /// * `size` < 0.0
+    pub fn update(&mut self, timestamp: i64, price: f64, size: f64) -> crate::Result<Option<Bar>> {
+        if let Some(last_timestamp) = self.last_timestamp {
+            if timestamp < last_timestamp {
+                return Err(TechnicalIndicatorError::InvalidValue {
+                    name: "timestamp".to_string(),
+                    value: timestamp as f64,
+                    reason: format!(
+                        "must not be earlier than the previous trade ({})",
+                        last_timestamp
+                    ),
+                });
+            }
+        }
+        assert_positive("price", price)?;
+        assert_non_negative("size", size)?;
+
+        let side = match self.last_timestamp {
+            Some(_) => tick_rule(price, self.last_price, self.last_side),
+            None => TradeSide::Unknown,
+        };
+        self.last_timestamp = Some(timestamp);
+        self.last_price = price;
+        self.last_side = side;
+
+        let mut completed = None;
+        if let BarType::Time { interval } = self.bar_type {
+            let start = timestamp.div_euclid(interval) * interval;
+            if self.current.is_some_and(|bar| bar.timestamp != start) {
+                completed = self.close_bar();
+            }
+        }
+
+        let bar = self.current.get_or_insert(Bar {
+            timestamp: match self.bar_type {
+                BarType::Time { interval } => timestamp.div_euclid(interval) * interval,
+                _ => timestamp,
+            },
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: 0.0,
+            trades: 0,
+        });
+        bar.high = bar.high.max(price);
+        bar.low = bar.low.min(price);
+        bar.close = price;
+        bar.volume += size;
+        bar.trades += 1;
+        self.imbalance += signed(size, side);
+        self.value += price * size;
+
+        let closed = match self.bar_type {
+            BarType::Time { .. } => false,
+            BarType::Tick { count } => bar.trades >= count,
+            BarType::Volume { threshold } => bar.volume >= threshold,
+            BarType::Dollar { threshold } => self.value >= threshold,
+            BarType::Range { range } => bar.high - bar.low >= range,
+            BarType::Imbalance { threshold } => self.imbalance.abs() >= threshold,
+        };
+        if closed {
+            completed = self.close_bar();
+        }
+        Ok(completed)
+    }
+
+    /// Returns the bar currently being built, if any
+    pub fn current(&self) -> Option<Bar> {
+        self.current
+    }
+
+    /// Closes and returns the bar currently being built, complete or not
+    pub fn flush(&mut self) -> Option<Bar> {
+        self.close_bar()
+    }
+
+    fn close_bar(&mut self) -> Option<Bar> {
+        self.imbalance = 0.0;
+        self.value = 0.0;
+        self.current.take()
+    }
+}
+
+/// **bulk**: Functions that aggregate a slice of trades and return [`Bars`].
+pub mod bulk {
+    use crate::bars::{BarBuilder, BarType, Bars};
+    use crate::validation::{assert_non_empty, assert_same_len};
+    use crate::TechnicalIndicatorError;
+
+    /// Aggregates trades into time bars
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `interval` - Bar duration in timestamp units
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] with one bar per interval that contains trades
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `interval` <= 0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::time_bars(
+    ///     &timestamps, &prices, &sizes, 60
+    /// ).unwrap();
+    /// assert_eq!(vec![0, 60, 120], bars.timestamps);
+    /// assert_eq!(vec![102.0, 101.0, 100.0], bars.high);
+    /// assert_eq!(vec![8.0, 2.0, 5.0], bars.volume);
+    /// ```
+    pub fn time_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        interval: i64,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Time { interval })
+    }
+
+    /// Aggregates trades into bars of `count` trades
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `count` - Number of trades per bar
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] of `count` trades each (the last bar may have fewer)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `count` == 0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::tick_bars(
+    ///     &timestamps, &prices, &sizes, 2
+    /// ).unwrap();
+    /// assert_eq!(vec![0, 70, 150], bars.timestamps);
+    /// assert_eq!(vec![102.0, 99.0, 100.0], bars.close);
+    /// ```
+    pub fn tick_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        count: usize,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Tick { count })
+    }
+
+    /// Aggregates trades into bars of at least `threshold` traded size
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `threshold` - Size at which a bar closes
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] closing on the trade that takes their volume to `threshold` or more
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `threshold` <= 0.0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::volume_bars(
+    ///     &timestamps, &prices, &sizes, 6.0
+    /// ).unwrap();
+    /// assert_eq!(vec![8.0, 6.0, 1.0], bars.volume);
+    /// ```
+    pub fn volume_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        threshold: f64,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Volume { threshold })
+    }
+
+    /// Aggregates trades into bars of at least `threshold` traded value
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `threshold` - Traded value (price * size) at which a bar closes
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] closing on the trade that takes their traded value to `threshold` or more
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `threshold` <= 0.0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::dollar_bars(
+    ///     &timestamps, &prices, &sizes, 500.0
+    /// ).unwrap();
+    /// assert_eq!(vec![0, 20, 130], bars.timestamps);
+    /// ```
+    pub fn dollar_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        threshold: f64,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Dollar { threshold })
+    }
+
+    /// Aggregates trades into bars spanning at least `range` from high to low
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `range` - High-low range at which a bar closes
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] closing on the trade that takes their range to `range` or more
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `range` <= 0.0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::range_bars(
+    ///     &timestamps, &prices, &sizes, 2.0
+    /// ).unwrap();
+    /// assert_eq!(vec![100.0, 101.0, 100.0], bars.open);
+    /// assert_eq!(vec![102.0, 99.0, 100.0], bars.close);
+    /// ```
+    pub fn range_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        range: f64,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Range { range })
+    }
+
+    /// Aggregates trades into bars of at least `threshold` signed volume imbalance
+    ///
+    /// Trades are signed with the tick rule (the first trade is unsigned) and a bar
+    /// closes once the absolute sum of its signed sizes reaches `threshold`.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of trade timestamps
+    /// * `prices` - Slice of trade prices
+    /// * `sizes` - Slice of trade sizes
+    /// * `threshold` - Absolute signed volume at which a bar closes
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] closing on the trade that takes their imbalance to `threshold` or more
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `threshold` <= 0.0
+    /// * Timestamps decrease, a price is <= 0.0, or a size is < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 20, 70, 130, 150];
+    /// let prices = vec![100.0, 102.0, 101.0, 99.0, 100.0];
+    /// let sizes = vec![5.0, 3.0, 2.0, 4.0, 1.0];
+    ///
+    /// let bars = centaur_technical_indicators::bars::bulk::imbalance_bars(
+    ///     &timestamps, &prices, &sizes, 3.0
+    /// ).unwrap();
+    /// assert_eq!(vec![0, 70, 150], bars.timestamps);
+    /// ```
+    pub fn imbalance_bars(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        threshold: f64,
+    ) -> crate::Result<Bars> {
+        build(timestamps, prices, sizes, BarType::Imbalance { threshold })
+    }
+
+    /// Runs a [`BarBuilder`] over a slice of trades, keeping the last partial bar
+    fn build(
+        timestamps: &[i64],
+        prices: &[f64],
+        sizes: &[f64],
+        bar_type: BarType,
+    ) -> crate::Result<Bars> {
+        assert_same_len(&[("prices", prices), ("sizes", sizes)])?;
+        assert_non_empty("prices", prices)?;
+        if timestamps.len() != prices.len() {
+            return Err(TechnicalIndicatorError::MismatchedLength {
+                names: vec![
+                    ("timestamps".to_string(), timestamps.len()),
+                    ("prices".to_string(), prices.len()),
+                ],
+            });
+        }
+        let mut builder = BarBuilder::new(bar_type)?;
+        let mut bars = Bars::default();
+        for i in 0..prices.len() {
+            if let Some(bar) = builder.update(timestamps[i], prices[i], sizes[i])? {
+                bars.push(bar);
+            }
+        }
+        if let Some(bar) = builder.flush() {
+            bars.push(bar);
+        }
+        Ok(bars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMESTAMPS: [i64; 5] = [0, 20, 70, 130, 150];
+    const PRICES: [f64; 5] = [100.0, 102.0, 101.0, 99.0, 100.0];
+    const SIZES: [f64; 5] = [5.0, 3.0, 2.0, 4.0, 1.0];
+
+    #[test]
+    fn builder_time_bars_close_on_next_interval() {
+        let mut builder = BarBuilder::new(BarType::Time { interval: 60 }).unwrap();
+        assert_eq!(None, builder.update(0, 100.0, 5.0).unwrap());
+        assert_eq!(None, builder.update(20, 102.0, 3.0).unwrap());
+        let bar = builder.update(70, 101.0, 2.0).unwrap().unwrap();
+        assert_eq!(
+            Bar {
+                timestamp: 0,
+                open: 100.0,
+                high: 102.0,
+                low: 100.0,
+                close: 102.0,
+                volume: 8.0,
+                trades: 2,
+            },
+            bar
+        );
+        assert_eq!(60, builder.current().unwrap().timestamp);
+    }
+
+    #[test]
+    fn builder_time_bars_negative_timestamps() {
+        let mut builder = BarBuilder::new(BarType::Time { interval: 60 }).unwrap();
+        builder.update(-30, 100.0, 1.0).unwrap();
+        assert_eq!(-60, builder.current().unwrap().timestamp);
+    }
+
+    #[test]
+    fn builder_flush_empty() {
+        let mut builder = BarBuilder::new(BarType::Volume { threshold: 10.0 }).unwrap();
+        assert_eq!(None, builder.flush());
+    }
+
+    #[test]
+    fn builder_invalid_bar_type() {
+        assert!(BarBuilder::new(BarType::Time { interval: 0 }).is_err());
+        assert!(BarBuilder::new(BarType::Tick { count: 0 }).is_err());
+        assert!(BarBuilder::new(BarType::Volume { threshold: 0.0 }).is_err());
+        assert!(BarBuilder::new(BarType::Dollar { threshold: -1.0 }).is_err());
+        assert!(BarBuilder::new(BarType::Range { range: 0.0 }).is_err());
+        assert!(BarBuilder::new(BarType::Imbalance { threshold: 0.0 }).is_err());
+    }
+
+    #[test]
+    fn builder_decreasing_timestamp_error() {
+        let mut builder = BarBuilder::new(BarType::Tick { count: 3 }).unwrap();
+        builder.update(10, 100.0, 1.0).unwrap();
+        assert!(builder.update(9, 100.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn builder_invalid_trade_error() {
+        let mut builder = BarBuilder::new(BarType::Tick { count: 3 }).unwrap();
+        assert!(builder.update(10, 0.0, 1.0).is_err());
+        assert!(builder.update(10, 100.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn bars_from_iterator() {
+        let bar = Bar {
+            timestamp: 5,
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 10.0,
+            trades: 3,
+        };
+        let bars: Bars = vec![bar, bar].into_iter().collect();
+        assert_eq!(2, bars.len());
+        assert_eq!(vec![0.5, 0.5], bars.low);
+        assert_eq!(vec![3, 3], bars.trades);
+    }
+
+    #[test]
+    fn bulk_tick_bars() {
+        let bars = bulk::tick_bars(&TIMESTAMPS, &PRICES, &SIZES, 2).unwrap();
+        assert_eq!(vec![100.0, 101.0, 100.0], bars.open);
+        assert_eq!(vec![102.0, 101.0, 100.0], bars.high);
+        assert_eq!(vec![100.0, 99.0, 100.0], bars.low);
+        assert_eq!(vec![8.0, 6.0, 1.0], bars.volume);
+    }
+
+    #[test]
+    fn bulk_dollar_bars() {
+        let bars = bulk::dollar_bars(&TIMESTAMPS, &PRICES, &SIZES, 500.0).unwrap();
+        assert_eq!(vec![5.0, 5.0, 5.0], bars.volume);
+        assert_eq!(vec![100.0, 101.0, 100.0], bars.close);
+    }
+
+    #[test]
+    fn bulk_imbalance_bars() {
+        let bars = bulk::imbalance_bars(&TIMESTAMPS, &PRICES, &SIZES, 3.0).unwrap();
+        assert_eq!(vec![8.0, 6.0, 1.0], bars.volume);
+    }
+
+    #[test]
+    fn bulk_bars_feed_true_range() {
+        let bars = bulk::time_bars(&TIMESTAMPS, &PRICES, &SIZES, 60).unwrap();
+        let true_range =
+            crate::other_indicators::bulk::true_range(&bars.close, &bars.high, &bars.low).unwrap();
+        assert_eq!(3, true_range.len());
+    }
+
+    #[test]
+    fn bulk_mismatched_length_error() {
+        assert!(bulk::time_bars(&TIMESTAMPS[..4], &PRICES, &SIZES, 60).is_err());
+        assert!(bulk::time_bars(&TIMESTAMPS, &PRICES, &SIZES[..4], 60).is_err());
+    }
+
+    #[test]
+    fn bulk_empty_error() {
+        assert!(bulk::volume_bars(&[], &[], &[], 10.0).is_err());
+    }
+}
@@ -15,13 +15,14 @@
 //! ### Bulk
 //! - [`absolute_deviation`](bulk::absolute_deviation): Mean/Median/Mode absolute deviation over each period
 //! - [`cauchy_iqr_scale`](bulk::cauchy_iqr_scale): Cauchy IQR-based scale parameter over each period
+//! - [`kernel_density_mode`](bulk::kernel_density_mode): Kernel density estimate of the mode over each period
 //! - [`laplace_std_equivalent`](bulk::laplace_std_equivalent): Laplace standard deviation equivalent over each period
 //! - [`log`](bulk::log): Natural logarithm of each price
 //! - [`log_difference`](bulk::log_difference): Difference in log(price) at t and t-1
 //! - [`log_standard_deviation`](bulk::log_standard_deviation): Log standard deviation over each period
 //! - [`mean`](bulk::mean): Average
 //! - [`median`](bulk::median): Median
-//! - [`mode`](bulk::mode): Mode
+//! - [`mode`](bulk::mode): Mode, bucketed to a precision
 //! - [`price_distribution`](bulk::price_distribution): Distribution of prices (count of each unique price) over each period
 //! - [`standard_deviation`](bulk::standard_deviation): Standard deviation
 //! - [`student_t_adjusted_std`](bulk::student_t_adjusted_std): Student's t-adjusted standard deviation over each period
@@ -30,6 +31,7 @@
 //! ### Single
 //! - [`absolute_deviation`](single::absolute_deviation): Mean/Median/Mode absolute deviation
 //! - [`cauchy_iqr_scale`](single::cauchy_iqr_scale): Cauchy IQR-based scale parameter
+//! - [`kernel_density_mode`](single::kernel_density_mode): Kernel density estimate of the mode
 //! - [`laplace_std_equivalent`](single::laplace_std_equivalent): Laplace standard deviation equivalent
 //! - [`log_difference`](single::log_difference): Log difference between two prices
 //! - [`log_standard_deviation`](single::log_standard_deviation): Log standard deviation
@@ -37,7 +39,7 @@
 //! - [`mean`](single::mean): Mean price
 //! - [`median`](single::median): Median price
 //! - [`min`](single::min): Minimum price
-//! - [`mode`](single::mode): Mode price
+//! - [`mode`](single::mode): Mode price, bucketed to a precision
 //! - [`price_distribution`](single::price_distribution): Distribution of prices (count of each unique price)
 //! - [`standard_deviation`](single::standard_deviation): Standard deviation
 //! - [`student_t_adjusted_std`](single::student_t_adjusted_std): Student's t-adjusted standard deviation
@@ -127,12 +129,13 @@ pub mod single {
 
     /// Calculates the mode (most common price) of a slice of prices.
     ///
-    /// Rounds prices to the nearest integer for frequency counting.
-    /// If multiple modes exist, returns their average.
+    /// Groups prices into buckets of width `precision` (as [`price_distribution`] does) and
+    /// counts how often each bucket occurs. If multiple modes exist, returns their average.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
+    /// * `precision` - Bucket width, e.g. 1.0 for whole numbers or 0.0001 for a pip
     ///
     /// # Returns
     ///
@@ -141,38 +144,138 @@ pub mod single {
     /// # Errors
     ///
     /// Returns `TechnicalIndicatorError::EmptyData` if `prices.is_empty()`
+    /// Returns `TechnicalIndicatorError::InvalidValue` if `precision` <= 0
     ///
     /// # Examples
     ///
     /// ```rust
     /// let prices = vec![100.0, 102.0, 101.0, 101.0, 100.0];
-    /// let mode = centaur_technical_indicators::basic_indicators::single::mode(&prices).unwrap();
+    /// let mode = centaur_technical_indicators::basic_indicators::single::mode(&prices, 1.0).unwrap();
     /// assert_eq!(100.5, mode); // 100.0 and 101.0 occur equally often, so average is 100.5
     ///
     /// let prices = vec![100.0, 102.0, 103.0, 101.0, 100.0];
-    /// let mode = centaur_technical_indicators::basic_indicators::single::mode(&prices).unwrap();
+    /// let mode = centaur_technical_indicators::basic_indicators::single::mode(&prices, 1.0).unwrap();
     /// assert_eq!(100.0, mode); // 100.0 occurs most often
+    ///
+    /// // FX quotes, bucketed to the pip
+    /// let prices = vec![1.0834, 1.0836, 1.0834, 1.0851, 1.0829];
+    /// let mode = centaur_technical_indicators::basic_indicators::single::mode(&prices, 0.0001).unwrap();
+    /// // The answer is `1.0834` but `f64` implementation we get `1.0834000000000001`
+    /// assert_eq!(1.0834000000000001, mode);
     /// ```
     #[inline]
-    pub fn mode(prices: &[f64]) -> crate::Result<f64> {
-        assert_non_empty("prices", prices)?;
-        let mut frequency: HashMap<i64, usize> = HashMap::new();
-        for &price in prices {
-            *frequency.entry(price.round() as i64).or_insert(0) += 1;
-        }
-        let max_count = frequency.values().copied().max().unwrap();
-        let modes: Vec<i64> = frequency
+    pub fn mode(prices: &[f64], precision: f64) -> crate::Result<f64> {
+        let distribution = price_distribution(prices, precision)?;
+        let max_count = distribution
             .iter()
-            .filter_map(|(&value, &count)| {
-                if count == max_count {
-                    Some(value)
-                } else {
-                    None
-                }
-            })
+            .map(|&(_, count)| count)
+            .max()
+            .unwrap_or(0);
+        let modes: Vec<f64> = distribution
+            .iter()
+            .filter(|&&(_, count)| count == max_count)
+            .map(|&(price, _)| price)
             .collect();
 
-        Ok(modes.iter().sum::<i64>() as f64 / modes.len() as f64)
+        mean(&modes)
+    }
+
+    /// Estimates the mode of continuous prices with a Gaussian kernel density estimate.
+    ///
+    /// Unlike [`mode`], no bucketing is needed: the price with the highest estimated density is
+    /// taken as a starting point and refined with mean-shift iterations to the nearest peak of
+    /// the density. When no bandwidth is given, Silverman's rule of thumb is used,
+    /// `0.9 * min(σ, IQR / 1.34) * n^(-1/5)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `bandwidth` - Kernel bandwidth, `None` for Silverman's rule of thumb
+    ///
+    /// # Returns
+    ///
+    /// The price at the peak of the estimated density
+    ///
+    /// # Errors
+    ///
+    /// Returns `TechnicalIndicatorError::EmptyData` if `prices.is_empty()`
+    /// Returns `TechnicalIndicatorError::InvalidValue` if `bandwidth` <= 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![1.0, 2.0, 3.0];
+    /// let mode = centaur_technical_indicators::basic_indicators::single::kernel_density_mode(&prices, Some(1.0)).unwrap();
+    /// assert_eq!(2.0, mode);
+    ///
+    /// let prices = vec![1.0831, 1.0834, 1.0835, 1.0836, 1.0851, 1.0872];
+    /// let mode = centaur_technical_indicators::basic_indicators::single::kernel_density_mode(&prices, None).unwrap();
+    /// assert_eq!(1.0834172144656926, mode);
+    /// ```
+    #[inline]
+    pub fn kernel_density_mode(prices: &[f64], bandwidth: Option<f64>) -> crate::Result<f64> {
+        assert_non_empty("prices", prices)?;
+        let bandwidth = match bandwidth {
+            Some(bandwidth) => {
+                assert_positive("bandwidth", bandwidth)?;
+                bandwidth
+            }
+            None => silverman_bandwidth(prices)?,
+        };
+        if bandwidth == 0.0 {
+            // Every price is the same
+            return Ok(prices[0]);
+        }
+
+        let weights = |x: f64| {
+            prices
+                .iter()
+                .map(move |&price| (price, (-0.5 * ((x - price) / bandwidth).powi(2)).exp()))
+        };
+        let density = |x: f64| weights(x).map(|(_, weight)| weight).sum::<f64>();
+
+        let mut mode = prices
+            .iter()
+            .map(|&price| (price, density(price)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .map(|(price, _)| price)
+            .unwrap();
+        for _ in 0..KDE_MAX_ITERATIONS {
+            let (weighted_sum, weight_sum) = weights(mode)
+                .fold((0.0, 0.0), |(ws, w), (price, weight)| {
+                    (ws + price * weight, w + weight)
+                });
+            let shifted = weighted_sum / weight_sum;
+            let converged = (shifted - mode).abs() <= KDE_TOLERANCE * bandwidth;
+            mode = shifted;
+            if converged {
+                break;
+            }
+        }
+        Ok(mode)
+    }
+
+    const KDE_MAX_ITERATIONS: usize = 500;
+    const KDE_TOLERANCE: f64 = 1e-12;
+
+    /// Silverman's rule of thumb bandwidth for a Gaussian kernel
+    fn silverman_bandwidth(prices: &[f64]) -> crate::Result<f64> {
+        let spread = standard_deviation(prices)?;
+        let mut sorted = prices.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let quantile = |q: f64| {
+            let rank = q * (sorted.len() - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        };
+        let iqr_spread = (quantile(0.75) - quantile(0.25)) / 1.34;
+        let scale = if iqr_spread > 0.0 {
+            spread.min(iqr_spread)
+        } else {
+            spread
+        };
+        Ok(0.9 * scale * (prices.len() as f64).powf(-0.2))
     }
 
     /// Calculates the difference between the natural logarithm at t and t-1
@@ -306,7 +409,7 @@ pub mod single {
     /// let mode_absolute_deviation =
     ///     centaur_technical_indicators::basic_indicators::single::absolute_deviation(
     ///         &prices,
-    ///         centaur_technical_indicators::AbsDevConfig{ center: centaur_technical_indicators::CentralPoint::Mode, aggregate: centaur_technical_indicators::DeviationAggregate::Mode }
+    ///         centaur_technical_indicators::AbsDevConfig{ center: centaur_technical_indicators::CentralPoint::Mode { precision: 1.0 }, aggregate: centaur_technical_indicators::DeviationAggregate::Mode { precision: 1.0 } }
     ///   ).unwrap();
     /// assert_eq!(0.0, mode_absolute_deviation);
     /// ```
@@ -316,7 +419,7 @@ pub mod single {
         let mid_point = match config.center {
             CentralPoint::Mean => mean(prices)?,
             CentralPoint::Median => median(prices)?,
-            CentralPoint::Mode => mode(prices)?,
+            CentralPoint::Mode { precision } => mode(prices, precision)?,
             _ => return Err(unsupported_type("CentralPoint")),
         };
 
@@ -325,7 +428,7 @@ pub mod single {
         match config.aggregate {
             DeviationAggregate::Mean => mean(&devs),
             DeviationAggregate::Median => median(&devs),
-            DeviationAggregate::Mode => mode(&devs),
+            DeviationAggregate::Mode { precision } => mode(&devs, precision),
         }
     }
 
@@ -783,6 +886,7 @@ pub mod bulk {
     ///
     /// * `prices` - Slice of prices
     /// * `period` - Period over which to calculate the mode
+    /// * `precision` - Bucket width, e.g. 1.0 for whole numbers or 0.0001 for a pip
     ///
     /// # Returns
     ///
@@ -791,24 +895,63 @@ pub mod bulk {
     /// # Errors
     ///
     /// Returns `TechnicalIndicatorError::InvalidPeriod` if `period` == 0 or `period` > `prices.len()`
+    /// Returns `TechnicalIndicatorError::InvalidValue` if `precision` <= 0
     ///
     /// # Examples
     ///
     /// ```rust
     /// let prices = vec![101.0, 102.0, 101.0, 102.0];
-    /// let mode = centaur_technical_indicators::basic_indicators::bulk::mode(&prices, 3).unwrap();
+    /// let mode = centaur_technical_indicators::basic_indicators::bulk::mode(&prices, 3, 1.0).unwrap();
     /// assert_eq!(vec![101.0, 102.0], mode);
     /// ```
     #[inline]
-    pub fn mode(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+    pub fn mode(prices: &[f64], period: usize, precision: f64) -> crate::Result<Vec<f64>> {
         assert_period(period, prices.len())?;
         let mut result = Vec::with_capacity(prices.len());
         for window in prices.windows(period) {
-            result.push(single::mode(window)?)
+            result.push(single::mode(window, precision)?)
         }
         Ok(result)
     }
 
+    /// Estimates the mode of continuous prices with a Gaussian kernel density estimate over a
+    /// given period.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to estimate the mode
+    /// * `bandwidth` - Kernel bandwidth, `None` for Silverman's rule of thumb in each window
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns `TechnicalIndicatorError::InvalidPeriod` if `period` == 0 or `period` > `prices.len()`
+    /// Returns `TechnicalIndicatorError::InvalidValue` if `bandwidth` <= 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![1.0, 2.0, 3.0, 4.0];
+    /// let mode = centaur_technical_indicators::basic_indicators::bulk::kernel_density_mode(&prices, 3, Some(1.0)).unwrap();
+    /// assert_eq!(vec![2.0, 3.0], mode);
+    /// ```
+    #[inline]
+    pub fn kernel_density_mode(
+        prices: &[f64],
+        period: usize,
+        bandwidth: Option<f64>,
+    ) -> crate::Result<Vec<f64>> {
+        assert_period(period, prices.len())?;
+        prices
+            .windows(period)
+            .map(|w| single::kernel_density_mode(w, bandwidth))
+            .collect()
+    }
+
     /// Calculates the natural logarithm of slice of prices
     ///
     /// # Arguments
@@ -991,7 +1134,7 @@ pub mod bulk {
     ///     centaur_technical_indicators::basic_indicators::bulk::absolute_deviation(
     ///         &prices,
     ///         period,
-    ///         centaur_technical_indicators::AbsDevConfig{ center: CentralPoint::Mode, aggregate: DeviationAggregate::Mode }
+    ///         centaur_technical_indicators::AbsDevConfig{ center: CentralPoint::Mode { precision: 1.0 }, aggregate: DeviationAggregate::Mode { precision: 1.0 } }
     ///     ).unwrap();
     /// assert_eq!(
     ///     vec![1.0, 1.0, 1.0],
@@ -1346,25 +1489,25 @@ mod tests {
     #[test]
     fn single_mode_round_up() {
         let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
-        assert_eq!(101.0, single::mode(&prices).unwrap());
+        assert_eq!(101.0, single::mode(&prices, 1.0).unwrap());
     }
 
     #[test]
     fn single_mode_round_down() {
         let prices = vec![100.2, 100.46, 100.35, 101.08, 101.19];
-        assert_eq!(100.0, single::mode(&prices).unwrap());
+        assert_eq!(100.0, single::mode(&prices, 1.0).unwrap());
     }
 
     #[test]
     fn single_mode_average() {
         let prices = vec![100.46, 100.35, 101.08, 101.19];
-        assert_eq!(100.5, single::mode(&prices).unwrap());
+        assert_eq!(100.5, single::mode(&prices, 1.0).unwrap());
     }
 
     #[test]
     fn single_mode_error() {
         let prices = Vec::new();
-        let result = single::mode(&prices);
+        let result = single::mode(&prices, 1.0);
         assert!(result.is_err());
     }
 
@@ -1374,7 +1517,7 @@ mod tests {
         let period: usize = 3;
         assert_eq!(
             vec![100.0, 101.0, 101.0],
-            bulk::mode(&prices, period).unwrap()
+            bulk::mode(&prices, period, 1.0).unwrap()
         );
     }
 
@@ -1382,7 +1525,7 @@ mod tests {
     fn bulk_mode_long_period_error() {
         let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
         let period: usize = 30;
-        let result = bulk::mode(&prices, period);
+        let result = bulk::mode(&prices, period, 1.0);
         assert!(result.is_err());
     }
 
@@ -1390,10 +1533,92 @@ mod tests {
     fn bulk_mode_no_period_error() {
         let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
         let period: usize = 0;
-        let result = bulk::mode(&prices, period);
+        let result = bulk::mode(&prices, period, 1.0);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn single_mode_precision() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert_eq!(100.5, single::mode(&prices, 0.1).unwrap());
+    }
+
+    #[test]
+    fn single_mode_precision_error() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert!(single::mode(&prices, 0.0).is_err());
+    }
+
+    #[test]
+    fn bulk_mode_precision() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert_eq!(
+            vec![100.5, 100.5, 100.93333333333334],
+            bulk::mode(&prices, 3, 0.1).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_kernel_density_mode() {
+        let prices = vec![1.0, 2.0, 3.0];
+        assert_eq!(
+            2.0,
+            single::kernel_density_mode(&prices, Some(1.0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_kernel_density_mode_bimodal() {
+        let prices = vec![1.0, 1.1, 1.2, 5.0, 5.1];
+        assert_eq!(
+            1.100000000000098,
+            single::kernel_density_mode(&prices, Some(0.5)).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_kernel_density_mode_silverman() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert_eq!(
+            100.43007883555362,
+            single::kernel_density_mode(&prices, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_kernel_density_mode_flat() {
+        let prices = vec![100.0, 100.0, 100.0];
+        assert_eq!(100.0, single::kernel_density_mode(&prices, None).unwrap());
+    }
+
+    #[test]
+    fn single_kernel_density_mode_bandwidth_error() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert!(single::kernel_density_mode(&prices, Some(0.0)).is_err());
+    }
+
+    #[test]
+    fn single_kernel_density_mode_error() {
+        let prices = Vec::new();
+        assert!(single::kernel_density_mode(&prices, None).is_err());
+    }
+
+    #[test]
+    fn bulk_kernel_density_mode() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert_eq!(
+            vec![100.41456206936557, 100.51827635508431, 101.11447384628052],
+            bulk::kernel_density_mode(&prices, 3, Some(0.25)).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_kernel_density_mode_period_error() {
+        let prices = vec![100.2, 100.46, 100.53, 101.08, 101.19];
+        assert!(bulk::kernel_density_mode(&prices, 30, None).is_err());
+        assert!(bulk::kernel_density_mode(&prices, 0, None).is_err());
+    }
+
     #[test]
     fn bulk_log() {
         let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
@@ -1452,7 +1677,7 @@ mod tests {
 
     #[test]
     fn bulk_log_difference_difference() {
-        bulk::log_difference(&Vec::new());
+        assert!(bulk::log_difference(&Vec::new()).is_err());
     }
 
     #[test]
@@ -1574,8 +1799,8 @@ mod tests {
             single::absolute_deviation(
                 &prices,
                 crate::AbsDevConfig {
-                    center: crate::CentralPoint::Mode,
-                    aggregate: crate::DeviationAggregate::Mode
+                    center: crate::CentralPoint::Mode { precision: 1.0 },
+                    aggregate: crate::DeviationAggregate::Mode { precision: 1.0 }
                 }
             )
             .unwrap()
@@ -1585,13 +1810,14 @@ mod tests {
     #[test]
     fn single_absolute_deviation_error() {
         let prices = Vec::new();
-        single::absolute_deviation(
+        assert!(single::absolute_deviation(
             &prices,
             crate::AbsDevConfig {
                 center: crate::CentralPoint::Mean,
                 aggregate: crate::DeviationAggregate::Mean,
             },
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -1637,8 +1863,8 @@ mod tests {
                 &prices,
                 period,
                 crate::AbsDevConfig {
-                    center: crate::CentralPoint::Mode,
-                    aggregate: crate::DeviationAggregate::Mode
+                    center: crate::CentralPoint::Mode { precision: 1.0 },
+                    aggregate: crate::DeviationAggregate::Mode { precision: 1.0 }
                 }
             )
             .unwrap()
@@ -1649,28 +1875,30 @@ mod tests {
     fn bulk_absolute_deviation_long_period_error() {
         let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
         let period: usize = 30;
-        bulk::absolute_deviation(
+        assert!(bulk::absolute_deviation(
             &prices,
             period,
             crate::AbsDevConfig {
                 center: crate::CentralPoint::Median,
                 aggregate: crate::DeviationAggregate::Median,
             },
-        );
+        )
+        .is_err());
     }
 
     #[test]
     fn bulk_absolute_deviation_no_period_error() {
         let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
         let period: usize = 30;
-        bulk::absolute_deviation(
+        assert!(bulk::absolute_deviation(
             &prices,
             period,
             crate::AbsDevConfig {
                 center: crate::CentralPoint::Median,
                 aggregate: crate::DeviationAggregate::Median,
             },
-        );
+        )
+        .is_err());
     }
 
     #[test]
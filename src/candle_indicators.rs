@@ -17,6 +17,7 @@
 //!
 //! ### Bulk
 //! - [`ichimoku_cloud`](bulk::ichimoku_cloud): Ichimoku Cloud values
+//! - [`displaced_ichimoku_cloud`](bulk::displaced_ichimoku_cloud): Time-aligned Ichimoku Cloud with signals
 //! - [`mcginley_dynamic_bands`](bulk::mcginley_dynamic_bands): McGinley Dynamic Bands
 //! - [`mcginley_dynamic_envelopes`](bulk::mcginley_dynamic_envelopes): McGinley Dynamic Envelopes
 //! - [`moving_constant_bands`](bulk::moving_constant_bands): Generalized bands (e.g., Bollinger Bands)
@@ -24,6 +25,10 @@
 //! - [`donchian_channels`](bulk::donchian_channels): Donchian Channels
 //! - [`keltner_channel`](bulk::keltner_channel): Keltner Channels
 //! - [`supertrend`](bulk::supertrend): Supertrend indicator
+//! - [`percent_b`](bulk::percent_b): %B of prices within any bands
+//! - [`bandwidth`](bulk::bandwidth): Bandwidth of any bands
+//! - [`squeeze_momentum`](bulk::squeeze_momentum): TTM Squeeze state and momentum histogram
+//! - [`extended_supertrend`](bulk::extended_supertrend): Supertrend with direction and flip events
 //!
 //! ### Single
 //! - [`ichimoku_cloud`](single::ichimoku_cloud): Ichimoku Cloud
@@ -34,6 +39,8 @@
 //! - [`donchian_channels`](single::donchian_channels): Donchian Channels
 //! - [`keltner_channel`](single::keltner_channel): Keltner Channel
 //! - [`supertrend`](single::supertrend): Supertrend indicator
+//! - [`percent_b`](single::percent_b): %B of a price within bands
+//! - [`bandwidth`](single::bandwidth): Bandwidth of bands
 //!
 //! ## API Details
 //! - All indicators are highly configurable: choose your model (SMA, EMA, median, etc.), deviation method, and period.
@@ -42,25 +49,18 @@
 
 /// **single**: Functions that return a single value for a slice of prices
 pub mod single {
-    use crate::basic_indicators::single::{
-        absolute_deviation, cauchy_iqr_scale, laplace_std_equivalent, log_standard_deviation, max,
-        median, min, mode, standard_deviation, student_t_adjusted_std,
-    };
-    use crate::moving_average::single::{mcginley_dynamic, moving_average};
+    use crate::basic_indicators::single::{max, min};
+    use crate::moving_average::single::mcginley_dynamic;
     use crate::other_indicators::single::average_true_range;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
-    use crate::volatility_indicators::single::ulcer_index;
-    use crate::{
-        AbsDevConfig, CentralPoint, ConstantModelType, DeviationAggregate, DeviationModel,
-        MovingAverageType,
-    };
+    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
+    use crate::{CentralTendency, Deviation, TechnicalIndicatorError};
 
     /// Calculates upper/lower envelopes around a moving constant (mean, median, etc.)
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `difference` - Percent band width (e.g., 3.0 for +-3%)
     ///
     /// # Returns
@@ -96,34 +96,11 @@ pub mod single {
     #[inline]
     pub fn moving_constant_envelopes(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         difference: f64,
     ) -> crate::Result<(f64, f64, f64)> {
         assert_non_empty("prices", prices)?;
-        let moving_constant = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices)?,
-            ConstantModelType::SimpleMovingMode => mode(prices)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let moving_constant = constant_model_type.central_value(prices)?;
 
         let upper_envelope = moving_constant * (1.0 + (difference / 100.0));
         let lower_envelope = moving_constant * (1.0 - (difference / 100.0));
@@ -191,8 +168,8 @@ pub mod single {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `deviation_multiplier` - Deviation multiplier (e.g., 3.0 for +-3%)
     ///
     /// # Returns
@@ -230,77 +207,14 @@ pub mod single {
     #[inline]
     pub fn moving_constant_bands(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
         deviation_multiplier: f64,
     ) -> crate::Result<(f64, f64, f64)> {
         assert_non_empty("prices", prices)?;
-        let moving_constant = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices)?,
-            ConstantModelType::SimpleMovingMode => mode(prices)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
-
-        let deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices, config)?
-            }
-            DeviationModel::UlcerIndex => ulcer_index(prices)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices, precision, low, high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
+        let moving_constant = constant_model_type.central_value(prices)?;
+
+        let deviation = deviation_model.deviation(prices)?;
         let upper_band = moving_constant + (deviation * deviation_multiplier);
         let lower_band = moving_constant - (deviation * deviation_multiplier);
         Ok((lower_band, moving_constant, upper_band))
@@ -311,7 +225,7 @@ pub mod single {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `deviation_multiplier` - Deviatio multiplier (e.g., 3.0 for +-3%)
     /// * `previous_mcginley_dynamic` - Previous McGinley dynamic (0.0 if none)
     ///
@@ -351,7 +265,7 @@ pub mod single {
     #[inline]
     pub fn mcginley_dynamic_bands(
         prices: &[f64],
-        deviation_model: DeviationModel,
+        deviation_model: impl Deviation,
         deviation_multiplier: f64,
         previous_mcginley_dynamic: f64,
     ) -> crate::Result<(f64, f64, f64)> {
@@ -360,47 +274,7 @@ pub mod single {
         let mcginley_dynamic =
             mcginley_dynamic(*last_price, previous_mcginley_dynamic, prices.len())?;
 
-        let deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices, config)?
-            }
-            DeviationModel::UlcerIndex => ulcer_index(prices)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices, precision, low, high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
+        let deviation = deviation_model.deviation(prices)?;
         let upper_band = mcginley_dynamic + (deviation * deviation_multiplier);
         let lower_band = mcginley_dynamic - (deviation * deviation_multiplier);
         Ok((lower_band, mcginley_dynamic, upper_band))
@@ -539,8 +413,8 @@ pub mod single {
     /// * `highs` - Slice of highs
     /// * `lows` - Slice of lows
     /// * `close` - Slice of previous closing prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`] for the function
-    /// * `atr_constant_model_type` - Variant of [`ConstantModelType`] for the ATR
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the function
+    /// * `atr_constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
     /// * `multiplier` - Multiplier for the ATR
     ///
     /// # Returns
@@ -575,8 +449,8 @@ pub mod single {
         highs: &[f64],
         lows: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
-        atr_constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
+        atr_constant_model_type: impl CentralTendency,
         multiplier: f64,
     ) -> crate::Result<(f64, f64, f64)> {
         let length = highs.len();
@@ -588,34 +462,96 @@ pub mod single {
             .map(|i| (highs[i] + lows[i] + close[i]) / 3.0)
             .collect();
 
-        let mc = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(&prices, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(&prices, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(&prices, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                &prices,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(&prices)?,
-            ConstantModelType::SimpleMovingMode => mode(&prices)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let mc = constant_model_type.central_value(&prices)?;
         let constant = atr * multiplier;
         Ok((mc - constant, mc, mc + constant))
     }
 
+    /// Calculates %B, the position of a price within a set of bands
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Price to locate, usually the latest close
+    /// * `bands` - Tuple of (lower_band, middle_band, upper_band) from any band or channel
+    ///   function
+    ///
+    /// # Returns
+    ///
+    /// 0.0 at the lower band, 1.0 at the upper band, and values outside that range when
+    /// the price is outside the bands. Returns 0.5 when the bands have no width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    ///
+    /// let bollinger_bands =
+    ///     centaur_technical_indicators::candle_indicators::single::moving_constant_bands(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         2.0
+    ///     ).unwrap();
+    /// let percent_b = centaur_technical_indicators::candle_indicators::single::percent_b(
+    ///     99.0,
+    ///     bollinger_bands
+    /// );
+    /// assert_eq!(0.14644660940672555, percent_b);
+    /// ```
+    #[inline]
+    pub fn percent_b(price: f64, bands: (f64, f64, f64)) -> f64 {
+        let (lower_band, _, upper_band) = bands;
+        if upper_band == lower_band {
+            return 0.5;
+        }
+        (price - lower_band) / (upper_band - lower_band)
+    }
+
+    /// Calculates the bandwidth, the width of a set of bands relative to the middle band
+    ///
+    /// # Arguments
+    ///
+    /// * `bands` - Tuple of (lower_band, middle_band, upper_band) from any band or channel
+    ///   function
+    ///
+    /// # Returns
+    ///
+    /// (upper_band - lower_band) / middle_band
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `middle_band` == 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    ///
+    /// let bollinger_bands =
+    ///     centaur_technical_indicators::candle_indicators::single::moving_constant_bands(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         2.0
+    ///     ).unwrap();
+    /// let bandwidth = centaur_technical_indicators::candle_indicators::single::bandwidth(
+    ///     bollinger_bands
+    /// ).unwrap();
+    /// assert_eq!(0.05600845791576603, bandwidth);
+    /// ```
+    #[inline]
+    pub fn bandwidth(bands: (f64, f64, f64)) -> crate::Result<f64> {
+        let (lower_band, middle_band, upper_band) = bands;
+        if middle_band == 0.0 {
+            return Err(TechnicalIndicatorError::InvalidValue {
+                name: "middle_band".to_string(),
+                value: middle_band,
+                reason: "must not be zero".to_string(),
+            });
+        }
+        Ok((upper_band - lower_band) / middle_band)
+    }
+
     /// Calculates the supertrend indicator
     ///
     /// # Arguments
@@ -623,7 +559,7 @@ pub mod single {
     /// * `high` - Slice of highs
     /// * `low` - Slice of lows
     /// * `close` - Slice of previous closing prices
-    /// * `constant_type_model` - Variant of [`ConstantModelType`] for the ATR
+    /// * `constant_type_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
     /// * `multiplier` - Multiplier for the ATR
     ///
     /// # Returns
@@ -657,7 +593,7 @@ pub mod single {
         highs: &[f64],
         lows: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         multiplier: f64,
     ) -> crate::Result<f64> {
         assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
@@ -673,15 +609,22 @@ pub mod single {
 /// **bulk** : Functions that compute values of a slice of prices over a period and return a vector
 pub mod bulk {
     use crate::candle_indicators::single;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
-    use crate::{ConstantModelType, DeviationModel};
+    use crate::chart_trends::overall_trend;
+    use crate::other_indicators::bulk::average_true_range;
+    use crate::validation::{
+        assert_min_length, assert_non_empty, assert_period, assert_positive, assert_positive_usize,
+        assert_same_len,
+    };
+    use crate::volatility_indicators::bulk::initial_position;
+    use crate::{CentralTendency, Deviation};
+    use crate::{IchimokuBar, Position, PriceSource};
 
     /// Calculates the Moving Constant Envelopes
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `difference` -  Percent band width (e.g., 3.0 for +-3%)
     /// * `period` - Period over which to calculate the moving constant envelopes
     ///
@@ -732,7 +675,7 @@ pub mod bulk {
     #[inline]
     pub fn moving_constant_envelopes(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         difference: f64,
         period: usize,
     ) -> crate::Result<Vec<(f64, f64, f64)>> {
@@ -742,7 +685,7 @@ pub mod bulk {
             .map(|i| {
                 single::moving_constant_envelopes(
                     &prices[i..i + period],
-                    constant_model_type,
+                    &constant_model_type,
                     difference,
                 )
             })
@@ -813,8 +756,8 @@ pub mod bulk {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `deviation_multiplier` - Price deviation multiplier
     /// * `period` - Period over which to calculate the moving constant bands.
     ///
@@ -868,8 +811,8 @@ pub mod bulk {
     #[inline]
     pub fn moving_constant_bands(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
         deviation_multiplier: f64,
         period: usize,
     ) -> crate::Result<Vec<(f64, f64, f64)>> {
@@ -879,8 +822,8 @@ pub mod bulk {
             .map(|i| {
                 single::moving_constant_bands(
                     &prices[i..i + period],
-                    constant_model_type,
-                    deviation_model,
+                    &constant_model_type,
+                    &deviation_model,
                     deviation_multiplier,
                 )
             })
@@ -892,7 +835,7 @@ pub mod bulk {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `deviation_model` - Variant [`DeviationModel`]
+    /// * `deviation_model` - Variant [`DeviationModel`](crate::DeviationModel)
     /// * `deviation_multiplier` - Price deviation multiplier
     /// * `previous_mcginley_dynamic` - Previous McGinley dynamic (0.0 if none)
     /// * `period` - Period over which to calculate the McGinley dynamic bands.
@@ -932,7 +875,7 @@ pub mod bulk {
     #[inline]
     pub fn mcginley_dynamic_bands(
         prices: &[f64],
-        deviation_model: DeviationModel,
+        deviation_model: impl Deviation,
         deviation_multiplier: f64,
         previous_mcginley_dynamic: f64,
         period: usize,
@@ -944,7 +887,7 @@ pub mod bulk {
         for window in prices.windows(period) {
             let band = single::mcginley_dynamic_bands(
                 window,
-                deviation_model,
+                &deviation_model,
                 deviation_multiplier,
                 prev,
             )?;
@@ -1015,6 +958,7 @@ pub mod bulk {
     ///     ], ichimoku_cloud);
     /// ```
     #[inline]
+    #[allow(clippy::type_complexity)]
     pub fn ichimoku_cloud(
         highs: &[f64],
         lows: &[f64],
@@ -1042,6 +986,166 @@ pub mod bulk {
             .collect::<crate::Result<Vec<_>>>()
     }
 
+    /// Calculates the Ichimoku Cloud with the spans displaced as they are plotted
+    ///
+    /// The leading spans are projected `displacement` bars ahead and the lagging span is
+    /// plotted `displacement` bars back, so every field of a bar lines up with the price
+    /// at the same index. The cloud is extended `displacement` bars past the last price.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `conversion_period` - Period for the conversion line (Tenkan-sen)
+    /// * `base_period` - Period for the base line (Kijun-sen)
+    /// * `span_b_period` - Period for leading span B
+    /// * `displacement` - Number of bars the spans are shifted by
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`IchimokuBar`] of length `highs.len() + displacement`. The last
+    /// `displacement` bars only hold the projected cloud.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.len()` != `lows.len()` != `close.len()`
+    /// * `highs.is_empty()`
+    /// * any period == 0 or > `highs.len()`
+    /// * `displacement` == 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::Position;
+    ///
+    /// let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+    /// let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+    /// let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+    ///
+    /// let ichimoku =
+    ///     centaur_technical_indicators::candle_indicators::bulk::displaced_ichimoku_cloud(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         2,
+    ///         3,
+    ///         4,
+    ///         2
+    ///     ).unwrap();
+    ///
+    /// assert_eq!(9, ichimoku.len());
+    /// assert_eq!(Some(102.0), ichimoku[5].leading_span_a);
+    /// assert_eq!(Some(103.0), ichimoku[8].leading_span_b);
+    /// assert_eq!(Some(108.0), ichimoku[4].lagging_span);
+    /// assert_eq!(None, ichimoku[5].lagging_span);
+    /// assert_eq!(Some(Position::Long), ichimoku[6].tk_cross);
+    /// ```
+    pub fn displaced_ichimoku_cloud(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        conversion_period: usize,
+        base_period: usize,
+        span_b_period: usize,
+        displacement: usize,
+    ) -> crate::Result<Vec<IchimokuBar>> {
+        let length = highs.len();
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(conversion_period, length)?;
+        assert_period(base_period, length)?;
+        assert_period(span_b_period, length)?;
+        assert_positive_usize("displacement", displacement)?;
+
+        let conversion_line = midpoints(highs, lows, conversion_period);
+        let base_line = midpoints(highs, lows, base_period);
+        let span_b = midpoints(highs, lows, span_b_period);
+
+        let mut ichimoku: Vec<IchimokuBar> = Vec::with_capacity(length + displacement);
+        for t in 0..length + displacement {
+            let (conversion, base) = if t < length {
+                (conversion_line[t], base_line[t])
+            } else {
+                (None, None)
+            };
+            let (leading_span_a, leading_span_b) = match t.checked_sub(displacement) {
+                Some(source) => (
+                    conversion_line[source]
+                        .zip(base_line[source])
+                        .map(|(conversion, base)| (conversion + base) / 2.0),
+                    span_b[source],
+                ),
+                None => (None, None),
+            };
+            let cloud = leading_span_a.zip(leading_span_b);
+            let previous = t.checked_sub(1).map(|p| ichimoku[p]);
+
+            let tk_cross = previous
+                .and_then(|p| p.conversion_line.zip(p.base_line))
+                .zip(conversion.zip(base))
+                .and_then(|((prev_conversion, prev_base), (conversion, base))| {
+                    if prev_conversion <= prev_base && conversion > base {
+                        Some(Position::Long)
+                    } else if prev_conversion >= prev_base && conversion < base {
+                        Some(Position::Short)
+                    } else {
+                        None
+                    }
+                });
+            let kumo_breakout = previous
+                .and_then(|p| p.leading_span_a.zip(p.leading_span_b))
+                .zip(cloud)
+                .filter(|_| t < length)
+                .and_then(|((prev_a, prev_b), (a, b))| {
+                    if close[t - 1] <= prev_a.max(prev_b) && close[t] > a.max(b) {
+                        Some(Position::Long)
+                    } else if close[t - 1] >= prev_a.min(prev_b) && close[t] < a.min(b) {
+                        Some(Position::Short)
+                    } else {
+                        None
+                    }
+                });
+            let cloud_colour = cloud.map(|(a, b)| {
+                if a >= b {
+                    Position::Long
+                } else {
+                    Position::Short
+                }
+            });
+
+            ichimoku.push(IchimokuBar {
+                conversion_line: conversion,
+                base_line: base,
+                leading_span_a,
+                leading_span_b,
+                lagging_span: close.get(t + displacement).copied(),
+                tk_cross,
+                kumo_breakout,
+                cloud_colour,
+            });
+        }
+        Ok(ichimoku)
+    }
+
+    /// Midpoint of the highest high and lowest low over the `period` bars ending at
+    /// each index, `None` until `period` bars are available.
+    fn midpoints(highs: &[f64], lows: &[f64], period: usize) -> Vec<Option<f64>> {
+        (0..highs.len())
+            .map(|t| {
+                (t + 1 >= period).then(|| {
+                    let window = t + 1 - period..=t;
+                    let high = highs[window.clone()]
+                        .iter()
+                        .fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+                    let low = lows[window].iter().fold(f64::INFINITY, |a, &b| a.min(b));
+                    (high + low) / 2.0
+                })
+            })
+            .collect()
+    }
+
     /// Calculates the Donchian Channels over a given period.
     ///
     /// # Arguments
@@ -1101,8 +1205,8 @@ pub mod bulk {
     /// * `highs` - Slice of highs
     /// * `lows` - Slice of lows
     /// * `close` - Slice of previous closing prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`] for the function
-    /// * `atr_constant_model_type` - Variant of [`ConstantModelType`] for the ATR
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the function
+    /// * `atr_constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
     /// * `multiplier` - Multiplier for the ATR
     /// * `period` - Period over which to calculate the Keltner Channel
     ///
@@ -1148,8 +1252,8 @@ pub mod bulk {
         highs: &[f64],
         lows: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
-        atr_constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
+        atr_constant_model_type: impl CentralTendency,
         multiplier: f64,
         period: usize,
     ) -> crate::Result<Vec<(f64, f64, f64)>> {
@@ -1163,14 +1267,226 @@ pub mod bulk {
                     &highs[i..i + period],
                     &lows[i..i + period],
                     &close[i..i + period],
-                    constant_model_type,
-                    atr_constant_model_type,
+                    &constant_model_type,
+                    &atr_constant_model_type,
                     multiplier,
                 )
             })
             .collect::<crate::Result<Vec<_>>>()
     }
 
+    /// Calculates %B for each price against a series of bands
+    ///
+    /// The bands are aligned to the end of `prices`, so the output of any bulk band or
+    /// channel function can be passed alongside the prices it was computed from.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `bands` - Slice of (lower_band, middle_band, upper_band) tuples
+    ///
+    /// # Returns
+    ///
+    /// A vector of %B values, one per band
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bands.is_empty()`
+    /// * `bands.len()` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    ///
+    /// let bollinger_bands =
+    ///     centaur_technical_indicators::candle_indicators::bulk::moving_constant_bands(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         2.0,
+    ///         3
+    ///     ).unwrap();
+    /// let percent_b = centaur_technical_indicators::candle_indicators::bulk::percent_b(
+    ///     &prices,
+    ///     &bollinger_bands
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![0.7672612419124232, 0.19381378215210282, 0.19381378215210282],
+    ///     percent_b
+    /// );
+    /// ```
+    #[inline]
+    pub fn percent_b(prices: &[f64], bands: &[(f64, f64, f64)]) -> crate::Result<Vec<f64>> {
+        assert_non_empty("bands", bands)?;
+        assert_min_length("prices", bands.len(), prices.len())?;
+        Ok(prices[prices.len() - bands.len()..]
+            .iter()
+            .zip(bands)
+            .map(|(&price, &bands)| single::percent_b(price, bands))
+            .collect())
+    }
+
+    /// Calculates the bandwidth of each set of bands
+    ///
+    /// # Arguments
+    ///
+    /// * `bands` - Slice of (lower_band, middle_band, upper_band) tuples
+    ///
+    /// # Returns
+    ///
+    /// A vector of bandwidths
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bands.is_empty()`
+    /// * any `middle_band` == 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    ///
+    /// let bollinger_bands =
+    ///     centaur_technical_indicators::candle_indicators::bulk::moving_constant_bands(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         2.0,
+    ///         3
+    ///     ).unwrap();
+    /// let bandwidth = centaur_technical_indicators::candle_indicators::bulk::bandwidth(
+    ///     &bollinger_bands
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![0.049070916547855004, 0.03201947376187162, 0.06467299650912683],
+    ///     bandwidth
+    /// );
+    /// ```
+    #[inline]
+    pub fn bandwidth(bands: &[(f64, f64, f64)]) -> crate::Result<Vec<f64>> {
+        assert_non_empty("bands", bands)?;
+        bands
+            .iter()
+            .map(|&bands| single::bandwidth(bands))
+            .collect()
+    }
+
+    /// Calculates the TTM Squeeze: whether the Bollinger Bands are inside the Keltner
+    /// Channel, along with a momentum histogram
+    ///
+    /// The momentum is the linear regression value, at the latest bar, of the close minus
+    /// the average of the Donchian midpoint and the moving constant of the closes over
+    /// `period` bars.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the bands, channel and
+    ///   momentum
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`] for the Bollinger Bands
+    /// * `deviation_multiplier` - Multiplier for the deviation (e.g. 2.0)
+    /// * `atr_constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the Keltner ATR
+    /// * `atr_multiplier` - Multiplier for the ATR (e.g. 1.5)
+    /// * `period` - Period of the bands, channel and momentum
+    ///
+    /// # Returns
+    ///
+    /// A vector of (squeeze_on, momentum) tuples of length `highs.len() - 2 * period + 2`.
+    /// `squeeze_on` is `true` when both Bollinger Bands are inside the Keltner Channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.len()` != `lows.len()` != `close.len()`
+    /// * `highs.is_empty()`
+    /// * `period` == 0
+    /// * `highs.len()` < `2 * period - 1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+    /// let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+    /// let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+    ///
+    /// let squeeze = centaur_technical_indicators::candle_indicators::bulk::squeeze_momentum(
+    ///     &highs,
+    ///     &lows,
+    ///     &close,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///     2.0,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     1.5,
+    ///     3
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (true, 2.027777777777781),
+    ///         (true, 4.9722222222222285),
+    ///         (true, 3.7222222222222334)
+    ///     ],
+    ///     squeeze
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn squeeze_momentum(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+        deviation_multiplier: f64,
+        atr_constant_model_type: impl CentralTendency,
+        atr_multiplier: f64,
+        period: usize,
+    ) -> crate::Result<Vec<(bool, f64)>> {
+        let length = highs.len();
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(period, length)?;
+        assert_min_length("highs", 2 * period - 1, length)?;
+
+        let deltas = (period - 1..length)
+            .map(|t| {
+                let window = t + 1 - period..=t;
+                let donchian =
+                    single::donchian_channels(&highs[window.clone()], &lows[window.clone()])?;
+                let average = constant_model_type.central_value(&close[window])?;
+                Ok(close[t] - (donchian.1 + average) / 2.0)
+            })
+            .collect::<crate::Result<Vec<f64>>>()?;
+
+        (2 * period - 2..length)
+            .map(|t| {
+                let window = t + 1 - period..=t;
+                let bollinger = single::moving_constant_bands(
+                    &close[window.clone()],
+                    &constant_model_type,
+                    &deviation_model,
+                    deviation_multiplier,
+                )?;
+                let keltner = single::keltner_channel(
+                    &highs[window.clone()],
+                    &lows[window.clone()],
+                    &close[window],
+                    &constant_model_type,
+                    &atr_constant_model_type,
+                    atr_multiplier,
+                )?;
+                let squeeze_on = bollinger.0 > keltner.0 && bollinger.2 < keltner.2;
+                let (slope, intercept) =
+                    overall_trend(&deltas[t + 2 - 2 * period..=t + 1 - period])?;
+                Ok((squeeze_on, slope * (period - 1) as f64 + intercept))
+            })
+            .collect()
+    }
+
     /// Calculates the Super Trend indicator
     ///
     /// # Arguments
@@ -1178,7 +1494,7 @@ pub mod bulk {
     /// * `high` - Slice of highs
     /// * `low` - Slice of lows
     /// * `close` - Slice of previous closing prices
-    /// * `constant_type_model` - Variant of [`ConstantModelType`] for the ATR
+    /// * `constant_type_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
     /// * `multiplier` - Multiplier for the ATR
     /// * `period` - Period over which to calculate the supertrend
     ///
@@ -1217,7 +1533,7 @@ pub mod bulk {
         highs: &[f64],
         lows: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         multiplier: f64,
         period: usize,
     ) -> crate::Result<Vec<f64>> {
@@ -1232,17 +1548,142 @@ pub mod bulk {
                     &highs[i..i + period],
                     &lows[i..i + period],
                     &close[i..i + period],
-                    constant_model_type,
+                    &constant_model_type,
                     multiplier,
                 )
             })
             .collect::<crate::Result<Vec<_>>>()
     }
+
+    /// Calculates the Supertrend with final-band ratcheting, direction and flip events
+    ///
+    /// The basic bands are the source price plus/minus `multiplier` times the ATR.
+    /// The final upper band only moves down (and the final lower band only moves up)
+    /// unless the previous close broke through it. The trend flips short when the
+    /// close falls below the final lower band, and long when it rises above the final
+    /// upper band.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
+    /// * `multiplier` - Multiplier for the ATR
+    /// * `period` - Period of the ATR
+    /// * `price_source` - Variant of [`PriceSource`] the bands are built around
+    ///
+    /// # Returns
+    ///
+    /// A vector of (supertrend level, [`Position`], flipped) tuples, one for each bar
+    /// from `period - 1` onwards. The level is the final lower band when long and the
+    /// final upper band when short. The initial position follows the trend of the
+    /// first `period` closes and is never marked as a flip.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.len()` != `lows.len()` != `close.len()`
+    /// * `highs.is_empty()`
+    /// * `period` == 0
+    /// * `period` > `highs.len()`
+    /// * `multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::Position;
+    ///
+    /// let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+    /// let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+    /// let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+    ///
+    /// let supertrend = centaur_technical_indicators::candle_indicators::bulk::extended_supertrend(
+    ///     &highs,
+    ///     &lows,
+    ///     &close,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     1.0,
+    ///     3,
+    ///     centaur_technical_indicators::PriceSource::MedianPrice
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         (109.5, Position::Short, false),
+    ///         (104.66666666666667, Position::Short, false),
+    ///         (104.66666666666667, Position::Short, false),
+    ///         (98.5, Position::Long, true),
+    ///         (101.16666666666667, Position::Long, false)
+    ///     ],
+    ///     supertrend
+    /// );
+    /// ```
+    pub fn extended_supertrend(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        constant_model_type: impl CentralTendency,
+        multiplier: f64,
+        period: usize,
+        price_source: PriceSource,
+    ) -> crate::Result<Vec<(f64, Position, bool)>> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(period, highs.len())?;
+        assert_positive("multiplier", multiplier)?;
+
+        let atr = average_true_range(close, highs, lows, constant_model_type, period)?;
+        let mut position = initial_position(&close[..period])?;
+        let mut supertrend = Vec::with_capacity(atr.len());
+        let mut final_upper = f64::NAN;
+        let mut final_lower = f64::NAN;
+        for (i, atr) in atr.iter().enumerate() {
+            let t = i + period - 1;
+            let source = match price_source {
+                PriceSource::Close => close[t],
+                PriceSource::MedianPrice => (highs[t] + lows[t]) / 2.0,
+                PriceSource::TypicalPrice => (highs[t] + lows[t] + close[t]) / 3.0,
+            };
+            let basic_upper = source + multiplier * atr;
+            let basic_lower = source - multiplier * atr;
+            let mut flipped = false;
+            if i == 0 {
+                final_upper = basic_upper;
+                final_lower = basic_lower;
+            } else {
+                if basic_upper < final_upper || close[t - 1] > final_upper {
+                    final_upper = basic_upper;
+                }
+                if basic_lower > final_lower || close[t - 1] < final_lower {
+                    final_lower = basic_lower;
+                }
+                match position {
+                    Position::Long if close[t] < final_lower => {
+                        position = Position::Short;
+                        flipped = true;
+                    }
+                    Position::Short if close[t] > final_upper => {
+                        position = Position::Long;
+                        flipped = true;
+                    }
+                    _ => {}
+                }
+            }
+            let level = match position {
+                Position::Long => final_lower,
+                Position::Short => final_upper,
+            };
+            supertrend.push((level, position, flipped));
+        }
+        Ok(supertrend)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{IchimokuBar, Position};
 
     #[test]
     fn single_ma_moving_constant_envelope() {
@@ -1324,7 +1765,7 @@ mod tests {
             (97.0, 100.0, 103.0),
             single::moving_constant_envelopes(
                 &prices,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 3.0
             )
             .unwrap()
@@ -1336,7 +1777,7 @@ mod tests {
         let prices = Vec::new();
         let result = single::moving_constant_envelopes(
             &prices,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             3.0,
         );
         assert!(result.is_err());
@@ -1514,7 +1955,7 @@ mod tests {
             (99.73089778893514, 100.0, 100.26910221106486),
             single::moving_constant_bands(
                 &prices,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 crate::DeviationModel::StandardDeviation,
                 2.0
             )
@@ -1560,7 +2001,7 @@ mod tests {
             single::moving_constant_bands(
                 &prices,
                 crate::ConstantModelType::SimpleMovingAverage,
-                crate::DeviationModel::ModeAbsoluteDeviation,
+                crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
                 2.0
             )
             .unwrap()
@@ -1585,12 +2026,13 @@ mod tests {
     #[test]
     fn single_constant_bands_error() {
         let prices = Vec::new();
-        single::moving_constant_bands(
+        assert!(single::moving_constant_bands(
             &prices,
             crate::ConstantModelType::SimpleMovingAverage,
-            crate::DeviationModel::ModeAbsoluteDeviation,
+            crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -1678,7 +2120,7 @@ mod tests {
             (100.21, 100.21, 100.21),
             single::mcginley_dynamic_bands(
                 &prices,
-                crate::DeviationModel::ModeAbsoluteDeviation,
+                crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
                 2.0,
                 0.0
             )
@@ -2085,7 +2527,7 @@ mod tests {
                 &highs,
                 &lows,
                 &close,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 crate::ConstantModelType::SimpleMovingAverage,
                 2.0
             )
@@ -2098,14 +2540,15 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 98.98, 99.07];
         let close = vec![100.94, 101.27, 100.55, 99.01, 100.43];
-        single::keltner_channel(
+        assert!(single::keltner_channel(
             &highs,
             &lows,
             &close,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2113,14 +2556,15 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.69, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 99.07];
         let close = vec![100.94, 101.27, 100.55, 99.01, 100.43];
-        single::keltner_channel(
+        assert!(single::keltner_channel(
             &highs,
             &lows,
             &close,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2128,14 +2572,15 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.69, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 98.98, 99.07];
         let close = vec![100.94, 101.27, 100.55, 100.43];
-        single::keltner_channel(
+        assert!(single::keltner_channel(
             &highs,
             &lows,
             &close,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2143,14 +2588,15 @@ mod tests {
         let highs = Vec::new();
         let lows = Vec::new();
         let close = Vec::new();
-        single::keltner_channel(
+        assert!(single::keltner_channel(
             &highs,
             &lows,
             &close,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2285,13 +2731,14 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 98.98, 99.07];
         let close = vec![100.94, 101.27, 100.55, 99.01, 100.43];
-        single::supertrend(
+        assert!(single::supertrend(
             &highs,
             &lows,
             &close,
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2299,13 +2746,14 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.69, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 99.07];
         let close = vec![100.94, 101.27, 100.55, 99.01, 100.43];
-        single::supertrend(
+        assert!(single::supertrend(
             &highs,
             &lows,
             &close,
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2313,13 +2761,14 @@ mod tests {
         let highs = vec![101.26, 102.57, 102.32, 100.69, 100.83];
         let lows = vec![100.08, 98.75, 100.14, 98.98, 99.07];
         let close = vec![100.94, 101.27, 100.55, 100.43];
-        single::supertrend(
+        assert!(single::supertrend(
             &highs,
             &lows,
             &close,
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2327,13 +2776,14 @@ mod tests {
         let highs = Vec::new();
         let lows = Vec::new();
         let close = Vec::new();
-        single::supertrend(
+        assert!(single::supertrend(
             &highs,
             &lows,
             &close,
             crate::ConstantModelType::SimpleMovingAverage,
             2.0,
-        );
+        )
+        .is_err());
     }
 
     #[test]
@@ -2503,4 +2953,332 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn bulk_extended_supertrend_median_price() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        assert_eq!(
+            vec![
+                (109.5, crate::Position::Short, false),
+                (104.66666666666667, crate::Position::Short, false),
+                (104.66666666666667, crate::Position::Short, false),
+                (98.5, crate::Position::Long, true),
+                (101.16666666666667, crate::Position::Long, false)
+            ],
+            bulk::extended_supertrend(
+                &highs,
+                &lows,
+                &close,
+                crate::ConstantModelType::SimpleMovingAverage,
+                1.0,
+                3,
+                crate::PriceSource::MedianPrice
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_extended_supertrend_close_source() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        assert_eq!(
+            vec![
+                (107.0, crate::Position::Short, false),
+                (104.66666666666667, crate::Position::Short, false),
+                (104.66666666666667, crate::Position::Short, false),
+                (100.0, crate::Position::Long, true),
+                (100.66666666666667, crate::Position::Long, false)
+            ],
+            bulk::extended_supertrend(
+                &highs,
+                &lows,
+                &close,
+                crate::ConstantModelType::SimpleMovingAverage,
+                1.0,
+                3,
+                crate::PriceSource::Close
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_extended_supertrend_typical_price_len() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        let supertrend = bulk::extended_supertrend(
+            &highs,
+            &lows,
+            &close,
+            crate::ConstantModelType::ExponentialMovingAverage,
+            2.0,
+            4,
+            crate::PriceSource::TypicalPrice,
+        )
+        .unwrap();
+        assert_eq!(4, supertrend.len());
+        assert!(!supertrend[0].2);
+    }
+
+    #[test]
+    fn bulk_extended_supertrend_non_positive_multiplier() {
+        let prices = vec![100.0, 102.0, 103.0];
+        assert!(bulk::extended_supertrend(
+            &prices,
+            &prices,
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            0.0,
+            2,
+            crate::PriceSource::Close
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_extended_supertrend_mismatched_lengths() {
+        let prices = vec![100.0, 102.0, 103.0];
+        assert!(bulk::extended_supertrend(
+            &prices,
+            &prices[..2],
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            1.0,
+            2,
+            crate::PriceSource::Close
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_displaced_ichimoku_cloud() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        let ichimoku = bulk::displaced_ichimoku_cloud(&highs, &lows, &close, 2, 3, 4, 2).unwrap();
+        assert_eq!(9, ichimoku.len());
+        assert_eq!(
+            IchimokuBar {
+                conversion_line: None,
+                base_line: None,
+                leading_span_a: None,
+                leading_span_b: None,
+                lagging_span: Some(100.0),
+                tk_cross: None,
+                kumo_breakout: None,
+                cloud_colour: None,
+            },
+            ichimoku[0]
+        );
+        assert_eq!(
+            IchimokuBar {
+                conversion_line: Some(100.0),
+                base_line: Some(101.0),
+                leading_span_a: Some(102.25),
+                leading_span_b: None,
+                lagging_span: Some(108.0),
+                tk_cross: Some(Position::Short),
+                kumo_breakout: None,
+                cloud_colour: None,
+            },
+            ichimoku[4]
+        );
+        assert_eq!(
+            IchimokuBar {
+                conversion_line: Some(106.5),
+                base_line: Some(103.0),
+                leading_span_a: Some(100.5),
+                leading_span_b: Some(101.0),
+                lagging_span: None,
+                tk_cross: Some(Position::Long),
+                kumo_breakout: None,
+                cloud_colour: Some(Position::Short),
+            },
+            ichimoku[6]
+        );
+        assert_eq!(
+            IchimokuBar {
+                conversion_line: None,
+                base_line: None,
+                leading_span_a: Some(104.75),
+                leading_span_b: Some(103.0),
+                lagging_span: None,
+                tk_cross: None,
+                kumo_breakout: None,
+                cloud_colour: Some(Position::Long),
+            },
+            ichimoku[8]
+        );
+    }
+
+    #[test]
+    fn bulk_displaced_ichimoku_cloud_kumo_breakouts() {
+        let highs = vec![
+            105.0, 103.0, 107.0, 101.0, 103.0, 100.0, 109.0, 105.0, 110.0, 112.0, 111.0, 105.0,
+            106.0, 100.0, 103.0, 102.0, 98.0,
+        ];
+        let lows = vec![
+            97.0, 99.0, 98.0, 100.0, 95.0, 98.0, 99.0, 100.0, 102.0, 106.0, 99.0, 101.0, 98.0,
+            93.0, 98.0, 91.0, 89.0,
+        ];
+        let close = vec![
+            100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0, 103.0, 106.0, 107.0, 105.0, 104.0,
+            101.0, 97.0, 100.0, 96.0, 93.0,
+        ];
+        let breakouts: Vec<(usize, Position)> =
+            bulk::displaced_ichimoku_cloud(&highs, &lows, &close, 2, 3, 4, 2)
+                .unwrap()
+                .iter()
+                .enumerate()
+                .filter_map(|(i, bar)| bar.kumo_breakout.map(|side| (i, side)))
+                .collect();
+        assert_eq!(vec![(6, Position::Long), (11, Position::Short)], breakouts);
+    }
+
+    #[test]
+    fn bulk_displaced_ichimoku_cloud_zero_displacement() {
+        let prices = vec![100.0, 102.0, 103.0];
+        assert!(bulk::displaced_ichimoku_cloud(&prices, &prices, &prices, 1, 2, 3, 0).is_err());
+    }
+
+    #[test]
+    fn bulk_displaced_ichimoku_cloud_period_too_long() {
+        let prices = vec![100.0, 102.0, 103.0];
+        assert!(bulk::displaced_ichimoku_cloud(&prices, &prices, &prices, 1, 2, 4, 1).is_err());
+    }
+
+    #[test]
+    fn single_percent_b() {
+        assert_eq!(0.75, single::percent_b(102.5, (98.0, 101.0, 104.0)));
+        assert_eq!(-0.25, single::percent_b(96.5, (98.0, 101.0, 104.0)));
+    }
+
+    #[test]
+    fn single_percent_b_flat_bands() {
+        assert_eq!(0.5, single::percent_b(102.0, (101.0, 101.0, 101.0)));
+    }
+
+    #[test]
+    fn single_bandwidth() {
+        assert_eq!(0.06, single::bandwidth((97.0, 100.0, 103.0)).unwrap());
+    }
+
+    #[test]
+    fn single_bandwidth_zero_middle() {
+        assert!(single::bandwidth((-1.0, 0.0, 1.0)).is_err());
+    }
+
+    #[test]
+    fn bulk_percent_b_keltner() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0];
+        let keltner = bulk::keltner_channel(
+            &highs,
+            &lows,
+            &close,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::ConstantModelType::SimpleMovingAverage,
+            1.0,
+            3,
+        )
+        .unwrap();
+        let expected: Vec<f64> = keltner
+            .iter()
+            .zip(&close[2..])
+            .map(|(&bands, &price)| single::percent_b(price, bands))
+            .collect();
+        assert_eq!(expected, bulk::percent_b(&close, &keltner).unwrap());
+    }
+
+    #[test]
+    fn bulk_percent_b_too_many_bands() {
+        let bands = vec![(98.0, 101.0, 104.0); 3];
+        assert!(bulk::percent_b(&[100.0, 101.0], &bands).is_err());
+    }
+
+    #[test]
+    fn bulk_percent_b_empty_bands() {
+        assert!(bulk::percent_b(&[100.0, 101.0], &[]).is_err());
+    }
+
+    #[test]
+    fn bulk_bandwidth() {
+        assert_eq!(
+            vec![0.06, 0.08],
+            bulk::bandwidth(&[(97.0, 100.0, 103.0), (96.0, 100.0, 104.0)]).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_squeeze_momentum_off() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        let squeeze = bulk::squeeze_momentum(
+            &highs,
+            &lows,
+            &close,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            2.0,
+            crate::ConstantModelType::SimpleMovingAverage,
+            0.1,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                (false, 2.027777777777781),
+                (false, 4.9722222222222285),
+                (false, 3.7222222222222334)
+            ],
+            squeeze
+        );
+    }
+
+    #[test]
+    fn bulk_squeeze_momentum_median_mad() {
+        let highs = vec![105.0, 103.0, 107.0, 101.0, 105.0, 109.0, 111.0];
+        let lows = vec![97.0, 99.0, 98.0, 97.0, 95.0, 102.0, 106.0];
+        let close = vec![101.0, 102.0, 100.0, 99.0, 104.0, 107.0, 108.0];
+        let squeeze = bulk::squeeze_momentum(
+            &highs,
+            &lows,
+            &close,
+            crate::ConstantModelType::SimpleMovingMedian,
+            crate::DeviationModel::MedianAbsoluteDeviation,
+            2.0,
+            crate::ConstantModelType::SimpleMovingAverage,
+            1.5,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![(true, 2.5), (true, 4.833333333333333), (true, 3.25)],
+            squeeze
+        );
+    }
+
+    #[test]
+    fn bulk_squeeze_momentum_too_short() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0];
+        assert!(bulk::squeeze_momentum(
+            &prices,
+            &prices,
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            2.0,
+            crate::ConstantModelType::SimpleMovingAverage,
+            1.5,
+            3,
+        )
+        .is_err());
+    }
 }
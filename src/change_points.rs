@@ -0,0 +1,674 @@
+//! # Change Points
+//!
+//! The `change_points` module splits a price series into segments where its behaviour
+//! changes, as an alternative to the threshold heuristics of
+//! [`break_down_trends`](crate::chart_trends::break_down_trends).
+//!
+//! ## When to Use
+//! Use change point detectors when you want to:
+//! - Find where the mean level of a series shifts (CUSUM, PELT, Bayesian online)
+//! - Find where volatility or the trend changes (PELT with a variance or linear trend cost)
+//! - Compare segmentations of the same data across methods
+//!
+//! ## Structure
+//! Like `chart_trends`, `change_points` does not have `single` or `bulk` submodules.
+//! All functions operate over the whole slice and return trend segments.
+//!
+//! ## Included Functions
+//! - [`cusum`]: Two-sided CUSUM mean shift detection
+//! - [`pelt`]: Pruned Exact Linear Time optimal segmentation with a [`ChangePointCost`]
+//! - [`bayesian_online`]: Bayesian Online Change-Point Detection
+//!
+//! ## API Details
+//! - Every function returns `(start, end, slope, intercept)` segments like
+//!   [`break_down_trends`](crate::chart_trends::break_down_trends), with the line fitted to the
+//!   prices of the segment against their index in the whole slice.
+//! - Segments do not overlap: each starts at the index after the previous one ends.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+use crate::basic_indicators::single::{mean, variance};
+use crate::chart_trends::get_trend_line;
+use crate::validation::{
+    assert_min_period, assert_non_empty, assert_non_negative, assert_period, assert_positive,
+};
+use crate::TechnicalIndicatorError;
+
+/// Cost of a segment used by [`pelt`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChangePointCost {
+    /// Squared deviations from the segment mean, for shifts in level
+    Mean,
+    /// Gaussian likelihood with the segment mean and variance, for shifts in volatility
+    Variance,
+    /// Squared residuals of a least squares line, for changes in trend
+    LinearTrend,
+}
+
+/// Detects mean shifts with a two-sided CUSUM.
+///
+/// Each segment keeps a running mean of its prices. Deviations of every new price from that
+/// mean, less `drift`, are accumulated separately upwards and downwards, and a change is
+/// flagged when either sum exceeds `threshold`. The new segment starts after the last time the
+/// flagging sum was zero, which estimates when the shift began.
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `threshold` - Accumulated deviation, in price units, that flags a change
+/// * `drift` - Deviation, in price units, ignored at each step (0.0 to accumulate all of it)
+///
+/// # Returns
+///
+/// A vector of `(start, end, slope, intercept)` segments
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * `threshold` <= 0.0
+/// * `drift` < 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// let prices = vec![
+///     100.0, 100.5, 99.5, 100.2, 99.8, 105.0, 105.4, 104.6, 105.2, 104.9
+/// ];
+/// let segments =
+///     centaur_technical_indicators::change_points::cusum(&prices, 4.0, 0.5).unwrap();
+/// assert_eq!(
+///     vec![
+///         (0, 4, -0.07000000000000028, 100.14),
+///         (5, 9, -0.03999999999999915, 105.30000000000001)
+///     ],
+///     segments
+/// );
+/// ```
+pub fn cusum(
+    prices: &[f64],
+    threshold: f64,
+    drift: f64,
+) -> crate::Result<Vec<(usize, usize, f64, f64)>> {
+    assert_non_empty("prices", prices)?;
+    assert_positive("threshold", threshold)?;
+    assert_non_negative("drift", drift)?;
+
+    let mut starts = vec![0];
+    let mut start = 0;
+    let mut index = 1;
+    let mut sum = prices[0];
+    let (mut upper, mut lower) = (0.0, 0.0);
+    let (mut upper_reset, mut lower_reset) = (0, 0);
+    while index < prices.len() {
+        let price = prices[index];
+        let segment_mean = sum / (index - start) as f64;
+        upper = f64::max(0.0, upper + price - segment_mean - drift);
+        lower = f64::max(0.0, lower + segment_mean - price - drift);
+        if upper == 0.0 {
+            upper_reset = index;
+        }
+        if lower == 0.0 {
+            lower_reset = index;
+        }
+
+        if upper > threshold || lower > threshold {
+            start = if upper > threshold {
+                upper_reset + 1
+            } else {
+                lower_reset + 1
+            };
+            starts.push(start);
+            index = start + 1;
+            sum = prices[start];
+            (upper, lower) = (0.0, 0.0);
+            (upper_reset, lower_reset) = (start, start);
+            continue;
+        }
+        sum += price;
+        index += 1;
+    }
+    Ok(segments(prices, &starts))
+}
+
+/// Finds the optimal segmentation with the Pruned Exact Linear Time (PELT) algorithm.
+///
+/// Minimises the total cost of the segments plus `penalty` for every segment, pruning
+/// candidate change points that can no longer be optimal so the search stays close to linear.
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `cost` - Variant of [`ChangePointCost`]
+/// * `penalty` - Cost of adding a segment; larger values give fewer segments. A common
+///   choice is `k * ln(prices.len())` (BIC), with `k` the number of parameters per segment.
+/// * `min_segment_length` - Minimum number of prices in a segment
+///
+/// # Returns
+///
+/// A vector of `(start, end, slope, intercept)` segments
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `penalty` < 0.0
+/// * `min_segment_length` < 2
+/// * `min_segment_length` > `prices.len()`
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::change_points::ChangePointCost;
+///
+/// let prices = vec![
+///     100.0, 100.5, 99.5, 100.2, 99.8, 105.0, 105.4, 104.6, 105.2, 104.9
+/// ];
+/// let segments = centaur_technical_indicators::change_points::pelt(
+///     &prices,
+///     ChangePointCost::Mean,
+///     5.0,
+///     2
+/// ).unwrap();
+/// assert_eq!(
+///     vec![
+///         (0, 4, -0.07000000000000028, 100.14),
+///         (5, 9, -0.03999999999999915, 105.30000000000001)
+///     ],
+///     segments
+/// );
+/// ```
+pub fn pelt(
+    prices: &[f64],
+    cost: ChangePointCost,
+    penalty: f64,
+    min_segment_length: usize,
+) -> crate::Result<Vec<(usize, usize, f64, f64)>> {
+    assert_min_period(min_segment_length, 2, prices.len())?;
+    assert_non_negative("penalty", penalty)?;
+
+    let length = prices.len();
+    let sums = PrefixSums::new(prices);
+    let mut optimal_cost = vec![f64::INFINITY; length + 1];
+    optimal_cost[0] = -penalty;
+    let mut previous_change = vec![0; length + 1];
+    let mut candidates: Vec<usize> = vec![0];
+
+    for end in min_segment_length..=length {
+        let (best_start, best_cost) = candidates
+            .iter()
+            .filter(|&&start| end - start >= min_segment_length)
+            .map(|&start| {
+                (
+                    start,
+                    optimal_cost[start] + sums.cost(cost, start, end) + penalty,
+                )
+            })
+            .fold((0, f64::INFINITY), |best, candidate| {
+                if candidate.1 < best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+        optimal_cost[end] = best_cost;
+        previous_change[end] = best_start;
+
+        candidates.retain(|&start| {
+            end - start < min_segment_length
+                || optimal_cost[start] + sums.cost(cost, start, end) <= best_cost
+        });
+        candidates.push(end);
+    }
+
+    let mut starts = Vec::new();
+    let mut end = length;
+    while end > 0 {
+        end = previous_change[end];
+        starts.push(end);
+    }
+    starts.reverse();
+    Ok(segments(prices, &starts))
+}
+
+/// Run lengths whose probability falls below about 1e-10 are dropped by [`bayesian_online`].
+const MIN_RUN_LOG_PROBABILITY: f64 = -23.0;
+/// Most run lengths [`bayesian_online`] keeps after each price.
+const MAX_RUNS: usize = 500;
+
+/// Detects changes in the mean with Bayesian Online Change-Point Detection (Adams & MacKay).
+///
+/// Tracks the probability of each run length, the number of prices since the last change,
+/// under a Gaussian model with unknown mean and variance (Normal-Gamma prior) and a constant
+/// hazard rate. The prior is centred on the mean and variance of the first `warm_up_period`
+/// prices, so no later price affects it. Segments are read back from the most likely run
+/// length, starting from the last price.
+///
+/// Run lengths less likely than about 1e-10 are dropped at each step, and only the 500 most
+/// likely are kept, so the cost is O(n) with at most 500 run lengths updated per price.
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `hazard_rate` - Prior probability of a change at each price, e.g. 1/250 for one change
+///   a year in daily data
+/// * `warm_up_period` - Number of leading prices the prior mean and variance are taken from
+///
+/// # Returns
+///
+/// A vector of `(start, end, slope, intercept)` segments
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * `hazard_rate` <= 0.0 or `hazard_rate` >= 1.0
+/// * `warm_up_period` == 0 or `warm_up_period` > `prices.len()`
+///
+/// # Examples
+///
+/// ```rust
+/// let prices = vec![
+///     100.0, 100.5, 99.5, 100.2, 99.8, 105.0, 105.4, 104.6, 105.2, 104.9
+/// ];
+/// let segments =
+///     centaur_technical_indicators::change_points::bayesian_online(&prices, 0.1, 5).unwrap();
+/// assert_eq!(
+///     vec![
+///         (0, 4, -0.07000000000000028, 100.14),
+///         (5, 9, -0.03999999999999915, 105.30000000000001)
+///     ],
+///     segments
+/// );
+/// ```
+pub fn bayesian_online(
+    prices: &[f64],
+    hazard_rate: f64,
+    warm_up_period: usize,
+) -> crate::Result<Vec<(usize, usize, f64, f64)>> {
+    assert_non_empty("prices", prices)?;
+    assert_positive("hazard_rate", hazard_rate)?;
+    if hazard_rate >= 1.0 {
+        return Err(TechnicalIndicatorError::InvalidValue {
+            name: "hazard_rate".to_string(),
+            value: hazard_rate,
+            reason: "must be less than 1".to_string(),
+        });
+    }
+    assert_period(warm_up_period, prices.len())?;
+
+    let warm_up = &prices[..warm_up_period];
+    let prior = NormalGamma {
+        mean: mean(warm_up)?,
+        kappa: 1.0,
+        alpha: 1.0,
+        beta: variance(warm_up)?.max(f64::MIN_POSITIVE),
+    };
+    let (log_hazard, log_survival) = (hazard_rate.ln(), (1.0 - hazard_rate).ln());
+
+    // Each run holds its length before the current price, log probability and posterior
+    let mut runs = vec![Run {
+        length: 0,
+        log_probability: 0.0,
+        posterior: prior,
+    }];
+    let mut starts = Vec::with_capacity(prices.len());
+    for (index, &price) in prices.iter().enumerate() {
+        let log_predictive: Vec<f64> = runs
+            .iter()
+            .map(|run| run.log_probability + run.posterior.log_predictive(price))
+            .collect();
+
+        let mut next = Vec::with_capacity(runs.len() + 1);
+        next.push(Run {
+            length: 0,
+            log_probability: log_sum_exp(&log_predictive) + log_hazard,
+            posterior: prior,
+        });
+        next.extend(runs.iter().zip(&log_predictive).map(|(run, p)| Run {
+            length: run.length + 1,
+            log_probability: p + log_survival,
+            posterior: run.posterior.update(price),
+        }));
+        let normaliser = log_sum_exp(
+            &next
+                .iter()
+                .map(|run| run.log_probability)
+                .collect::<Vec<f64>>(),
+        );
+        next.iter_mut()
+            .for_each(|run| run.log_probability -= normaliser);
+
+        // The most likely run that includes this price
+        let run_length = next[1..]
+            .iter()
+            .max_by(|a, b| a.log_probability.total_cmp(&b.log_probability))
+            .map_or(1, |run| run.length);
+        starts.push(index + 1 - run_length);
+
+        next.retain(|run| run.length == 0 || run.log_probability >= MIN_RUN_LOG_PROBABILITY);
+        if next.len() > MAX_RUNS {
+            // Keep the change point run and the most likely of the longer runs
+            next[1..].sort_by(|a, b| b.log_probability.total_cmp(&a.log_probability));
+            next.truncate(MAX_RUNS);
+            next[1..].sort_by_key(|run| run.length);
+        }
+        runs = next;
+    }
+
+    let mut segment_starts = Vec::new();
+    let mut end = prices.len();
+    while end > 0 {
+        end = starts[end - 1];
+        segment_starts.push(end);
+    }
+    segment_starts.reverse();
+    Ok(segments(prices, &segment_starts))
+}
+
+/// Builds `(start, end, slope, intercept)` segments from the start index of each segment.
+fn segments(prices: &[f64], starts: &[usize]) -> Vec<(usize, usize, f64, f64)> {
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).map_or(prices.len(), |&next| next) - 1;
+            if start == end {
+                return (start, end, 0.0, prices[start]);
+            }
+            let indexed_points: Vec<(f64, usize)> =
+                (start..=end).map(|index| (prices[index], index)).collect();
+            let (slope, intercept) = get_trend_line(&indexed_points);
+            (start, end, slope, intercept)
+        })
+        .collect()
+}
+
+/// Prefix sums giving the cost of any segment in constant time.
+struct PrefixSums {
+    y: Vec<f64>,
+    yy: Vec<f64>,
+    xy: Vec<f64>,
+}
+
+impl PrefixSums {
+    fn new(prices: &[f64]) -> Self {
+        let offset = prices[0];
+        let mut sums = PrefixSums {
+            y: vec![0.0],
+            yy: vec![0.0],
+            xy: vec![0.0],
+        };
+        for (x, price) in prices.iter().enumerate() {
+            let y = price - offset;
+            sums.y.push(sums.y[x] + y);
+            sums.yy.push(sums.yy[x] + y * y);
+            sums.xy.push(sums.xy[x] + x as f64 * y);
+        }
+        sums
+    }
+
+    /// Cost of the prices from `start` up to, but excluding, `end`.
+    fn cost(&self, cost: ChangePointCost, start: usize, end: usize) -> f64 {
+        let n = (end - start) as f64;
+        let sum_y = self.y[end] - self.y[start];
+        let squared_deviations = (self.yy[end] - self.yy[start] - sum_y * sum_y / n).max(0.0);
+        match cost {
+            ChangePointCost::Mean => squared_deviations,
+            ChangePointCost::Variance => n * (squared_deviations / n).max(f64::EPSILON).ln(),
+            ChangePointCost::LinearTrend => {
+                // Sums of x over the segment, with x measured from its start
+                let sum_x = n * (n - 1.0) / 2.0;
+                let sum_xx = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+                let sum_xy = self.xy[end] - self.xy[start] - start as f64 * sum_y;
+                let s_xx = sum_xx - sum_x * sum_x / n;
+                let s_xy = sum_xy - sum_x * sum_y / n;
+                (squared_deviations - s_xy * s_xy / s_xx).max(0.0)
+            }
+        }
+    }
+}
+
+/// A run length tracked by [`bayesian_online`].
+#[derive(Debug, Copy, Clone)]
+struct Run {
+    /// Number of prices in the run before the current one
+    length: usize,
+    log_probability: f64,
+    posterior: NormalGamma,
+}
+
+/// Normal-Gamma posterior over the mean and precision of a run.
+#[derive(Debug, Copy, Clone)]
+struct NormalGamma {
+    mean: f64,
+    kappa: f64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl NormalGamma {
+    /// Posterior after observing `price`.
+    fn update(&self, price: f64) -> Self {
+        NormalGamma {
+            mean: (self.kappa * self.mean + price) / (self.kappa + 1.0),
+            kappa: self.kappa + 1.0,
+            alpha: self.alpha + 0.5,
+            beta: self.beta + self.kappa * (price - self.mean).powi(2) / (2.0 * (self.kappa + 1.0)),
+        }
+    }
+
+    /// Log density of `price` under the Student-t posterior predictive.
+    fn log_predictive(&self, price: f64) -> f64 {
+        let degrees_of_freedom = 2.0 * self.alpha;
+        let scale_squared = self.beta * (self.kappa + 1.0) / (self.alpha * self.kappa);
+        let z_squared = (price - self.mean).powi(2) / scale_squared;
+        ln_gamma((degrees_of_freedom + 1.0) / 2.0)
+            - ln_gamma(degrees_of_freedom / 2.0)
+            - 0.5 * (degrees_of_freedom * std::f64::consts::PI * scale_squared).ln()
+            - (degrees_of_freedom + 1.0) / 2.0 * (1.0 + z_squared / degrees_of_freedom).ln()
+    }
+}
+
+/// Natural log of the gamma function for positive `x` (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1.0)
+        });
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// Log of the sum of the exponentials of `values`.
+fn log_sum_exp(values: &[f64]) -> f64 {
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + values.iter().map(|v| (v - max).exp()).sum::<f64>().ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cusum_flat_prices() {
+        let prices = vec![100.0; 6];
+        assert_eq!(vec![(0, 5, 0.0, 100.0)], cusum(&prices, 1.0, 0.0).unwrap());
+    }
+
+    #[test]
+    fn cusum_downward_shift() {
+        let prices = vec![105.0, 105.4, 104.6, 105.2, 100.0, 100.5, 99.5, 100.2];
+        let starts: Vec<usize> = cusum(&prices, 4.0, 0.5)
+            .unwrap()
+            .iter()
+            .map(|segment| segment.0)
+            .collect();
+        assert_eq!(vec![0, 4], starts);
+    }
+
+    #[test]
+    fn cusum_single_price() {
+        assert_eq!(vec![(0, 0, 0.0, 100.0)], cusum(&[100.0], 1.0, 0.0).unwrap());
+    }
+
+    #[test]
+    fn cusum_non_positive_threshold() {
+        assert!(cusum(&[100.0, 101.0], 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn cusum_negative_drift() {
+        assert!(cusum(&[100.0, 101.0], 1.0, -0.1).is_err());
+    }
+
+    #[test]
+    fn pelt_variance_cost() {
+        let prices = vec![
+            100.0, 100.1, 99.9, 100.0, 100.1, 99.9, 100.0, 103.0, 97.0, 104.0, 96.0, 103.0, 97.0,
+            100.0,
+        ];
+        let starts: Vec<usize> = pelt(&prices, ChangePointCost::Variance, 5.0, 3)
+            .unwrap()
+            .iter()
+            .map(|segment| segment.0)
+            .collect();
+        assert_eq!(vec![0, 7], starts);
+    }
+
+    #[test]
+    fn pelt_linear_trend_cost() {
+        let prices = vec![
+            100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 104.0, 103.0, 102.0, 101.0, 100.0,
+        ];
+        assert_eq!(
+            vec![(0, 4, 1.0, 100.0), (5, 10, -1.0, 110.0)],
+            pelt(&prices, ChangePointCost::LinearTrend, 1.0, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn pelt_large_penalty_single_segment() {
+        let prices = vec![
+            100.0, 100.5, 99.5, 100.2, 99.8, 105.0, 105.4, 104.6, 105.2, 104.9,
+        ];
+        assert_eq!(
+            1,
+            pelt(&prices, ChangePointCost::Mean, 1000.0, 2)
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn pelt_min_segment_length_too_short() {
+        assert!(pelt(&[100.0, 101.0, 102.0], ChangePointCost::Mean, 1.0, 1).is_err());
+    }
+
+    #[test]
+    fn pelt_min_segment_length_too_long() {
+        assert!(pelt(&[100.0, 101.0, 102.0], ChangePointCost::Mean, 1.0, 4).is_err());
+    }
+
+    #[test]
+    fn pelt_negative_penalty() {
+        assert!(pelt(&[100.0, 101.0, 102.0], ChangePointCost::Mean, -1.0, 2).is_err());
+    }
+
+    #[test]
+    fn bayesian_online_flat_prices() {
+        let prices = vec![100.0; 6];
+        assert_eq!(
+            vec![(0, 5, 0.0, 100.0)],
+            bayesian_online(&prices, 0.01, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn bayesian_online_invalid_hazard_rate() {
+        let prices = vec![100.0, 101.0];
+        assert!(bayesian_online(&prices, 0.0, 1).is_err());
+        assert!(bayesian_online(&prices, 1.0, 1).is_err());
+    }
+
+    #[test]
+    fn bayesian_online_invalid_warm_up_period() {
+        let prices = vec![100.0, 101.0];
+        assert!(bayesian_online(&prices, 0.1, 0).is_err());
+        assert!(bayesian_online(&prices, 0.1, 3).is_err());
+    }
+
+    #[test]
+    fn bayesian_online_empty() {
+        assert!(bayesian_online(&[], 0.1, 1).is_err());
+    }
+
+    #[test]
+    fn bayesian_online_ignores_prices_after_warm_up() {
+        // Later prices do not move the prior, so the first segments are unchanged
+        let prices = vec![
+            100.0, 100.5, 99.5, 100.2, 99.8, 105.0, 105.4, 104.6, 105.2, 104.9,
+        ];
+        let mut extended = prices.clone();
+        extended.extend([150.0, 151.0, 149.5, 150.5]);
+        let segments = bayesian_online(&prices, 0.1, 5).unwrap();
+        let extended_segments = bayesian_online(&extended, 0.1, 5).unwrap();
+        assert_eq!(segments[0], extended_segments[0]);
+        assert_eq!((10, 13), (extended_segments[2].0, extended_segments[2].1));
+    }
+
+    #[test]
+    fn bayesian_online_bounded_runs_find_shift() {
+        // Stationary noise keeps every run plausible, so this runs past the run length cap
+        let mut state: u64 = 42;
+        let prices: Vec<f64> = (0..4_000)
+            .map(|i| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let noise = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+                if i < 2_000 {
+                    100.0 + noise
+                } else {
+                    105.0 + noise
+                }
+            })
+            .collect();
+        let segments = bayesian_online(&prices, 1.0 / 250.0, 100).unwrap();
+        assert_eq!(
+            vec![(0, 1_999), (2_000, 3_999)],
+            segments
+                .iter()
+                .map(|segment| (segment.0, segment.1))
+                .collect::<Vec<(usize, usize)>>()
+        );
+    }
+
+    #[test]
+    fn ln_gamma_known_values() {
+        assert!((ln_gamma(5.0) - 24.0_f64.ln()).abs() < 1e-12);
+        assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+        assert!(ln_gamma(1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,741 @@
+//! # Chart Patterns
+//!
+//! The `chart_patterns` module recognises classical chart patterns in the swing highs and lows
+//! found by [`peaks`] and [`valleys`].
+//!
+//! ## When to Use
+//! Use chart pattern recognition when you want to:
+//! - Scan for reversal patterns: double and triple tops and bottoms, head and shoulders
+//! - Scan for continuation patterns: triangles, wedges, flags and rectangles
+//! - Measure the height of a pattern for a price target, and find where it broke out
+//!
+//! ## Structure
+//! Like `chart_trends`, `chart_patterns` does not have `single` or `bulk` submodules.
+//! [`find_chart_patterns`] scans the whole slice and returns every pattern found.
+//!
+//! ## Included Functions
+//! - [`find_chart_patterns`]: Recognises every [`ChartPatternType`] in the swing points
+//!
+//! ## API Details
+//! - Swing highs are the [`peaks`] of the highs and swing lows the
+//!   [`valleys`] of the lows. Consecutive swings of the same kind
+//!   are merged, keeping the more extreme one, so highs and lows alternate, and a bar is at
+//!   most one swing point.
+//! - Tolerances are grouped in [`ChartPatternConfig`], in the style of
+//!   [`TrendBreakConfig`](crate::chart_trends::TrendBreakConfig).
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+use crate::chart_trends::{get_trend_line, peaks, valleys};
+use crate::validation::{assert_non_empty, assert_non_negative, assert_same_len};
+use crate::Position;
+
+/// Type of a pattern found by [`find_chart_patterns`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChartPatternType {
+    DoubleTop,
+    DoubleBottom,
+    TripleTop,
+    TripleBottom,
+    HeadAndShoulders,
+    InverseHeadAndShoulders,
+    /// Flat upper boundary, rising lower boundary
+    AscendingTriangle,
+    /// Falling upper boundary, flat lower boundary
+    DescendingTriangle,
+    /// Falling upper boundary, rising lower boundary
+    SymmetricTriangle,
+    /// Both boundaries rising and converging
+    RisingWedge,
+    /// Both boundaries falling and converging
+    FallingWedge,
+    /// Falling parallel channel after a sharp rise
+    BullFlag,
+    /// Rising parallel channel after a sharp fall
+    BearFlag,
+    /// Flat upper and lower boundaries
+    Rectangle,
+}
+
+/// A chart pattern found by [`find_chart_patterns`].
+///
+/// Lines are `(slope, intercept)` pairs against the index in the whole slice, like the output
+/// of [`overall_trend`](crate::chart_trends::overall_trend). For tops and head and shoulders the
+/// lower line is the neckline; for bottoms and inverse head and shoulders it is the upper line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPattern {
+    pub pattern_type: ChartPatternType,
+    /// Indices of the alternating swing highs and lows forming the pattern
+    pub pivots: Vec<usize>,
+    /// Line through the swing highs (through the shoulders for head and shoulders)
+    pub upper_line: (f64, f64),
+    /// Line through the swing lows (through the shoulders for inverse head and shoulders)
+    pub lower_line: (f64, f64),
+    /// Index and direction of the first close beyond the pattern after its last pivot, `None`
+    /// if there is none yet or, for reversal patterns, if price first closed beyond the tops or
+    /// bottoms instead of the neckline
+    pub breakout: Option<(usize, Position)>,
+    /// Largest distance between a pivot and the opposite line, or the flagpole for flags
+    pub height: f64,
+}
+
+/// Configuration for chart pattern recognition.
+///
+/// # Fields
+/// * `period` - Period of the [`peaks`] and
+///   [`valleys`] used as swing points.
+/// * `closest_neighbor` - Minimum distance between two swing highs or two swing lows.
+/// * `price_tolerance` - Relative difference under which two pivot prices are equal (tops of a
+///   double top), and relative distance a pivot may sit from its boundary line.
+/// * `flat_slope_tolerance` - Relative change per bar under which a boundary line is flat, and
+///   under which two boundary lines are parallel.
+/// * `flagpole_minimum` - Relative move into a flag required for the flagpole.
+/// * `flag_max_retracement` - Fraction of the flagpole the flag may retrace.
+///
+/// # Notes
+/// - Reversal patterns use 3 (double) or 5 (triple, head and shoulders) pivots. Triple tops
+///   and bottoms also need equal troughs or peaks between them, a flat neckline.
+/// - Triangles, wedges, flags and rectangles need at least 5 pivots, so that one boundary is
+///   confirmed by 3 touches, and are extended while further pivots fit the same lines.
+#[derive(Copy, Clone, Debug)]
+pub struct ChartPatternConfig {
+    pub period: usize,
+    pub closest_neighbor: usize,
+    pub price_tolerance: f64,
+    pub flat_slope_tolerance: f64,
+    pub flagpole_minimum: f64,
+    pub flag_max_retracement: f64,
+}
+
+impl Default for ChartPatternConfig {
+    fn default() -> Self {
+        Self {
+            period: 5,
+            closest_neighbor: 1,
+            price_tolerance: 0.02,
+            flat_slope_tolerance: 0.001,
+            flagpole_minimum: 0.05,
+            flag_max_retracement: 0.5,
+        }
+    }
+}
+
+/// Finds classical chart patterns in the swing highs and lows.
+///
+/// Reversal patterns (double and triple tops and bottoms, head and shoulders and its inverse)
+/// break out when price closes through the neckline. Triangles, wedges, flags and rectangles
+/// break out on the first close above the upper line or below the lower line.
+///
+/// # Arguments
+///
+/// * `highs` - Slice of highs
+/// * `lows` - Slice of lows
+/// * `close` - Slice of closing prices, used for breakouts
+/// * `config` - [`ChartPatternConfig`]
+///
+/// # Returns
+///
+/// A vector of [`ChartPattern`] ordered by their first pivot. Reversal and continuation
+/// patterns are found independently, so the same pivots can form both (a triple top with a flat
+/// neckline is also a rectangle).
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `highs.len()` != `lows.len()` != `close.len()`
+/// * `highs.is_empty()`
+/// * `config.period` == 0 or > `highs.len()`
+/// * a tolerance in `config` is < 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_patterns::{
+///     find_chart_patterns, ChartPatternConfig, ChartPatternType,
+/// };
+/// use centaur_technical_indicators::Position;
+///
+/// let prices = vec![
+///     100.0, 104.0, 110.0, 106.0, 103.0, 105.0, 109.5, 105.0, 101.0, 99.0
+/// ];
+/// let config = ChartPatternConfig {
+///     period: 3,
+///     ..Default::default()
+/// };
+/// let patterns = find_chart_patterns(&prices, &prices, &prices, config).unwrap();
+///
+/// assert_eq!(1, patterns.len());
+/// assert_eq!(ChartPatternType::DoubleTop, patterns[0].pattern_type);
+/// assert_eq!(vec![2, 4, 6], patterns[0].pivots);
+/// assert_eq!((0.0, 103.0), patterns[0].lower_line);
+/// assert_eq!(Some((8, Position::Short)), patterns[0].breakout);
+/// assert_eq!(7.0, patterns[0].height);
+/// ```
+pub fn find_chart_patterns(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    config: ChartPatternConfig,
+) -> crate::Result<Vec<ChartPattern>> {
+    assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+    assert_non_empty("highs", highs)?;
+    assert_non_negative("price_tolerance", config.price_tolerance)?;
+    assert_non_negative("flat_slope_tolerance", config.flat_slope_tolerance)?;
+    assert_non_negative("flagpole_minimum", config.flagpole_minimum)?;
+    assert_non_negative("flag_max_retracement", config.flag_max_retracement)?;
+
+    let pivots = swing_pivots(highs, lows, config)?;
+    let mut patterns = reversal_patterns(&pivots, close, config);
+    patterns.extend(continuation_patterns(&pivots, close, config));
+    patterns.sort_by_key(|pattern| (pattern.pivots[0], pattern.pivots[pattern.pivots.len() - 1]));
+    Ok(patterns)
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Pivot {
+    index: usize,
+    price: f64,
+    high: bool,
+}
+
+/// Peaks of the highs and valleys of the lows, merged so that highs and lows alternate.
+fn swing_pivots(
+    highs: &[f64],
+    lows: &[f64],
+    config: ChartPatternConfig,
+) -> crate::Result<Vec<Pivot>> {
+    let mut all: Vec<Pivot> = peaks(highs, config.period, config.closest_neighbor)?
+        .into_iter()
+        .map(|(price, index)| Pivot {
+            index,
+            price,
+            high: true,
+        })
+        .chain(
+            valleys(lows, config.period, config.closest_neighbor)?
+                .into_iter()
+                .map(|(price, index)| Pivot {
+                    index,
+                    price,
+                    high: false,
+                }),
+        )
+        .collect();
+    all.sort_by_key(|pivot| pivot.index);
+
+    let mut pivots: Vec<Pivot> = Vec::with_capacity(all.len());
+    for pivot in all {
+        match pivots.last_mut() {
+            Some(last) if last.high == pivot.high => {
+                let more_extreme = if pivot.high {
+                    pivot.price >= last.price
+                } else {
+                    pivot.price <= last.price
+                };
+                if more_extreme {
+                    *last = pivot;
+                }
+            }
+            // An outside bar can be both a peak and a valley, keep a single swing there
+            Some(last) if last.index == pivot.index => {}
+            _ => pivots.push(pivot),
+        }
+    }
+    Ok(pivots)
+}
+
+/// Double and triple tops and bottoms, head and shoulders and inverse head and shoulders.
+fn reversal_patterns(
+    pivots: &[Pivot],
+    close: &[f64],
+    config: ChartPatternConfig,
+) -> Vec<ChartPattern> {
+    let equal = |a: f64, b: f64| (a - b).abs() <= config.price_tolerance * a.abs().max(b.abs());
+    let mut patterns = Vec::new();
+    let mut i = 0;
+    while i + 3 <= pivots.len() {
+        let tops = pivots[i].high;
+        // Whether a is further from the neckline than b, by more than the tolerance
+        let beyond = |a: f64, b: f64| !equal(a, b) && (if tops { a > b } else { a < b });
+        let prices: Vec<f64> = pivots[i..(i + 5).min(pivots.len())]
+            .iter()
+            .map(|pivot| pivot.price)
+            .collect();
+
+        let mut found = None;
+        if prices.len() == 5 && beyond(prices[0], prices[1]) && beyond(prices[4], prices[3]) {
+            if beyond(prices[2], prices[0])
+                && beyond(prices[2], prices[4])
+                && equal(prices[0], prices[4])
+            {
+                found = Some((ChartPatternType::HeadAndShoulders, 5));
+            } else if equal(prices[0], prices[2])
+                && equal(prices[2], prices[4])
+                && equal(prices[0], prices[4])
+                && equal(prices[1], prices[3])
+                && beyond(prices[2], prices[3])
+            {
+                found = Some((ChartPatternType::TripleTop, 5));
+            }
+        }
+        if found.is_none()
+            && equal(prices[0], prices[2])
+            && beyond(prices[0], prices[1])
+            && beyond(prices[2], prices[1])
+        {
+            found = Some((ChartPatternType::DoubleTop, 3));
+        }
+
+        match found {
+            Some((pattern_type, length)) => {
+                let pattern_type = if tops {
+                    pattern_type
+                } else {
+                    match pattern_type {
+                        ChartPatternType::HeadAndShoulders => {
+                            ChartPatternType::InverseHeadAndShoulders
+                        }
+                        ChartPatternType::TripleTop => ChartPatternType::TripleBottom,
+                        _ => ChartPatternType::DoubleBottom,
+                    }
+                };
+                patterns.push(reversal_pattern(
+                    pattern_type,
+                    &pivots[i..i + length],
+                    close,
+                ));
+                i += length - 1;
+            }
+            None => i += 1,
+        }
+    }
+    patterns
+}
+
+fn reversal_pattern(
+    pattern_type: ChartPatternType,
+    pivots: &[Pivot],
+    close: &[f64],
+) -> ChartPattern {
+    let tops = pivots[0].high;
+    let extremes: Vec<Pivot> = match pattern_type {
+        ChartPatternType::HeadAndShoulders | ChartPatternType::InverseHeadAndShoulders => {
+            vec![pivots[0], pivots[4]]
+        }
+        _ => pivots.iter().step_by(2).copied().collect(),
+    };
+    let troughs: Vec<Pivot> = pivots.iter().skip(1).step_by(2).copied().collect();
+    let extreme_line = fit(&extremes);
+    let neckline = fit(&troughs);
+    let height = pivots
+        .iter()
+        .step_by(2)
+        .map(|pivot| (pivot.price - line_at(neckline, pivot.index)).abs())
+        .fold(0.0, f64::max);
+    let invalidation = if tops {
+        pivots
+            .iter()
+            .map(|pivot| pivot.price)
+            .fold(f64::MIN, f64::max)
+    } else {
+        pivots
+            .iter()
+            .map(|pivot| pivot.price)
+            .fold(f64::MAX, f64::min)
+    };
+
+    let mut breakout = None;
+    for (index, &price) in close
+        .iter()
+        .enumerate()
+        .skip(pivots[pivots.len() - 1].index + 1)
+    {
+        let neck = line_at(neckline, index);
+        if (tops && price > invalidation) || (!tops && price < invalidation) {
+            break;
+        }
+        if tops && price < neck {
+            breakout = Some((index, Position::Short));
+            break;
+        }
+        if !tops && price > neck {
+            breakout = Some((index, Position::Long));
+            break;
+        }
+    }
+
+    let (upper_line, lower_line) = if tops {
+        (extreme_line, neckline)
+    } else {
+        (neckline, extreme_line)
+    };
+    ChartPattern {
+        pattern_type,
+        pivots: pivots.iter().map(|pivot| pivot.index).collect(),
+        upper_line,
+        lower_line,
+        breakout,
+        height,
+    }
+}
+
+/// Triangles, wedges, flags and rectangles, extended while further pivots fit.
+fn continuation_patterns(
+    pivots: &[Pivot],
+    close: &[f64],
+    config: ChartPatternConfig,
+) -> Vec<ChartPattern> {
+    let mut patterns = Vec::new();
+    let mut i = 0;
+    while i + 5 <= pivots.len() {
+        let previous = i.checked_sub(1).map(|previous| pivots[previous]);
+        let mut found: Option<(ContinuationFit, usize)> = None;
+        for end in i + 5..=pivots.len() {
+            match classify(&pivots[i..end], previous, config) {
+                Some(fit)
+                    if found
+                        .as_ref()
+                        .is_none_or(|(first, _)| first.pattern_type == fit.pattern_type) =>
+                {
+                    found = Some((fit, end));
+                }
+                _ => break,
+            }
+        }
+
+        match found {
+            Some((fit, end)) => {
+                let last = pivots[end - 1].index;
+                let breakout =
+                    close
+                        .iter()
+                        .enumerate()
+                        .skip(last + 1)
+                        .find_map(|(index, &price)| {
+                            if price > line_at(fit.upper_line, index) {
+                                Some((index, Position::Long))
+                            } else if price < line_at(fit.lower_line, index) {
+                                Some((index, Position::Short))
+                            } else {
+                                None
+                            }
+                        });
+                patterns.push(ChartPattern {
+                    pattern_type: fit.pattern_type,
+                    pivots: pivots[i..end].iter().map(|pivot| pivot.index).collect(),
+                    upper_line: fit.upper_line,
+                    lower_line: fit.lower_line,
+                    breakout,
+                    height: fit.height,
+                });
+                i = end - 1;
+            }
+            None => i += 1,
+        }
+    }
+    patterns
+}
+
+struct ContinuationFit {
+    pattern_type: ChartPatternType,
+    upper_line: (f64, f64),
+    lower_line: (f64, f64),
+    height: f64,
+}
+
+fn classify(
+    window: &[Pivot],
+    previous: Option<Pivot>,
+    config: ChartPatternConfig,
+) -> Option<ContinuationFit> {
+    let highs: Vec<Pivot> = window.iter().filter(|pivot| pivot.high).copied().collect();
+    let lows: Vec<Pivot> = window.iter().filter(|pivot| !pivot.high).copied().collect();
+    let upper_line = fit(&highs);
+    let lower_line = fit(&lows);
+    let touches = |line: (f64, f64), pivots: &[Pivot]| {
+        pivots.iter().all(|pivot| {
+            (pivot.price - line_at(line, pivot.index)).abs()
+                <= config.price_tolerance * pivot.price.abs()
+        })
+    };
+    if !touches(upper_line, &highs) || !touches(lower_line, &lows) {
+        return None;
+    }
+
+    // Slopes relative to the price level, so the tolerance is a fraction per bar
+    let scale = window.iter().map(|pivot| pivot.price.abs()).sum::<f64>() / window.len() as f64;
+    let upper = upper_line.0 / scale;
+    let lower = lower_line.0 / scale;
+    let tolerance = config.flat_slope_tolerance;
+    let flat = |slope: f64| slope.abs() <= tolerance;
+    let converging = upper - lower < -tolerance;
+    let parallel = (upper - lower).abs() <= tolerance;
+
+    let pattern_type = if flat(upper) && flat(lower) {
+        ChartPatternType::Rectangle
+    } else if flat(upper) && lower > tolerance {
+        ChartPatternType::AscendingTriangle
+    } else if upper < -tolerance && flat(lower) {
+        ChartPatternType::DescendingTriangle
+    } else if upper < -tolerance && lower > tolerance {
+        ChartPatternType::SymmetricTriangle
+    } else if lower > tolerance && converging {
+        ChartPatternType::RisingWedge
+    } else if upper < -tolerance && converging {
+        ChartPatternType::FallingWedge
+    } else if parallel && !flat(upper) {
+        // A flag slopes against its flagpole: the move from the previous pivot into the window
+        let first = window[0];
+        let previous = previous?;
+        let pole = (first.price - previous.price).abs();
+        let (pattern_type, retracement) = if first.high && upper < 0.0 {
+            let low = lows
+                .iter()
+                .map(|pivot| pivot.price)
+                .fold(f64::MAX, f64::min);
+            (ChartPatternType::BullFlag, first.price - low)
+        } else if !first.high && upper > 0.0 {
+            let high = highs
+                .iter()
+                .map(|pivot| pivot.price)
+                .fold(f64::MIN, f64::max);
+            (ChartPatternType::BearFlag, high - first.price)
+        } else {
+            return None;
+        };
+        if pole < config.flagpole_minimum * previous.price.abs()
+            || retracement > config.flag_max_retracement * pole
+        {
+            return None;
+        }
+        return Some(ContinuationFit {
+            pattern_type,
+            upper_line,
+            lower_line,
+            height: pole,
+        });
+    } else {
+        return None;
+    };
+
+    let height = window
+        .iter()
+        .map(|pivot| {
+            if pivot.high {
+                pivot.price - line_at(lower_line, pivot.index)
+            } else {
+                line_at(upper_line, pivot.index) - pivot.price
+            }
+        })
+        .fold(0.0, f64::max);
+    Some(ContinuationFit {
+        pattern_type,
+        upper_line,
+        lower_line,
+        height,
+    })
+}
+
+/// Least squares line through the pivots, flat through a single pivot.
+fn fit(pivots: &[Pivot]) -> (f64, f64) {
+    if pivots.len() == 1 {
+        return (0.0, pivots[0].price);
+    }
+    let points: Vec<(f64, usize)> = pivots
+        .iter()
+        .map(|pivot| (pivot.price, pivot.index))
+        .collect();
+    get_trend_line(&points)
+}
+
+fn line_at(line: (f64, f64), index: usize) -> f64 {
+    line.0 * index as f64 + line.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Joins the pivot prices with two linearly interpolated prices between each pair.
+    fn swings(pivots: &[f64]) -> Vec<f64> {
+        let mut prices: Vec<f64> = pivots
+            .windows(2)
+            .flat_map(|pair| (0..3).map(move |k| pair[0] + (pair[1] - pair[0]) * k as f64 / 3.0))
+            .collect();
+        prices.push(pivots[pivots.len() - 1]);
+        prices
+    }
+
+    fn patterns_of(pivots: &[f64], pattern_type: ChartPatternType) -> Vec<ChartPattern> {
+        let prices = swings(pivots);
+        let config = ChartPatternConfig {
+            period: 3,
+            ..Default::default()
+        };
+        find_chart_patterns(&prices, &prices, &prices, config)
+            .unwrap()
+            .into_iter()
+            .filter(|pattern| pattern.pattern_type == pattern_type)
+            .collect()
+    }
+
+    #[test]
+    fn head_and_shoulders() {
+        let patterns = patterns_of(
+            &[100.0, 110.0, 104.0, 116.0, 104.5, 110.5, 100.0, 98.0],
+            ChartPatternType::HeadAndShoulders,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![3, 6, 9, 12, 15], patterns[0].pivots);
+        assert_eq!((0.08333333333333333, 103.5), patterns[0].lower_line);
+        assert_eq!(Some((17, Position::Short)), patterns[0].breakout);
+        assert_eq!(11.75, patterns[0].height);
+    }
+
+    #[test]
+    fn inverse_head_and_shoulders() {
+        let patterns = patterns_of(
+            &[110.0, 100.0, 106.0, 94.0, 106.5, 100.5, 110.0, 112.0],
+            ChartPatternType::InverseHeadAndShoulders,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![3, 6, 9, 12, 15], patterns[0].pivots);
+        assert_eq!((0.08333333333333333, 105.5), patterns[0].upper_line);
+        assert_eq!(Some((18, Position::Long)), patterns[0].breakout);
+        assert_eq!(12.25, patterns[0].height);
+    }
+
+    #[test]
+    fn triple_bottom() {
+        let patterns = patterns_of(
+            &[110.0, 100.0, 106.0, 100.5, 106.0, 100.0, 112.0],
+            ChartPatternType::TripleBottom,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![3, 6, 9, 12, 15], patterns[0].pivots);
+        assert_eq!((0.0, 106.0), patterns[0].upper_line);
+        assert_eq!(Some((17, Position::Long)), patterns[0].breakout);
+        assert_eq!(6.0, patterns[0].height);
+    }
+
+    #[test]
+    fn triple_needs_flat_neckline() {
+        assert!(patterns_of(
+            &[110.0, 100.0, 106.0, 103.0, 106.0, 100.0, 112.0],
+            ChartPatternType::TripleBottom,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn double_top_invalidated() {
+        let patterns = patterns_of(
+            &[100.0, 110.0, 103.0, 109.5, 106.0, 115.0, 101.0],
+            ChartPatternType::DoubleTop,
+        );
+        assert_eq!(vec![3, 6, 9], patterns[0].pivots);
+        assert_eq!(None, patterns[0].breakout);
+    }
+
+    #[test]
+    fn ascending_triangle() {
+        let patterns = patterns_of(
+            &[
+                90.0, 110.0, 96.0, 110.0, 100.0, 110.2, 103.0, 110.0, 106.0, 116.0,
+            ],
+            ChartPatternType::AscendingTriangle,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![0, 3, 6, 9, 12, 15, 18, 21, 24], patterns[0].pivots);
+        assert_eq!(Some((26, Position::Long)), patterns[0].breakout);
+    }
+
+    #[test]
+    fn symmetric_triangle() {
+        let patterns = patterns_of(
+            &[95.0, 120.0, 100.0, 116.0, 103.0, 112.0, 106.0, 100.0],
+            ChartPatternType::SymmetricTriangle,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![0, 3, 6, 9, 12, 15], patterns[0].pivots);
+        assert_eq!(Some((18, Position::Short)), patterns[0].breakout);
+        assert_eq!(27.0, patterns[0].height);
+    }
+
+    #[test]
+    fn bull_flag() {
+        let patterns = patterns_of(
+            &[100.0, 115.0, 111.0, 113.5, 109.5, 112.0, 108.0, 116.0],
+            ChartPatternType::BullFlag,
+        );
+        assert_eq!(1, patterns.len());
+        assert_eq!(vec![3, 6, 9, 12, 15, 18], patterns[0].pivots);
+        assert_eq!((-0.25, 115.75), patterns[0].upper_line);
+        assert_eq!((-0.25, 112.5), patterns[0].lower_line);
+        assert_eq!(Some((20, Position::Long)), patterns[0].breakout);
+        assert_eq!(15.0, patterns[0].height);
+    }
+
+    #[test]
+    fn bull_flag_needs_flagpole() {
+        assert!(patterns_of(
+            &[111.0, 115.0, 111.0, 113.5, 109.5, 112.0, 108.0, 116.0],
+            ChartPatternType::BullFlag,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn rectangle_and_triple_bottom() {
+        let pivots = [100.0, 110.0, 100.0, 110.0, 100.0, 110.0, 98.0];
+        let rectangles = patterns_of(&pivots, ChartPatternType::Rectangle);
+        assert_eq!(1, rectangles.len());
+        assert_eq!(vec![0, 3, 6, 9, 12, 15, 18], rectangles[0].pivots);
+        assert_eq!((0.0, 110.0), rectangles[0].upper_line);
+        assert_eq!(
+            1,
+            patterns_of(&pivots, ChartPatternType::TripleBottom).len()
+        );
+    }
+
+    #[test]
+    fn swing_pivots_outside_bar() {
+        let highs = vec![101.0, 103.0, 108.0, 104.0, 102.0];
+        let lows = vec![100.0, 99.0, 95.0, 98.0, 101.0];
+        let config = ChartPatternConfig {
+            period: 3,
+            ..Default::default()
+        };
+        let pivots = swing_pivots(&highs, &lows, config).unwrap();
+        assert_eq!(
+            vec![2],
+            pivots.iter().map(|pivot| pivot.index).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn no_patterns_in_trend() {
+        let prices: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        assert!(
+            find_chart_patterns(&prices, &prices, &prices, ChartPatternConfig::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn find_chart_patterns_errors() {
+        let prices = vec![100.0, 101.0, 102.0];
+        let config = ChartPatternConfig::default();
+        assert!(find_chart_patterns(&[], &[], &[], config).is_err());
+        assert!(find_chart_patterns(&prices, &prices[..2], &prices, config).is_err());
+        assert!(find_chart_patterns(&prices, &prices, &prices, config).is_err());
+        let config = ChartPatternConfig {
+            period: 3,
+            price_tolerance: -0.01,
+            ..Default::default()
+        };
+        assert!(find_chart_patterns(&prices, &prices, &prices, config).is_err());
+    }
+}
@@ -0,0 +1,734 @@
+//! # Chart Transforms
+//!
+//! The `chart_transforms` module converts raw OHLC prices into alternative chart
+//! types: Heikin-Ashi candles, Renko bricks, Kagi lines and Point-and-Figure columns.
+//!
+//! ## When to Use
+//! Use chart transforms when you want to:
+//! - Smooth candles before running trend or momentum indicators (Heikin-Ashi)
+//! - Filter out noise and time by only recording moves of a fixed size (Renko, Point-and-Figure)
+//! - Follow reversals of a minimum size (Kagi)
+//!
+//! ## Structure
+//! - **single**: Functions that transform a single candle.
+//! - **bulk**: Functions that transform a slice of prices and return a vector.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`heikin_ashi`](bulk::heikin_ashi): Heikin-Ashi candles
+//! - [`kagi`](bulk::kagi): Kagi lines
+//! - [`point_and_figure`](bulk::point_and_figure): Point-and-Figure columns
+//! - [`renko`](bulk::renko): Renko bricks with a fixed box size
+//! - [`renko_atr`](bulk::renko_atr): Renko bricks sized by the Average True Range
+//!
+//! ### Single
+//! - [`heikin_ashi`](single::heikin_ashi): Heikin-Ashi candle from the previous Heikin-Ashi candle
+//!
+//! ## API Details
+//! - Heikin-Ashi output has one candle per input candle and can be passed straight to other indicators.
+//! - Renko bricks, Kagi lines and Point-and-Figure columns record the index of the input price
+//!   that formed them; collect their closes (or ends) to feed them to other indicators.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+/// A Renko brick.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Brick {
+    /// Index of the price that completed the brick
+    pub index: usize,
+    pub open: f64,
+    pub close: f64,
+}
+
+/// A Kagi line between two reversals.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KagiLine {
+    /// Index of the price where the line ends
+    pub index: usize,
+    pub start: f64,
+    pub end: f64,
+    /// Thick (Yang) line if `true`, thin (Yin) line if `false`
+    pub yang: bool,
+}
+
+/// A Point-and-Figure column of Xs (rising) or Os (falling).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointAndFigureColumn {
+    /// Index of the price that started the column
+    pub index: usize,
+    /// Column of Xs if `true`, Os if `false`
+    pub rising: bool,
+    /// Lowest box level in the column
+    pub low: f64,
+    /// Highest box level in the column
+    pub high: f64,
+    /// Number of boxes in the column
+    pub boxes: usize,
+}
+
+/// **single**: Functions that transform a single candle
+pub mod single {
+    /// Calculates a Heikin-Ashi candle
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - Open of the candle
+    /// * `high` - High of the candle
+    /// * `low` - Low of the candle
+    /// * `close` - Close of the candle
+    /// * `previous_open` - Heikin-Ashi open of the previous candle
+    /// * `previous_close` - Heikin-Ashi close of the previous candle
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the Heikin-Ashi (open, high, low, close)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let candle = centaur_technical_indicators::chart_transforms::single::heikin_ashi(
+    ///     11.0, 13.0, 10.0, 12.0, 10.5, 10.5
+    /// );
+    /// assert_eq!((10.5, 13.0, 10.0, 11.5), candle);
+    /// ```
+    #[inline]
+    pub fn heikin_ashi(
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        previous_open: f64,
+        previous_close: f64,
+    ) -> (f64, f64, f64, f64) {
+        let ha_open = (previous_open + previous_close) / 2.0;
+        let ha_close = (open + high + low + close) / 4.0;
+        (
+            ha_open,
+            high.max(ha_open).max(ha_close),
+            low.min(ha_open).min(ha_close),
+            ha_close,
+        )
+    }
+}
+
+/// **bulk**: Functions that transform a slice of prices and return a vector
+pub mod bulk {
+    use crate::chart_transforms::{single, Brick, KagiLine, PointAndFigureColumn};
+    use crate::other_indicators::single::average_true_range;
+    use crate::validation::{
+        assert_finite, assert_non_empty, assert_period, assert_positive, assert_positive_usize,
+        assert_same_len,
+    };
+    use crate::{CentralTendency, TechnicalIndicatorError};
+
+    /// Calculates Heikin-Ashi candles
+    ///
+    /// The first candle opens at the midpoint of its open and close.
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - Slice of opens
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    ///
+    /// # Returns
+    ///
+    /// A tuple of Heikin-Ashi (opens, highs, lows, closes), one per input candle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `open.is_empty()`
+    /// * The slices are not all the same length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let open = vec![10.0, 11.0];
+    /// let high = vec![12.0, 13.0];
+    /// let low = vec![9.0, 10.0];
+    /// let close = vec![11.0, 12.0];
+    ///
+    /// let (ha_open, ha_high, ha_low, ha_close) =
+    ///     centaur_technical_indicators::chart_transforms::bulk::heikin_ashi(
+    ///         &open, &high, &low, &close
+    ///     ).unwrap();
+    /// assert_eq!(vec![10.5, 10.5], ha_open);
+    /// assert_eq!(vec![12.0, 13.0], ha_high);
+    /// assert_eq!(vec![9.0, 10.0], ha_low);
+    /// assert_eq!(vec![10.5, 11.5], ha_close);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn heikin_ashi(
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+    ) -> crate::Result<(Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>)> {
+        assert_same_len(&[
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+        ])?;
+        assert_non_empty("open", open)?;
+        let length = open.len();
+        let mut ha_open = Vec::with_capacity(length);
+        let mut ha_high = Vec::with_capacity(length);
+        let mut ha_low = Vec::with_capacity(length);
+        let mut ha_close = Vec::with_capacity(length);
+        let (mut previous_open, mut previous_close) = (open[0], close[0]);
+        for i in 0..length {
+            let (o, h, l, c) = single::heikin_ashi(
+                open[i],
+                high[i],
+                low[i],
+                close[i],
+                previous_open,
+                previous_close,
+            );
+            ha_open.push(o);
+            ha_high.push(h);
+            ha_low.push(l);
+            ha_close.push(c);
+            previous_open = o;
+            previous_close = c;
+        }
+        Ok((ha_open, ha_high, ha_low, ha_close))
+    }
+
+    /// Calculates Renko bricks with a fixed box size
+    ///
+    /// A new brick is added each time the price moves a full box beyond the top
+    /// or bottom of the last brick, so reversals need a move of two boxes from
+    /// the last close.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `box_size` - Height of each brick
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`Brick`]s
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * Any price is NaN or infinite
+    /// * `box_size` <= 0.0 or too small to change the price of a brick
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::chart_transforms::Brick;
+    ///
+    /// let prices = vec![100.0, 101.0, 103.0, 104.5, 102.0, 99.5];
+    ///
+    /// let bricks = centaur_technical_indicators::chart_transforms::bulk::renko(&prices, 2.0).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         Brick { index: 2, open: 100.0, close: 102.0 },
+    ///         Brick { index: 3, open: 102.0, close: 104.0 },
+    ///         Brick { index: 5, open: 102.0, close: 100.0 },
+    ///     ],
+    ///     bricks
+    /// );
+    /// ```
+    pub fn renko(prices: &[f64], box_size: f64) -> crate::Result<Vec<Brick>> {
+        assert_non_empty("prices", prices)?;
+        assert_positive("box_size", box_size)?;
+        bricks(prices, box_size, 0)
+    }
+
+    /// Calculates Renko bricks sized by the Average True Range
+    ///
+    /// The box size is the ATR of the first `period` candles. Bricks are built
+    /// from the close of the last of those candles onwards so no brick depends on
+    /// a box size calculated from later prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `close` - Slice of closes
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used to average the true range
+    /// * `period` - Number of candles used to size the box
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`Brick`]s, indexed against the input slices
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.is_empty()`
+    /// * The slices are not all the same length
+    /// * `period` == 0 or `period` > `close.len()`
+    /// * Any close from the `period`th onwards is NaN or infinite
+    /// * The ATR is 0.0 or too small to change the price of a brick
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let close = vec![100.0, 102.0, 101.0, 104.0, 107.0, 103.0];
+    /// let highs = vec![101.0, 103.0, 102.0, 105.0, 108.0, 104.0];
+    /// let lows = vec![99.0, 101.0, 100.0, 103.0, 106.0, 102.0];
+    ///
+    /// let bricks = centaur_technical_indicators::chart_transforms::bulk::renko_atr(
+    ///     &close,
+    ///     &highs,
+    ///     &lows,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     3
+    /// ).unwrap();
+    /// assert_eq!(vec![103.0, 105.0, 107.0, 103.0], bricks.iter().map(|b| b.close).collect::<Vec<f64>>());
+    /// ```
+    pub fn renko_atr(
+        close: &[f64],
+        highs: &[f64],
+        lows: &[f64],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<Brick>> {
+        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
+        assert_non_empty("close", close)?;
+        assert_period(period, close.len())?;
+        let box_size = average_true_range(
+            &close[..period],
+            &highs[..period],
+            &lows[..period],
+            constant_model_type,
+        )?;
+        assert_positive("average_true_range", box_size)?;
+        bricks(&close[period - 1..], box_size, period - 1)
+    }
+
+    /// Calculates Kagi lines
+    ///
+    /// A line continues in its direction until the price reverses by at least
+    /// `reversal_amount` from the line's extreme. An up line turns thick (Yang)
+    /// when it rises above the previous up line's end (shoulder) and a down line
+    /// turns thin (Yin) when it falls below the previous down line's end (waist);
+    /// otherwise a line keeps the thickness of the line before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `reversal_amount` - Minimum price reversal that starts a new line
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`KagiLine`]s, the last one still open. Empty if the price never
+    /// moves by `reversal_amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `reversal_amount` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::chart_transforms::KagiLine;
+    ///
+    /// let prices = vec![10.0, 11.0, 13.0, 12.0, 10.0, 11.0, 14.0];
+    ///
+    /// let lines = centaur_technical_indicators::chart_transforms::bulk::kagi(&prices, 2.0).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         KagiLine { index: 2, start: 10.0, end: 13.0, yang: true },
+    ///         KagiLine { index: 4, start: 13.0, end: 10.0, yang: true },
+    ///         KagiLine { index: 6, start: 10.0, end: 14.0, yang: true },
+    ///     ],
+    ///     lines
+    /// );
+    /// ```
+    pub fn kagi(prices: &[f64], reversal_amount: f64) -> crate::Result<Vec<KagiLine>> {
+        assert_non_empty("prices", prices)?;
+        assert_positive("reversal_amount", reversal_amount)?;
+
+        let mut lines: Vec<KagiLine> = Vec::new();
+        let mut rising: Option<bool> = None;
+        let mut start = prices[0];
+        let (mut extreme, mut extreme_index) = (prices[0], 0);
+        let (mut shoulder, mut waist): (Option<f64>, Option<f64>) = (None, None);
+        let mut yang = true;
+
+        let mut close_line = |start: f64, end: f64, index: usize, rising: bool| {
+            if rising {
+                if lines.is_empty() || shoulder.is_some_and(|s| end > s) {
+                    yang = true;
+                }
+                shoulder = Some(end);
+            } else {
+                if lines.is_empty() || waist.is_some_and(|w| end < w) {
+                    yang = false;
+                }
+                waist = Some(end);
+            }
+            lines.push(KagiLine {
+                index,
+                start,
+                end,
+                yang,
+            });
+        };
+
+        for (i, &price) in prices.iter().enumerate().skip(1) {
+            match rising {
+                None => {
+                    if (price - start).abs() >= reversal_amount {
+                        rising = Some(price > start);
+                        extreme = price;
+                        extreme_index = i;
+                    }
+                }
+                Some(up) => {
+                    if (up && price > extreme) || (!up && price < extreme) {
+                        extreme = price;
+                        extreme_index = i;
+                    } else if (price - extreme).abs() >= reversal_amount {
+                        close_line(start, extreme, extreme_index, up);
+                        start = extreme;
+                        extreme = price;
+                        extreme_index = i;
+                        rising = Some(!up);
+                    }
+                }
+            }
+        }
+        if let Some(up) = rising {
+            close_line(start, extreme, extreme_index, up);
+        }
+        Ok(lines)
+    }
+
+    /// Calculates Point-and-Figure columns using the close method
+    ///
+    /// Prices are placed on a grid of `box_size`. A column of Xs extends while
+    /// prices make new boxes higher and reverses into a column of Os once the
+    /// price falls `reversal` boxes below the column's high (and vice versa).
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `box_size` - Size of each box
+    /// * `reversal` - Number of boxes needed to start a new column
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`PointAndFigureColumn`]s, the last one still open. Empty if the
+    /// price never moves a full box.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `box_size` <= 0.0
+    /// * `reversal` == 0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::chart_transforms::PointAndFigureColumn;
+    ///
+    /// let prices = vec![100.0, 101.0, 103.0, 104.5, 102.0, 99.5, 98.0];
+    ///
+    /// let columns = centaur_technical_indicators::chart_transforms::bulk::point_and_figure(
+    ///     &prices, 1.0, 3
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         PointAndFigureColumn { index: 1, rising: true, low: 101.0, high: 104.0, boxes: 4 },
+    ///         PointAndFigureColumn { index: 5, rising: false, low: 98.0, high: 103.0, boxes: 6 },
+    ///     ],
+    ///     columns
+    /// );
+    /// ```
+    pub fn point_and_figure(
+        prices: &[f64],
+        box_size: f64,
+        reversal: usize,
+    ) -> crate::Result<Vec<PointAndFigureColumn>> {
+        assert_non_empty("prices", prices)?;
+        assert_positive("box_size", box_size)?;
+        assert_positive_usize("reversal", reversal)?;
+
+        let floor = |price: f64| (price / box_size).floor() * box_size;
+        let ceil = |price: f64| (price / box_size).ceil() * box_size;
+        let column = |index: usize, rising: bool, low: f64, high: f64| PointAndFigureColumn {
+            index,
+            rising,
+            low,
+            high,
+            boxes: ((high - low) / box_size).round() as usize + 1,
+        };
+        let reversal_size = reversal as f64 * box_size;
+
+        let mut columns = Vec::new();
+        let level = floor(prices[0]);
+        let mut current: Option<PointAndFigureColumn> = None;
+        for (i, &price) in prices.iter().enumerate() {
+            current = match current {
+                None if price >= level + box_size => {
+                    Some(column(i, true, level + box_size, floor(price)))
+                }
+                None if price <= level - box_size => {
+                    Some(column(i, false, ceil(price), level - box_size))
+                }
+                None => None,
+                Some(c) if c.rising && price >= c.high + box_size => {
+                    Some(column(c.index, true, c.low, floor(price)))
+                }
+                Some(c) if c.rising && price <= c.high - reversal_size => {
+                    columns.push(c);
+                    Some(column(i, false, ceil(price), c.high - box_size))
+                }
+                Some(c) if !c.rising && price <= c.low - box_size => {
+                    Some(column(c.index, false, ceil(price), c.high))
+                }
+                Some(c) if !c.rising && price >= c.low + reversal_size => {
+                    columns.push(c);
+                    Some(column(i, true, c.low + box_size, floor(price)))
+                }
+                unchanged => unchanged,
+            };
+        }
+        columns.extend(current);
+        Ok(columns)
+    }
+
+    /// Builds Renko bricks, offsetting their indices by `offset`
+    fn bricks(prices: &[f64], box_size: f64, offset: usize) -> crate::Result<Vec<Brick>> {
+        for &price in prices {
+            assert_finite("prices", price)?;
+        }
+        // A box below the precision of the price would never reach it
+        let assert_moves = |level: f64, next: f64| {
+            if next == level {
+                return Err(TechnicalIndicatorError::InvalidValue {
+                    name: "box_size".to_string(),
+                    value: box_size,
+                    reason: format!("is too small to change a brick at {}", level),
+                });
+            }
+            Ok(())
+        };
+        let mut bricks = Vec::new();
+        let (mut top, mut bottom) = (prices[0], prices[0]);
+        for (i, &price) in prices.iter().enumerate() {
+            while price >= top + box_size {
+                assert_moves(top, top + box_size)?;
+                bricks.push(Brick {
+                    index: i + offset,
+                    open: top,
+                    close: top + box_size,
+                });
+                bottom = top;
+                top += box_size;
+            }
+            while price <= bottom - box_size {
+                assert_moves(bottom, bottom - box_size)?;
+                bricks.push(Brick {
+                    index: i + offset,
+                    open: bottom,
+                    close: bottom - box_size,
+                });
+                top = bottom;
+                bottom -= box_size;
+            }
+        }
+        Ok(bricks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstantModelType;
+
+    #[test]
+    fn single_heikin_ashi_bounds() {
+        let (open, high, low, close) = single::heikin_ashi(10.0, 10.5, 9.5, 10.0, 12.0, 12.0);
+        assert_eq!(12.0, open);
+        assert_eq!(12.0, high);
+        assert_eq!(9.5, low);
+        assert_eq!(10.0, close);
+    }
+
+    #[test]
+    fn bulk_heikin_ashi_matches_single() {
+        let open = vec![10.0, 11.0, 12.5];
+        let high = vec![12.0, 13.0, 13.5];
+        let low = vec![9.0, 10.0, 11.0];
+        let close = vec![11.0, 12.0, 11.5];
+        let (ha_open, ha_high, ha_low, ha_close) =
+            bulk::heikin_ashi(&open, &high, &low, &close).unwrap();
+        assert_eq!(
+            single::heikin_ashi(12.5, 13.5, 11.0, 11.5, ha_open[1], ha_close[1]),
+            (ha_open[2], ha_high[2], ha_low[2], ha_close[2])
+        );
+    }
+
+    #[test]
+    fn bulk_heikin_ashi_error() {
+        assert!(bulk::heikin_ashi(&[10.0], &[11.0], &[9.0], &[]).is_err());
+        assert!(bulk::heikin_ashi(&[], &[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn bulk_renko_multiple_bricks_per_price() {
+        let bricks = bulk::renko(&[100.0, 107.0], 2.0).unwrap();
+        assert_eq!(
+            vec![102.0, 104.0, 106.0],
+            bricks.iter().map(|b| b.close).collect::<Vec<f64>>()
+        );
+        assert!(bricks.iter().all(|b| b.index == 1));
+    }
+
+    #[test]
+    fn bulk_renko_error() {
+        assert!(bulk::renko(&[100.0], 0.0).is_err());
+        assert!(bulk::renko(&[], 1.0).is_err());
+        assert!(bulk::renko(&[100.0, f64::INFINITY], 1.0).is_err());
+        assert!(bulk::renko(&[100.0, f64::NAN], 1.0).is_err());
+        assert!(bulk::renko(&[100.0, 101.0], 1e-15).is_err());
+    }
+
+    #[test]
+    fn bulk_renko_atr_matches_renko() {
+        let close = vec![100.0, 102.0, 101.0, 104.0, 107.0, 103.0];
+        let highs = vec![101.0, 103.0, 102.0, 105.0, 108.0, 104.0];
+        let lows = vec![99.0, 101.0, 100.0, 103.0, 106.0, 102.0];
+        let bricks = bulk::renko_atr(
+            &close,
+            &highs,
+            &lows,
+            ConstantModelType::SimpleMovingAverage,
+            3,
+        )
+        .unwrap();
+        let expected: Vec<Brick> = bulk::renko(&close[2..], 2.0)
+            .unwrap()
+            .into_iter()
+            .map(|b| Brick {
+                index: b.index + 2,
+                ..b
+            })
+            .collect();
+        assert_eq!(expected, bricks);
+    }
+
+    #[test]
+    fn bulk_renko_atr_error() {
+        let close = vec![100.0, 102.0];
+        assert!(bulk::renko_atr(
+            &close,
+            &close,
+            &close[..1],
+            ConstantModelType::SimpleMovingAverage,
+            2
+        )
+        .is_err());
+        assert!(bulk::renko_atr(
+            &close,
+            &close,
+            &close,
+            ConstantModelType::SimpleMovingAverage,
+            3
+        )
+        .is_err());
+        assert!(bulk::renko_atr(
+            &close,
+            &close,
+            &close,
+            ConstantModelType::SimpleMovingAverage,
+            2
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_kagi_yin_line() {
+        let prices = vec![10.0, 13.0, 11.0, 12.0, 8.0, 7.0, 9.5];
+        let lines = bulk::kagi(&prices, 2.0).unwrap();
+        assert_eq!(
+            vec![
+                KagiLine {
+                    index: 1,
+                    start: 10.0,
+                    end: 13.0,
+                    yang: true
+                },
+                KagiLine {
+                    index: 5,
+                    start: 13.0,
+                    end: 7.0,
+                    yang: true
+                },
+                KagiLine {
+                    index: 6,
+                    start: 7.0,
+                    end: 9.5,
+                    yang: true
+                },
+            ],
+            lines
+        );
+        let lines = bulk::kagi(&[10.0, 13.0, 11.0, 14.0, 9.0, 12.0, 8.0], 2.0).unwrap();
+        assert_eq!(
+            vec![true, true, true, false, false, false],
+            lines.iter().map(|l| l.yang).collect::<Vec<bool>>()
+        );
+    }
+
+    #[test]
+    fn bulk_kagi_no_reversal() {
+        assert!(bulk::kagi(&[10.0, 10.5, 11.0], 2.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn bulk_kagi_error() {
+        assert!(bulk::kagi(&[10.0], 0.0).is_err());
+        assert!(bulk::kagi(&[], 1.0).is_err());
+    }
+
+    #[test]
+    fn bulk_point_and_figure_reversal_back_up() {
+        let prices = vec![100.0, 97.0, 96.0, 99.0];
+        let columns = bulk::point_and_figure(&prices, 1.0, 3).unwrap();
+        assert_eq!(
+            vec![
+                PointAndFigureColumn {
+                    index: 1,
+                    rising: false,
+                    low: 96.0,
+                    high: 99.0,
+                    boxes: 4
+                },
+                PointAndFigureColumn {
+                    index: 3,
+                    rising: true,
+                    low: 97.0,
+                    high: 99.0,
+                    boxes: 3
+                },
+            ],
+            columns
+        );
+    }
+
+    #[test]
+    fn bulk_point_and_figure_error() {
+        assert!(bulk::point_and_figure(&[100.0], 0.0, 3).is_err());
+        assert!(bulk::point_and_figure(&[100.0], 1.0, 0).is_err());
+        assert!(bulk::point_and_figure(&[], 1.0, 3).is_err());
+    }
+}
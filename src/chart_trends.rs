@@ -15,9 +15,18 @@
 //!
 //! ## Included Functions
 //! - [`break_down_trends`]: Segments the chart into distinct up/down trends
+//! - [`break_down_trends_with_diagnostics`]: Trend segments with their fit statistics and break types
 //! - [`overall_trend`]: Returns the overall trend (slope) for all price points
 //! - [`peak_trend`]: Calculates the trend based on local peaks
 //! - [`peaks`]: Finds all local maxima (peaks) in the series
+//! - [`find_peaks`]: Finds peaks filtered by height, prominence, width and distance
+//! - [`find_valleys`]: Finds valleys filtered by height, prominence, width and distance
+//! - [`peak_trend_with_config`]: Trend line through peaks from [`find_peaks`]
+//! - [`valley_trend_with_config`]: Trend line through valleys from [`find_valleys`]
+//! - [`zigzag`]: Alternating swing highs and lows labelled HH/HL/LH/LL
+//! - [`break_of_structure`]: Closes through confirmed swing levels
+//! - [`support_resistance_zones`]: Horizontal zones clustered from swing highs and lows
+//! - [`trend_line_touches`]: Swing touches of sloped lines such as [`break_down_trends`]
 //! - [`valley_trend`]: Calculates the trend based on local valleys
 //! - [`valleys`]: Finds all local minima (valleys) in the series
 //!
@@ -28,8 +37,13 @@
 //!
 //! ---
 
-use crate::basic_indicators::single::{max, mean, min};
-use crate::validation::{assert_non_empty, assert_period};
+use crate::basic_indicators::single::{max, mean, min, price_distribution};
+use crate::other_indicators::bulk::average_true_range;
+use crate::validation::{
+    assert_min_length, assert_non_empty, assert_non_negative, assert_period, assert_positive,
+    assert_positive_usize, assert_same_len,
+};
+use crate::{CentralTendency, Position, TechnicalIndicatorError};
 
 /// Calculates all peaks over a given period
 ///
@@ -207,7 +221,7 @@ pub fn valleys(
 }
 
 /// OLS simple linear regression function
-fn get_trend_line(p: &[(f64, usize)]) -> (f64, f64) {
+pub(crate) fn get_trend_line(p: &[(f64, usize)]) -> (f64, f64) {
     let length = p.len() as f64;
     let mean_x = p.iter().map(|&(_, x)| x as f64).sum::<f64>() / length;
     let mean_y = p.iter().map(|&(y, _)| y).sum::<f64>() / length;
@@ -281,6 +295,1000 @@ pub fn valley_trend(prices: &[f64], period: usize) -> crate::Result<(f64, f64)>
     Ok(get_trend_line(&valleys))
 }
 
+/// Configuration for [`find_peaks`] and [`find_valleys`]. Filters set to `None` are not
+/// applied.
+///
+/// # Fields
+/// * `height` - Lowest price a peak may have (highest price a valley may have).
+/// * `prominence` - Minimum prominence: how far the peak stands above the higher of its two
+///   bases.
+/// * `width` - Minimum width, in bars, measured at `relative_height` of the prominence.
+/// * `distance` - Minimum number of bars between peaks; smaller peaks closer than this to a
+///   larger one are dropped.
+/// * `relative_height` - Fraction of the prominence below the peak at which the width is
+///   measured (0.5 gives the width at half prominence).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PeakConfig {
+    pub height: Option<f64>,
+    pub prominence: Option<f64>,
+    pub width: Option<f64>,
+    pub distance: Option<usize>,
+    pub relative_height: f64,
+}
+
+impl Default for PeakConfig {
+    fn default() -> Self {
+        Self {
+            height: None,
+            prominence: None,
+            width: None,
+            distance: None,
+            relative_height: 0.5,
+        }
+    }
+}
+
+/// A peak (or valley) found by [`find_peaks`] or [`find_valleys`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Peak {
+    /// Index of the peak, the middle of the plateau for flat peaks
+    pub index: usize,
+    pub value: f64,
+    pub prominence: f64,
+    /// Index of the lowest point between the peak and the next higher price to the left
+    pub left_base: usize,
+    /// Index of the lowest point between the peak and the next higher price to the right
+    pub right_base: usize,
+    /// Width in bars at `relative_height` of the prominence, interpolated between prices
+    pub width: f64,
+    /// First index of the plateau, equal to `index` for single-bar peaks
+    pub left_edge: usize,
+    /// Last index of the plateau, equal to `index` for single-bar peaks
+    pub right_edge: usize,
+}
+
+/// Finds peaks and measures their prominence and width, in the style of SciPy's `find_peaks`.
+///
+/// A peak is a price, or a flat run of prices, with lower prices on both sides; the first
+/// and last prices are never peaks. Filters are applied in the order height, distance,
+/// prominence, width.
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `peak_config` - Filters (see [`PeakConfig`])
+///
+/// # Returns
+///
+/// A vector of [`Peak`] ordered by index
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * `peak_config.distance` == `Some(0)`
+/// * `peak_config.relative_height` < 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::{find_peaks, PeakConfig};
+///
+/// let highs = vec![100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0];
+///
+/// let peaks = find_peaks(&highs, PeakConfig::default()).unwrap();
+/// assert_eq!(vec![1, 3, 5, 9], peaks.iter().map(|peak| peak.index).collect::<Vec<_>>());
+///
+/// let prominent = find_peaks(
+///     &highs,
+///     PeakConfig { prominence: Some(2.0), ..PeakConfig::default() }
+/// ).unwrap();
+/// assert_eq!(vec![1, 5], prominent.iter().map(|peak| peak.index).collect::<Vec<_>>());
+/// assert_eq!((5, 6), (prominent[1].left_edge, prominent[1].right_edge));
+/// assert_eq!((0, 8), (prominent[1].left_base, prominent[1].right_base));
+/// assert_eq!(7.0, prominent[1].prominence);
+/// assert_eq!(2.708333333333333, prominent[1].width);
+/// ```
+pub fn find_peaks(prices: &[f64], peak_config: PeakConfig) -> crate::Result<Vec<Peak>> {
+    assert_non_empty("prices", prices)?;
+    if let Some(distance) = peak_config.distance {
+        assert_positive_usize("distance", distance)?;
+    }
+    assert_non_negative("relative_height", peak_config.relative_height)?;
+
+    let mut candidates = local_maxima(prices);
+    if let Some(height) = peak_config.height {
+        candidates.retain(|&(index, _, _)| prices[index] >= height);
+    }
+    if let Some(distance) = peak_config.distance {
+        candidates = separate_peaks(prices, candidates, distance);
+    }
+
+    let mut peaks: Vec<Peak> = candidates
+        .into_iter()
+        .map(|(index, left_edge, right_edge)| {
+            let (prominence, left_base, right_base) = prominence(prices, index);
+            let width = peak_width(
+                prices,
+                index,
+                prominence,
+                left_base,
+                right_base,
+                peak_config.relative_height,
+            );
+            Peak {
+                index,
+                value: prices[index],
+                prominence,
+                left_base,
+                right_base,
+                width,
+                left_edge,
+                right_edge,
+            }
+        })
+        .collect();
+    if let Some(prominence) = peak_config.prominence {
+        peaks.retain(|peak| peak.prominence >= prominence);
+    }
+    if let Some(width) = peak_config.width {
+        peaks.retain(|peak| peak.width >= width);
+    }
+    Ok(peaks)
+}
+
+/// Finds valleys and measures their prominence and width, in the style of SciPy's
+/// `find_peaks` on the negated prices.
+///
+/// The prominence and width of a valley are measured downwards, so they are positive, and
+/// its bases are the highest points either side of it. `peak_config.height` is the highest
+/// price a valley may have.
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `peak_config` - Filters (see [`PeakConfig`])
+///
+/// # Returns
+///
+/// A vector of [`Peak`] ordered by index
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * `peak_config.distance` == `Some(0)`
+/// * `peak_config.relative_height` < 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::{find_valleys, PeakConfig};
+///
+/// let lows = vec![100.0, 97.0, 98.0, 97.5, 99.0, 93.0, 96.0, 100.0, 98.0, 99.0];
+///
+/// let valleys = find_valleys(
+///     &lows,
+///     PeakConfig { prominence: Some(2.0), ..PeakConfig::default() }
+/// ).unwrap();
+/// assert_eq!(vec![1, 5], valleys.iter().map(|valley| valley.index).collect::<Vec<_>>());
+/// assert_eq!(93.0, valleys[1].value);
+/// assert_eq!(7.0, valleys[1].prominence);
+/// ```
+pub fn find_valleys(prices: &[f64], peak_config: PeakConfig) -> crate::Result<Vec<Peak>> {
+    let negated: Vec<f64> = prices.iter().map(|price| -price).collect();
+    let config = PeakConfig {
+        height: peak_config.height.map(|height| -height),
+        ..peak_config
+    };
+    Ok(find_peaks(&negated, config)?
+        .into_iter()
+        .map(|valley| Peak {
+            value: -valley.value,
+            ..valley
+        })
+        .collect())
+}
+
+/// Returns the slope and intercept of the trend line fitted to peaks found by [`find_peaks`].
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `peak_config` - Filters (see [`PeakConfig`])
+///
+/// # Returns
+///
+/// A tuple containing (slope, intercept) of the trend line
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * fewer than 2 peaks are found
+/// * `peak_config` is invalid (see [`find_peaks`])
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::PeakConfig;
+///
+/// let highs = vec![100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0];
+/// let peak_trend = centaur_technical_indicators::chart_trends::peak_trend_with_config(
+///     &highs,
+///     PeakConfig { prominence: Some(1.0), ..PeakConfig::default() }
+/// ).unwrap();
+/// assert_eq!((-0.125, 104.625), peak_trend);
+/// ```
+pub fn peak_trend_with_config(
+    prices: &[f64],
+    peak_config: PeakConfig,
+) -> crate::Result<(f64, f64)> {
+    let peaks: Vec<(f64, usize)> = find_peaks(prices, peak_config)?
+        .iter()
+        .map(|peak| (peak.value, peak.index))
+        .collect();
+    assert_min_length("peaks", 2, peaks.len())?;
+    Ok(get_trend_line(&peaks))
+}
+
+/// Returns the slope and intercept of the trend line fitted to valleys found by
+/// [`find_valleys`].
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `peak_config` - Filters (see [`PeakConfig`])
+///
+/// # Returns
+///
+/// A tuple containing (slope, intercept) of the trend line
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `prices.is_empty()`
+/// * fewer than 2 valleys are found
+/// * `peak_config` is invalid (see [`find_valleys`])
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::PeakConfig;
+///
+/// let lows = vec![100.0, 97.0, 98.0, 97.5, 99.0, 93.0, 96.0, 100.0, 98.0, 99.0];
+/// let valley_trend = centaur_technical_indicators::chart_trends::valley_trend_with_config(
+///     &lows,
+///     PeakConfig { prominence: Some(1.0), ..PeakConfig::default() }
+/// ).unwrap();
+/// assert_eq!((0.08108108108108109, 95.62162162162163), valley_trend);
+/// ```
+pub fn valley_trend_with_config(
+    prices: &[f64],
+    peak_config: PeakConfig,
+) -> crate::Result<(f64, f64)> {
+    let valleys: Vec<(f64, usize)> = find_valleys(prices, peak_config)?
+        .iter()
+        .map(|valley| (valley.value, valley.index))
+        .collect();
+    assert_min_length("valleys", 2, valleys.len())?;
+    Ok(get_trend_line(&valleys))
+}
+
+/// Reversal threshold for [`zigzag`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ZigZagThreshold {
+    /// Reversal of at least this fraction of the swing price (0.05 for 5%)
+    Percent(f64),
+    /// Reversal of at least `multiplier` times the average true range at the bar, averaged
+    /// with the `constant_model_type` passed to [`zigzag`]
+    AverageTrueRange { period: usize, multiplier: f64 },
+}
+
+/// Whether a swing point is a high or a low.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwingKind {
+    High,
+    Low,
+}
+
+/// Market structure label of a swing point relative to the previous swing of the same kind.
+///
+/// Only a strictly higher high or strictly lower low extends the structure, so a swing high
+/// equal to the previous one is a lower high and a swing low equal to the previous one is a
+/// higher low.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SwingStructure {
+    HigherHigh,
+    HigherLow,
+    LowerHigh,
+    LowerLow,
+}
+
+/// A swing point from [`zigzag`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SwingPoint {
+    /// Index of the swing high or low
+    pub index: usize,
+    pub price: f64,
+    pub kind: SwingKind,
+    /// `None` for the first swing high and first swing low
+    pub structure: Option<SwingStructure>,
+    /// Index of the bar whose reversal confirmed the swing, `None` for the last swing, which
+    /// can still move while the leg into it extends
+    pub confirmed_at: Option<usize>,
+}
+
+/// A close through the level of a confirmed swing point, from [`break_of_structure`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StructureBreak {
+    /// Index of the close that broke the level
+    pub index: usize,
+    /// Index of the swing point whose level was broken
+    pub swing_index: usize,
+    pub level: f64,
+    /// `Long` for a close above a swing high, `Short` for a close below a swing low
+    pub direction: Position,
+}
+
+/// Calculates the ZigZag: alternating swing highs and lows separated by reversals of at least
+/// the threshold.
+///
+/// Swing highs are taken from `highs` and swing lows from `lows`; pass the same slice three
+/// times for a close-only ZigZag. A swing is confirmed on the first bar that reverses far
+/// enough from it. The last swing is unconfirmed: it follows the current leg and can still
+/// move (repaint) as new prices arrive, so it is returned with `confirmed_at` set to `None`.
+///
+/// # Arguments
+///
+/// * `highs` - Slice of highs
+/// * `lows` - Slice of lows
+/// * `close` - Slice of closing prices, used for the ATR
+/// * `threshold` - Variant of [`ZigZagThreshold`]
+/// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used to
+///   average the true range, unused by `ZigZagThreshold::Percent`
+///
+/// # Returns
+///
+/// A vector of [`SwingPoint`] alternating between highs and lows, labelled with their
+/// [`SwingStructure`]. Empty if no reversal reaches the threshold.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `highs.len()` != `lows.len()` != `close.len()`
+/// * `highs.is_empty()`
+/// * the percentage or the ATR multiplier is <= 0.0
+/// * the ATR period == 0 or > `highs.len()`
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::{zigzag, SwingKind, SwingStructure, ZigZagThreshold};
+/// use centaur_technical_indicators::ConstantModelType;
+///
+/// let prices = vec![
+///     100.0, 103.0, 106.0, 104.0, 101.0, 103.0, 108.0, 110.0, 106.0, 104.0, 105.0
+/// ];
+/// let swings = zigzag(
+///     &prices,
+///     &prices,
+///     &prices,
+///     ZigZagThreshold::Percent(0.04),
+///     ConstantModelType::SimpleMovingAverage
+/// ).unwrap();
+///
+/// assert_eq!(
+///     vec![(0, 100.0), (2, 106.0), (4, 101.0), (7, 110.0), (9, 104.0)],
+///     swings.iter().map(|swing| (swing.index, swing.price)).collect::<Vec<_>>()
+/// );
+/// assert_eq!(SwingKind::High, swings[3].kind);
+/// assert_eq!(Some(SwingStructure::HigherHigh), swings[3].structure);
+/// assert_eq!(Some(9), swings[3].confirmed_at);
+/// assert_eq!(None, swings[4].confirmed_at);
+/// ```
+pub fn zigzag(
+    highs: &[f64],
+    lows: &[f64],
+    close: &[f64],
+    threshold: ZigZagThreshold,
+    constant_model_type: impl CentralTendency,
+) -> crate::Result<Vec<SwingPoint>> {
+    assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+    assert_non_empty("highs", highs)?;
+
+    // Reversal needed at each bar from a swing at the given price, None before the ATR starts
+    let reversals: Vec<Option<f64>> = match threshold {
+        ZigZagThreshold::Percent(percent) => {
+            assert_positive("percent", percent)?;
+            vec![None; highs.len()]
+        }
+        ZigZagThreshold::AverageTrueRange { period, multiplier } => {
+            assert_period(period, highs.len())?;
+            assert_positive("multiplier", multiplier)?;
+            let atr = average_true_range(close, highs, lows, constant_model_type, period)?;
+            std::iter::repeat_n(None, period - 1)
+                .chain(atr.iter().map(|atr| Some(multiplier * atr)))
+                .collect()
+        }
+    };
+    let reversed = |index: usize, from: f64, to: f64| match threshold {
+        ZigZagThreshold::Percent(percent) => (from - to).abs() >= percent * from.abs(),
+        ZigZagThreshold::AverageTrueRange { .. } => {
+            reversals[index].is_some_and(|reversal| (from - to).abs() >= reversal)
+        }
+    };
+
+    let mut swings: Vec<SwingPoint> = Vec::new();
+    let mut direction: Option<SwingKind> = None;
+    let (mut high_index, mut low_index) = (0, 0);
+    // Extremes made after the opposite extreme, before the first swing is found
+    let (mut high_after_low, mut low_after_high) = (None, None);
+    for index in 1..highs.len() {
+        match direction {
+            None => {
+                if highs[index] > highs[high_index] {
+                    high_index = index;
+                    low_after_high = None;
+                } else if low_after_high.is_none_or(|low| lows[index] < lows[low]) {
+                    low_after_high = Some(index);
+                }
+                if lows[index] < lows[low_index] {
+                    low_index = index;
+                    high_after_low = None;
+                } else if high_after_low.is_none_or(|high| highs[index] > highs[high]) {
+                    high_after_low = Some(index);
+                }
+                let up =
+                    high_after_low.filter(|&high| reversed(index, lows[low_index], highs[high]));
+                let down =
+                    low_after_high.filter(|&low| reversed(index, highs[high_index], lows[low]));
+                match (up, down) {
+                    (Some(high), _) if down.is_none() || low_index <= high_index => {
+                        push_swing(
+                            &mut swings,
+                            low_index,
+                            lows[low_index],
+                            SwingKind::Low,
+                            index,
+                        );
+                        direction = Some(SwingKind::High);
+                        high_index = high;
+                    }
+                    (_, Some(low)) => {
+                        push_swing(
+                            &mut swings,
+                            high_index,
+                            highs[high_index],
+                            SwingKind::High,
+                            index,
+                        );
+                        direction = Some(SwingKind::Low);
+                        low_index = low;
+                    }
+                    _ => {}
+                }
+            }
+            Some(SwingKind::High) => {
+                if highs[index] > highs[high_index] {
+                    high_index = index;
+                } else if reversed(index, highs[high_index], lows[index]) {
+                    push_swing(
+                        &mut swings,
+                        high_index,
+                        highs[high_index],
+                        SwingKind::High,
+                        index,
+                    );
+                    direction = Some(SwingKind::Low);
+                    low_index = index;
+                }
+            }
+            Some(SwingKind::Low) => {
+                if lows[index] < lows[low_index] {
+                    low_index = index;
+                } else if reversed(index, lows[low_index], highs[index]) {
+                    push_swing(
+                        &mut swings,
+                        low_index,
+                        lows[low_index],
+                        SwingKind::Low,
+                        index,
+                    );
+                    direction = Some(SwingKind::High);
+                    high_index = index;
+                }
+            }
+        }
+    }
+    match direction {
+        Some(SwingKind::High) => {
+            push_swing(
+                &mut swings,
+                high_index,
+                highs[high_index],
+                SwingKind::High,
+                0,
+            );
+        }
+        Some(SwingKind::Low) => {
+            push_swing(&mut swings, low_index, lows[low_index], SwingKind::Low, 0);
+        }
+        None => return Ok(swings),
+    }
+    if let Some(last) = swings.last_mut() {
+        last.confirmed_at = None;
+    }
+    Ok(swings)
+}
+
+/// Finds break-of-structure events: closes through the level of the latest confirmed swing
+/// high or low.
+///
+/// A swing level can only be broken after the bar that confirmed it, so the events contain
+/// no look-ahead, and each level is broken at most once.
+///
+/// # Arguments
+///
+/// * `close` - Slice of closing prices
+/// * `swings` - Swing points from [`zigzag`] on the same prices
+///
+/// # Returns
+///
+/// A vector of [`StructureBreak`] ordered by index
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `close.is_empty()`
+/// * a swing index is outside `close`
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::{break_of_structure, zigzag, ZigZagThreshold};
+/// use centaur_technical_indicators::{ConstantModelType, Position};
+///
+/// let prices = vec![
+///     100.0, 103.0, 106.0, 104.0, 101.0, 103.0, 108.0, 110.0, 106.0, 104.0, 105.0
+/// ];
+/// let swings = zigzag(
+///     &prices,
+///     &prices,
+///     &prices,
+///     ZigZagThreshold::Percent(0.04),
+///     ConstantModelType::SimpleMovingAverage
+/// ).unwrap();
+/// let breaks = break_of_structure(&prices, &swings).unwrap();
+///
+/// assert_eq!(1, breaks.len());
+/// assert_eq!((6, 2, 106.0), (breaks[0].index, breaks[0].swing_index, breaks[0].level));
+/// assert_eq!(Position::Long, breaks[0].direction);
+/// ```
+pub fn break_of_structure(
+    close: &[f64],
+    swings: &[SwingPoint],
+) -> crate::Result<Vec<StructureBreak>> {
+    assert_non_empty("close", close)?;
+    if let Some(swing) = swings.iter().find(|swing| swing.index >= close.len()) {
+        return Err(TechnicalIndicatorError::InvalidValue {
+            name: "swing index".to_string(),
+            value: swing.index as f64,
+            reason: format!("must be less than the length of close ({})", close.len()),
+        });
+    }
+
+    let mut breaks = Vec::new();
+    let (mut active_high, mut active_low): (Option<&SwingPoint>, Option<&SwingPoint>) =
+        (None, None);
+    let mut confirmed = swings
+        .iter()
+        .filter(|swing| swing.confirmed_at.is_some())
+        .peekable();
+    for (index, &price) in close.iter().enumerate() {
+        if let Some(swing) = active_high.filter(|swing| price > swing.price) {
+            breaks.push(StructureBreak {
+                index,
+                swing_index: swing.index,
+                level: swing.price,
+                direction: Position::Long,
+            });
+            active_high = None;
+        }
+        if let Some(swing) = active_low.filter(|swing| price < swing.price) {
+            breaks.push(StructureBreak {
+                index,
+                swing_index: swing.index,
+                level: swing.price,
+                direction: Position::Short,
+            });
+            active_low = None;
+        }
+        // Swings confirmed on this bar can be broken from the next one
+        while let Some(swing) = confirmed.next_if(|swing| swing.confirmed_at == Some(index)) {
+            match swing.kind {
+                SwingKind::High => active_high = Some(swing),
+                SwingKind::Low => active_low = Some(swing),
+            }
+        }
+    }
+    Ok(breaks)
+}
+
+/// Appends a swing point labelled against the previous swing of the same kind.
+fn push_swing(
+    swings: &mut Vec<SwingPoint>,
+    index: usize,
+    price: f64,
+    kind: SwingKind,
+    confirmed_at: usize,
+) {
+    let structure =
+        swings
+            .iter()
+            .rev()
+            .find(|swing| swing.kind == kind)
+            .map(|previous| match kind {
+                SwingKind::High if price > previous.price => SwingStructure::HigherHigh,
+                SwingKind::High => SwingStructure::LowerHigh,
+                SwingKind::Low if price < previous.price => SwingStructure::LowerLow,
+                SwingKind::Low => SwingStructure::HigherLow,
+            });
+    swings.push(SwingPoint {
+        index,
+        price,
+        kind,
+        structure,
+        confirmed_at: Some(confirmed_at),
+    });
+}
+
+/// A horizontal support or resistance zone from [`support_resistance_zones`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SupportResistanceZone {
+    /// Lowest swing price in the zone
+    pub lower: f64,
+    /// Highest swing price in the zone
+    pub upper: f64,
+    /// Number of swing highs and lows in the zone
+    pub touches: usize,
+    pub first_touch: usize,
+    pub last_touch: usize,
+    /// Touches weighted by their volume relative to the mean volume, `touches` without volume
+    pub strength: f64,
+}
+
+/// Touches of a sloped support or resistance line from [`trend_line_touches`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TrendLineTouches {
+    /// Index the line starts from
+    pub start: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    /// Number of swing highs and lows on the line
+    pub touches: usize,
+    pub first_touch: Option<usize>,
+    pub last_touch: Option<usize>,
+    /// Touches weighted by their volume relative to the mean volume, `touches` without volume
+    pub strength: f64,
+}
+
+/// Clusters swing highs and lows into horizontal support and resistance zones.
+///
+/// Swing highs are the [`peaks`] of `highs` and swing lows the [`valleys`] of `lows`. Their
+/// prices are bucketed to `precision` by
+/// [`price_distribution`], and runs of
+/// adjacent occupied buckets form a zone. A price touched as both support and resistance is a
+/// single zone.
+///
+/// # Arguments
+///
+/// * `highs` - Slice of highs
+/// * `lows` - Slice of lows
+/// * `volume` - Optional slice of volume to weight the strength of each touch
+/// * `period` - Period over which to find peaks and valleys
+/// * `closest_neighbor` - Minimum distance between peaks and between valleys
+/// * `precision` - Width of the price buckets
+///
+/// # Returns
+///
+/// A vector of [`SupportResistanceZone`] ordered by price
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `highs.len()` != `lows.len()` (or != `volume.len()`)
+/// * `highs.is_empty()`
+/// * `period` == 0 or > `highs.len()`
+/// * `precision` <= 0.0
+/// * the mean of `volume` is <= 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// let prices = vec![
+///     100.0, 105.0, 110.0, 104.0, 100.4, 106.0, 110.3, 105.0, 99.8, 104.0, 109.8, 103.0
+/// ];
+/// let zones = centaur_technical_indicators::chart_trends::support_resistance_zones(
+///     &prices, &prices, None, 3, 1, 1.0
+/// ).unwrap();
+///
+/// assert_eq!(
+///     vec![(99.8, 100.4, 3, 0, 8), (103.0, 103.0, 1, 11, 11), (109.8, 110.3, 3, 2, 10)],
+///     zones
+///         .iter()
+///         .map(|zone| (zone.lower, zone.upper, zone.touches, zone.first_touch, zone.last_touch))
+///         .collect::<Vec<_>>()
+/// );
+/// assert_eq!(3.0, zones[0].strength);
+/// ```
+pub fn support_resistance_zones(
+    highs: &[f64],
+    lows: &[f64],
+    volume: Option<&[f64]>,
+    period: usize,
+    closest_neighbor: usize,
+    precision: f64,
+) -> crate::Result<Vec<SupportResistanceZone>> {
+    let swings = swing_prices(highs, lows, period, closest_neighbor)?;
+    let weights = touch_weights(volume, highs)?;
+    let prices: Vec<f64> = swings.iter().map(|&(price, _)| price).collect();
+    let buckets = price_distribution(&prices, precision)?;
+
+    // Same bucketing as price_distribution, so swings compare exactly with its buckets
+    let bucket = |price: f64| (price / precision).round() * precision;
+    let mut zones = Vec::new();
+    let mut first_bucket = 0;
+    for i in 0..buckets.len() {
+        let last_in_run = buckets
+            .get(i + 1)
+            .is_none_or(|next| next.0 - buckets[i].0 > 1.5 * precision);
+        if !last_in_run {
+            continue;
+        }
+        let (low_bucket, high_bucket) = (buckets[first_bucket].0, buckets[i].0);
+        first_bucket = i + 1;
+
+        let touches: Vec<(f64, usize)> = swings
+            .iter()
+            .copied()
+            .filter(|&(price, _)| (low_bucket..=high_bucket).contains(&bucket(price)))
+            .collect();
+        zones.push(SupportResistanceZone {
+            lower: touches
+                .iter()
+                .map(|&(price, _)| price)
+                .fold(f64::MAX, f64::min),
+            upper: touches
+                .iter()
+                .map(|&(price, _)| price)
+                .fold(f64::MIN, f64::max),
+            touches: touches.len(),
+            first_touch: touches.iter().map(|&(_, index)| index).min().unwrap_or(0),
+            last_touch: touches.iter().map(|&(_, index)| index).max().unwrap_or(0),
+            strength: touches.iter().map(|&(_, index)| weights[index]).sum(),
+        });
+    }
+    Ok(zones)
+}
+
+/// Counts the swing highs and lows touching sloped support and resistance lines.
+///
+/// Accepts the `(start, end, slope, intercept)` lines of [`break_down_trends`] (or of the
+/// [`change_points`](crate::change_points) functions). The end of each line is ignored: the
+/// line is extended from its start to the end of the prices, so swings after the segment it
+/// was fitted to count as touches (retests) of it.
+///
+/// # Arguments
+///
+/// * `highs` - Slice of highs
+/// * `lows` - Slice of lows
+/// * `volume` - Optional slice of volume to weight the strength of each touch
+/// * `trend_lines` - Slice of `(start, end, slope, intercept)` lines
+/// * `period` - Period over which to find peaks and valleys
+/// * `closest_neighbor` - Minimum distance between peaks and between valleys
+/// * `tolerance` - Relative distance from the line within which a swing touches it
+///
+/// # Returns
+///
+/// A vector of [`TrendLineTouches`], one per line in `trend_lines`
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * `highs.len()` != `lows.len()` (or != `volume.len()`)
+/// * `highs.is_empty()`
+/// * `period` == 0 or > `highs.len()`
+/// * `tolerance` < 0.0
+/// * the mean of `volume` is <= 0.0
+///
+/// # Examples
+///
+/// ```rust
+/// let prices = vec![
+///     100.0, 105.0, 110.0, 104.0, 100.4, 106.0, 110.3, 105.0, 99.8, 104.0, 109.8, 103.0
+/// ];
+/// let volume = vec![10.0; 12];
+/// let lines = centaur_technical_indicators::chart_trends::trend_line_touches(
+///     &prices, &prices, Some(&volume), &[(0, 4, 0.1, 99.9)], 3, 1, 0.005
+/// ).unwrap();
+///
+/// assert_eq!(2, lines[0].touches);
+/// assert_eq!((Some(0), Some(4)), (lines[0].first_touch, lines[0].last_touch));
+/// assert_eq!(2.0, lines[0].strength);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn trend_line_touches(
+    highs: &[f64],
+    lows: &[f64],
+    volume: Option<&[f64]>,
+    trend_lines: &[(usize, usize, f64, f64)],
+    period: usize,
+    closest_neighbor: usize,
+    tolerance: f64,
+) -> crate::Result<Vec<TrendLineTouches>> {
+    assert_non_negative("tolerance", tolerance)?;
+    let swings = swing_prices(highs, lows, period, closest_neighbor)?;
+    let weights = touch_weights(volume, highs)?;
+
+    Ok(trend_lines
+        .iter()
+        .map(|&(start, _, slope, intercept)| {
+            let touches: Vec<usize> = swings
+                .iter()
+                .filter(|&&(price, index)| {
+                    let line = slope * index as f64 + intercept;
+                    index >= start && (price - line).abs() <= tolerance * line.abs()
+                })
+                .map(|&(_, index)| index)
+                .collect();
+            TrendLineTouches {
+                start,
+                slope,
+                intercept,
+                touches: touches.len(),
+                first_touch: touches.iter().min().copied(),
+                last_touch: touches.iter().max().copied(),
+                strength: touches.iter().map(|&index| weights[index]).sum(),
+            }
+        })
+        .collect())
+}
+
+/// Swing highs from the peaks of the highs and swing lows from the valleys of the lows.
+fn swing_prices(
+    highs: &[f64],
+    lows: &[f64],
+    period: usize,
+    closest_neighbor: usize,
+) -> crate::Result<Vec<(f64, usize)>> {
+    assert_same_len(&[("highs", highs), ("lows", lows)])?;
+    assert_non_empty("highs", highs)?;
+    let mut swings = peaks(highs, period, closest_neighbor)?;
+    swings.extend(valleys(lows, period, closest_neighbor)?);
+    Ok(swings)
+}
+
+/// Weight of a touch at each index: its volume relative to the mean volume, or 1.0.
+fn touch_weights(volume: Option<&[f64]>, highs: &[f64]) -> crate::Result<Vec<f64>> {
+    match volume {
+        None => Ok(vec![1.0; highs.len()]),
+        Some(volume) => {
+            assert_same_len(&[("highs", highs), ("volume", volume)])?;
+            let mean_volume = mean(volume)?;
+            assert_positive("mean volume", mean_volume)?;
+            Ok(volume.iter().map(|v| v / mean_volume).collect())
+        }
+    }
+}
+
+/// Local maxima as (index, left_edge, right_edge), with the index in the middle of flat
+/// plateaus.
+fn local_maxima(prices: &[f64]) -> Vec<(usize, usize, usize)> {
+    let mut maxima = Vec::new();
+    let mut i = 1;
+    while i + 1 < prices.len() {
+        if prices[i - 1] < prices[i] {
+            let mut ahead = i + 1;
+            while ahead + 1 < prices.len() && prices[ahead] == prices[i] {
+                ahead += 1;
+            }
+            if prices[ahead] < prices[i] {
+                let right_edge = ahead - 1;
+                maxima.push(((i + right_edge) / 2, i, right_edge));
+                i = ahead;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    maxima
+}
+
+/// Drops peaks closer than `distance` to a higher peak, keeping the highest first.
+fn separate_peaks(
+    prices: &[f64],
+    candidates: Vec<(usize, usize, usize)>,
+    distance: usize,
+) -> Vec<(usize, usize, usize)> {
+    let mut by_height: Vec<usize> = (0..candidates.len()).collect();
+    by_height.sort_by(|&a, &b| prices[candidates[b].0].total_cmp(&prices[candidates[a].0]));
+    let mut keep = vec![true; candidates.len()];
+    for &i in &by_height {
+        if !keep[i] {
+            continue;
+        }
+        let index = candidates[i].0;
+        for (j, other) in candidates.iter().enumerate() {
+            if j != i && keep[j] && other.0.abs_diff(index) < distance {
+                keep[j] = false;
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(candidate, keep)| keep.then_some(candidate))
+        .collect()
+}
+
+/// Prominence of the peak at `index` with its (left_base, right_base).
+fn prominence(prices: &[f64], index: usize) -> (f64, usize, usize) {
+    let peak = prices[index];
+    let (mut left_min, mut left_base) = (peak, index);
+    for i in (0..=index).rev() {
+        if prices[i] > peak {
+            break;
+        }
+        if prices[i] < left_min {
+            left_min = prices[i];
+            left_base = i;
+        }
+    }
+    let (mut right_min, mut right_base) = (peak, index);
+    for (i, &price) in prices.iter().enumerate().skip(index) {
+        if price > peak {
+            break;
+        }
+        if price < right_min {
+            right_min = price;
+            right_base = i;
+        }
+    }
+    (peak - left_min.max(right_min), left_base, right_base)
+}
+
+/// Width of the peak at `relative_height` of its prominence, interpolating between prices.
+fn peak_width(
+    prices: &[f64],
+    index: usize,
+    prominence: f64,
+    left_base: usize,
+    right_base: usize,
+    relative_height: f64,
+) -> f64 {
+    let height = prices[index] - prominence * relative_height;
+
+    let mut left = index;
+    while left_base < left && height < prices[left] {
+        left -= 1;
+    }
+    let mut left_intersection = left as f64;
+    if prices[left] < height {
+        left_intersection += (height - prices[left]) / (prices[left + 1] - prices[left]);
+    }
+
+    let mut right = index;
+    while right < right_base && height < prices[right] {
+        right += 1;
+    }
+    let mut right_intersection = right as f64;
+    if prices[right] < height {
+        right_intersection -= (height - prices[right]) / (prices[right - 1] - prices[right]);
+    }
+    right_intersection - left_intersection
+}
+
 /// Calculates the slope and intercept of the trend line fitted to all prices.
 ///
 /// # Arguments
@@ -405,9 +1413,106 @@ pub fn break_down_trends(
     prices: &[f64],
     trend_break_config: TrendBreakConfig,
 ) -> crate::Result<Vec<(usize, usize, f64, f64)>> {
+    Ok(
+        break_down_trends_with_diagnostics(prices, trend_break_config)?
+            .iter()
+            .map(|segment| (segment.start, segment.end, segment.slope, segment.intercept))
+            .collect(),
+    )
+}
+
+/// Kind of break that ended a trend segment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrendBreak {
+    /// All of the soft thresholds in [`TrendBreakConfig`] were crossed
+    Soft,
+    /// At least one of the hard thresholds in [`TrendBreakConfig`] was crossed
+    Hard,
+}
+
+/// A trend segment from [`break_down_trends_with_diagnostics`] with the fit statistics
+/// of the points it was fitted to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendSegment {
+    /// Index of the first price in the segment
+    pub start: usize,
+    /// Index of the last price in the segment
+    pub end: usize,
+    pub slope: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+    pub adjusted_r_squared: f64,
+    pub rmse: f64,
+    pub durbin_watson: f64,
+    /// Indices skipped as outliers while the segment was being fitted
+    pub outliers: Vec<usize>,
+    /// Break that ended the segment, `None` for the last segment
+    pub break_type: Option<TrendBreak>,
+}
+
+/// Calculates price trends along with the regression diagnostics used to split them.
+///
+/// Runs the same segmentation as [`break_down_trends`], so the start, end, slope and
+/// intercept of each segment match it exactly. Use the diagnostics to see why each break
+/// happened and tune [`TrendBreakConfig`].
+///
+/// # Arguments
+///
+/// * `prices` - Slice of prices
+/// * `trend_break_config` - Configuration thresholds (see [`TrendBreakConfig`])
+///
+/// # Returns
+///
+/// A vector of [`TrendSegment`]. The statistics of each segment are measured against its
+/// line over the points that were fitted, which excludes the outliers. Outliers skipped
+/// after the end of a segment are refitted into the next one, so every outlier lies
+/// between the start and end of its segment.
+///
+/// # Errors
+///
+/// Returns `TechnicalIndicatorError::EmptyData` if `prices` is empty
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::chart_trends::TrendBreak;
+///
+/// let prices = vec![
+///     100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0,
+///     103.0, 106.0, 107.0, 105.0, 104.0, 101.0, 97.0, 100.0
+/// ];
+/// let trend_break_config = centaur_technical_indicators::chart_trends::TrendBreakConfig {
+///     max_outliers: 1,
+///     soft_adj_r_squared_minimum: 0.25,
+///     hard_adj_r_squared_minimum: 0.05,
+///     soft_rmse_multiplier: 1.2,
+///     hard_rmse_multiplier: 1.8,
+///     soft_durbin_watson_min: 1.0,
+///     soft_durbin_watson_max: 3.0,
+///     hard_durbin_watson_min: 0.5,
+///     hard_durbin_watson_max: 3.5,
+/// };
+///
+/// let segments = centaur_technical_indicators::chart_trends::break_down_trends_with_diagnostics(
+///     &prices,
+///     trend_break_config
+/// ).unwrap();
+///
+/// assert_eq!(4, segments.len());
+/// assert_eq!((2, 4), (segments[1].start, segments[1].end));
+/// assert_eq!(Some(TrendBreak::Hard), segments[1].break_type);
+/// assert_eq!(vec![13], segments[3].outliers);
+/// assert_eq!(0.9642857142857143, segments[0].r_squared);
+/// assert_eq!(0.9285714285714286, segments[0].adjusted_r_squared);
+/// assert_eq!(None, segments[3].break_type);
+/// ```
+pub fn break_down_trends_with_diagnostics(
+    prices: &[f64],
+    trend_break_config: TrendBreakConfig,
+) -> crate::Result<Vec<TrendSegment>> {
     assert_non_empty("prices", prices)?;
     let mut outliers: Vec<usize> = Vec::new();
-    let mut trends: Vec<(usize, usize, f64, f64)> = Vec::new();
+    let mut trends: Vec<TrendSegment> = Vec::new();
     let mut current_slope = 0.0;
     let mut current_intercept = 0.0;
     let mut start_index: usize = 0;
@@ -423,7 +1528,7 @@ pub fn break_down_trends(
         }
         if index > end_index {
             let current_trend = get_trend_line(&indexed_points);
-            let (adjusted_r_squared, rmse, durbin_watson) =
+            let (_, adjusted_r_squared, rmse, durbin_watson) =
                 goodness_of_fit(&indexed_points, &current_trend)?;
 
             let soft_break = (adjusted_r_squared < trend_break_config.soft_adj_r_squared_minimum)
@@ -442,20 +1547,32 @@ pub fn break_down_trends(
                     indexed_points.pop();
                     continue;
                 };
-                trends.push((start_index, end_index, current_slope, current_intercept));
+                let break_type = if hard_break {
+                    TrendBreak::Hard
+                } else {
+                    TrendBreak::Soft
+                };
+                // Outliers after `end_index` are refitted as part of the next segment
+                outliers.retain(|&outlier| outlier <= end_index);
+                trends.push(trend_segment(
+                    start_index,
+                    end_index,
+                    (current_slope, current_intercept),
+                    &indexed_points[..indexed_points.len() - 1],
+                    std::mem::take(&mut outliers),
+                    Some(break_type),
+                )?);
                 start_index = end_index;
-                end_index = index;
                 indexed_points = (start_index..=index).map(|x| (prices[x], x)).collect();
                 let current_trend = get_trend_line(&indexed_points);
                 current_slope = current_trend.0;
                 current_intercept = current_trend.1;
                 // if list bigger than 2
                 if indexed_points.len() > 2 {
-                    (_, previous_rmse, _) = goodness_of_fit(&indexed_points, &current_trend)?;
+                    (_, _, previous_rmse, _) = goodness_of_fit(&indexed_points, &current_trend)?;
                 } else {
                     previous_rmse = f64::MAX;
                 };
-                outliers.clear();
             } else {
                 previous_rmse = rmse;
                 current_slope = current_trend.0;
@@ -464,35 +1581,67 @@ pub fn break_down_trends(
         }
         end_index = index;
     }
-    trends.push((start_index, end_index, current_slope, current_intercept));
+    trends.push(trend_segment(
+        start_index,
+        end_index,
+        (current_slope, current_intercept),
+        &indexed_points,
+        outliers,
+        None,
+    )?);
     Ok(trends)
 }
 
-/// Computes adjusted R², RMSE, and Durbin–Watson statistic for an OLS fit.
+/// Builds a [`TrendSegment`] from the points fitted to its line.
+fn trend_segment(
+    start: usize,
+    end: usize,
+    trend: (f64, f64),
+    indexed_points: &[(f64, usize)],
+    outliers: Vec<usize>,
+    break_type: Option<TrendBreak>,
+) -> crate::Result<TrendSegment> {
+    let (r_squared, adjusted_r_squared, rmse, durbin_watson) =
+        goodness_of_fit(indexed_points, &trend)?;
+    Ok(TrendSegment {
+        start,
+        end,
+        slope: trend.0,
+        intercept: trend.1,
+        r_squared,
+        adjusted_r_squared,
+        rmse,
+        durbin_watson,
+        outliers,
+        break_type,
+    })
+}
+
+/// Computes R², adjusted R², RMSE, and Durbin–Watson statistic for an OLS fit.
 ///
 /// # Arguments
 /// * `indexed_points` - Slice of `(price, index)` pairs
 /// * `trend` - `(slope, intercept)` from `get_trend_line`
 ///
 /// # Returns
-/// `(adjusted_r_squared, rmse, durbin_watson)`
+/// `(r_squared, adjusted_r_squared, rmse, durbin_watson)`
 ///
 /// # Errors
 ///
 /// Returns error if `mean` fails (e.g., empty data)
 ///
 /// # Notes
-/// - For `n < 2` returns `(0.0, 0.0, 2.0)` (neutral DW).
+/// - For `n < 2` returns `(0.0, 0.0, 0.0, 2.0)` (neutral DW).
 /// - Adjusted R² penalizes small samples; negative raw R² values are clamped to 0.0 here.
 /// - RMSE is unnormalized; if you need scale invariance, normalize externally.
 /// - Durbin–Watson near 2.0 suggests little autocorrelation; < 1 or > 3 signals structural issues.
 fn goodness_of_fit(
     indexed_points: &[(f64, usize)],
     trend: &(f64, f64),
-) -> crate::Result<(f64, f64, f64)> {
+) -> crate::Result<(f64, f64, f64, f64)> {
     let n = indexed_points.len();
     if n < 2 {
-        return Ok((0.0, 0.0, 2.0)); // Bad fit indicators
+        return Ok((0.0, 0.0, 0.0, 2.0)); // Bad fit indicators
     }
 
     let trend_line: Vec<f64> = indexed_points
@@ -543,12 +1692,13 @@ fn goodness_of_fit(
     // RMSE (root mean square error) - more interpretable than standard error
     let rmse = (sum_sq_residuals / n as f64).sqrt();
 
-    Ok((adjusted_r_squared, rmse, durbin_watson))
+    Ok((r_squared, adjusted_r_squared, rmse, durbin_watson))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ConstantModelType;
 
     #[test]
     fn peaks_single_peak() {
@@ -639,6 +1789,275 @@ mod tests {
         );
     }
 
+    #[test]
+    fn find_peaks_all_peaks() {
+        let highs = vec![
+            100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0,
+        ];
+        let peaks = find_peaks(&highs, PeakConfig::default()).unwrap();
+        assert_eq!(
+            Peak {
+                index: 3,
+                value: 102.5,
+                prominence: 0.5,
+                left_base: 2,
+                right_base: 4,
+                width: 0.6666666666666665,
+                left_edge: 3,
+                right_edge: 3,
+            },
+            peaks[1]
+        );
+        assert_eq!(
+            vec![2.0, 0.5, 7.0, 1.0],
+            peaks
+                .iter()
+                .map(|peak| peak.prominence)
+                .collect::<Vec<f64>>()
+        );
+    }
+
+    #[test]
+    fn find_peaks_ignores_edges_and_open_plateaus() {
+        let highs = vec![105.0, 101.0, 103.0, 102.0, 104.0, 104.0];
+        let peaks = find_peaks(&highs, PeakConfig::default()).unwrap();
+        assert_eq!(
+            vec![2],
+            peaks.iter().map(|peak| peak.index).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn find_peaks_plateau_middle() {
+        let highs = vec![100.0, 102.0, 102.0, 102.0, 102.0, 101.0];
+        let peaks = find_peaks(&highs, PeakConfig::default()).unwrap();
+        assert_eq!(1, peaks.len());
+        assert_eq!(
+            (2, 1, 4),
+            (peaks[0].index, peaks[0].left_edge, peaks[0].right_edge)
+        );
+    }
+
+    #[test]
+    fn find_peaks_height() {
+        let highs = vec![
+            100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0,
+        ];
+        let config = PeakConfig {
+            height: Some(102.5),
+            ..PeakConfig::default()
+        };
+        let peaks = find_peaks(&highs, config).unwrap();
+        assert_eq!(
+            vec![1, 3, 5],
+            peaks.iter().map(|peak| peak.index).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn find_peaks_distance_keeps_highest() {
+        let highs = vec![
+            100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0,
+        ];
+        let config = PeakConfig {
+            distance: Some(3),
+            ..PeakConfig::default()
+        };
+        let peaks = find_peaks(&highs, config).unwrap();
+        assert_eq!(
+            vec![1, 5, 9],
+            peaks.iter().map(|peak| peak.index).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn find_peaks_width() {
+        let highs = vec![
+            100.0, 103.0, 102.0, 102.5, 101.0, 107.0, 107.0, 104.0, 100.0, 102.0, 101.0,
+        ];
+        let config = PeakConfig {
+            width: Some(2.0),
+            ..PeakConfig::default()
+        };
+        let peaks = find_peaks(&highs, config).unwrap();
+        assert_eq!(
+            vec![5],
+            peaks.iter().map(|peak| peak.index).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn find_peaks_too_short() {
+        assert!(find_peaks(&[100.0, 101.0], PeakConfig::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn find_peaks_empty() {
+        assert!(find_peaks(&[], PeakConfig::default()).is_err());
+    }
+
+    #[test]
+    fn find_peaks_zero_distance() {
+        let config = PeakConfig {
+            distance: Some(0),
+            ..PeakConfig::default()
+        };
+        assert!(find_peaks(&[100.0, 101.0, 100.0], config).is_err());
+    }
+
+    #[test]
+    fn find_peaks_negative_relative_height() {
+        let config = PeakConfig {
+            relative_height: -0.5,
+            ..PeakConfig::default()
+        };
+        assert!(find_peaks(&[100.0, 101.0, 100.0], config).is_err());
+    }
+
+    #[test]
+    fn find_valleys_height_is_maximum() {
+        let lows = vec![100.0, 97.0, 98.0, 97.5, 99.0, 93.0, 96.0, 100.0, 98.0, 99.0];
+        let config = PeakConfig {
+            height: Some(97.0),
+            ..PeakConfig::default()
+        };
+        let valleys = find_valleys(&lows, config).unwrap();
+        assert_eq!(
+            vec![(97.0, 1), (93.0, 5)],
+            valleys
+                .iter()
+                .map(|valley| (valley.value, valley.index))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!((0, 7), (valleys[1].left_base, valleys[1].right_base));
+    }
+
+    #[test]
+    fn peak_trend_with_config_too_few_peaks() {
+        let highs = vec![100.0, 103.0, 102.0, 102.5, 101.0];
+        let config = PeakConfig {
+            prominence: Some(1.0),
+            ..PeakConfig::default()
+        };
+        assert!(peak_trend_with_config(&highs, config).is_err());
+    }
+
+    #[test]
+    fn valley_trend_with_config_too_few_valleys() {
+        let lows = vec![100.0, 97.0, 98.0];
+        assert!(valley_trend_with_config(&lows, PeakConfig::default()).is_err());
+    }
+
+    #[test]
+    fn break_down_trends_with_diagnostics_matches_break_down_trends() {
+        let prices = vec![
+            100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28, 100.49, 100.86, 101.03, 100.65,
+            100.21, 99.86, 99.67, 99.45,
+        ];
+        let config = TrendBreakConfig::default();
+        let segments = break_down_trends_with_diagnostics(&prices, config).unwrap();
+        let trends: Vec<(usize, usize, f64, f64)> = segments
+            .iter()
+            .map(|segment| (segment.start, segment.end, segment.slope, segment.intercept))
+            .collect();
+        assert_eq!(break_down_trends(&prices, config).unwrap(), trends);
+    }
+
+    #[test]
+    fn break_down_trends_with_diagnostics_segment() {
+        let prices = vec![
+            100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28, 100.49, 100.86, 101.03, 100.65,
+            100.21, 99.86, 99.67, 99.45,
+        ];
+        let segments =
+            break_down_trends_with_diagnostics(&prices, TrendBreakConfig::default()).unwrap();
+        assert_eq!(5, segments.len());
+        assert_eq!(
+            TrendSegment {
+                start: 0,
+                end: 3,
+                slope: -0.10142857142857054,
+                intercept: 100.52857142857142,
+                r_squared: 0.7447185699512305,
+                adjusted_r_squared: 0.48943713990246107,
+                rmse: 0.07406560798180693,
+                durbin_watson: 2.9285714285714284,
+                outliers: vec![2],
+                break_type: Some(TrendBreak::Hard),
+            },
+            segments[0]
+        );
+        assert_eq!(
+            TrendSegment {
+                start: 12,
+                end: 14,
+                slope: -0.2049999999999983,
+                intercept: 102.32499999999999,
+                r_squared: 0.9982185273159143,
+                adjusted_r_squared: 0.9964370546318286,
+                rmse: 0.007071067811865743,
+                durbin_watson: 3.0,
+                outliers: vec![],
+                break_type: None,
+            },
+            segments[4]
+        );
+    }
+
+    #[test]
+    fn break_down_trends_with_diagnostics_outliers_within_segment() {
+        let prices = vec![
+            100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0, 103.0, 106.0, 107.0, 105.0, 104.0,
+            101.0, 97.0, 100.0,
+        ];
+        for max_outliers in 1..4 {
+            let config = TrendBreakConfig {
+                max_outliers,
+                soft_adj_r_squared_minimum: 0.25,
+                hard_adj_r_squared_minimum: 0.05,
+                soft_rmse_multiplier: 1.2,
+                hard_rmse_multiplier: 1.8,
+                soft_durbin_watson_min: 1.0,
+                soft_durbin_watson_max: 3.0,
+                hard_durbin_watson_min: 0.5,
+                hard_durbin_watson_max: 3.5,
+            };
+            let segments = break_down_trends_with_diagnostics(&prices, config).unwrap();
+            for segment in segments {
+                assert!(segment
+                    .outliers
+                    .iter()
+                    .all(|&outlier| outlier >= segment.start && outlier <= segment.end));
+            }
+        }
+    }
+
+    #[test]
+    fn break_down_trends_with_diagnostics_soft_break() {
+        let prices = vec![100.0, 101.2, 101.9, 103.1, 102.8, 102.9];
+        let trend_break_config = TrendBreakConfig {
+            max_outliers: 0,
+            soft_adj_r_squared_minimum: 1.1,
+            hard_adj_r_squared_minimum: -1.0,
+            soft_rmse_multiplier: 0.0,
+            hard_rmse_multiplier: f64::MAX,
+            soft_durbin_watson_min: 4.0,
+            soft_durbin_watson_max: 4.0,
+            hard_durbin_watson_min: 0.0,
+            hard_durbin_watson_max: 4.0,
+        };
+        let segments = break_down_trends_with_diagnostics(&prices, trend_break_config).unwrap();
+        assert_eq!(Some(TrendBreak::Soft), segments[0].break_type);
+        assert_eq!(None, segments.last().unwrap().break_type);
+    }
+
+    #[test]
+    fn break_down_trends_with_diagnostics_empty() {
+        assert!(break_down_trends_with_diagnostics(&[], TrendBreakConfig::default()).is_err());
+    }
+
     #[test]
     fn break_down_trends_std_dev() {
         let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
@@ -662,4 +2081,385 @@ mod tests {
             trend_break_down
         );
     }
+
+    #[test]
+    fn zigzag_lower_highs_lower_lows() {
+        let prices = vec![100.0, 110.0, 104.0, 99.0, 106.0, 93.0, 98.0, 90.0];
+        let swings = zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                (0, 100.0, SwingKind::Low, None, Some(1)),
+                (1, 110.0, SwingKind::High, None, Some(2)),
+                (
+                    3,
+                    99.0,
+                    SwingKind::Low,
+                    Some(SwingStructure::LowerLow),
+                    Some(4)
+                ),
+                (
+                    4,
+                    106.0,
+                    SwingKind::High,
+                    Some(SwingStructure::LowerHigh),
+                    Some(5)
+                ),
+                (
+                    5,
+                    93.0,
+                    SwingKind::Low,
+                    Some(SwingStructure::LowerLow),
+                    Some(6)
+                ),
+                (
+                    6,
+                    98.0,
+                    SwingKind::High,
+                    Some(SwingStructure::LowerHigh),
+                    Some(7)
+                ),
+                (
+                    7,
+                    90.0,
+                    SwingKind::Low,
+                    Some(SwingStructure::LowerLow),
+                    None
+                ),
+            ],
+            swings
+                .iter()
+                .map(|s| (s.index, s.price, s.kind, s.structure, s.confirmed_at))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn zigzag_equal_swings() {
+        let prices = vec![100.0, 110.0, 100.0, 110.0, 100.0];
+        let swings = zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                None,
+                None,
+                Some(SwingStructure::HigherLow),
+                Some(SwingStructure::LowerHigh),
+                Some(SwingStructure::HigherLow)
+            ],
+            swings.iter().map(|s| s.structure).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn zigzag_highs_and_lows() {
+        let highs = vec![11.0, 12.0, 11.0, 10.0, 11.0, 13.0];
+        let lows = vec![10.0, 11.0, 9.0, 9.0, 10.0, 12.0];
+        let close = vec![10.5, 11.5, 10.0, 9.5, 10.5, 12.5];
+        let swings = zigzag(
+            &highs,
+            &lows,
+            &close,
+            ZigZagThreshold::Percent(0.1),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![(0, 10.0), (1, 12.0), (2, 9.0), (5, 13.0)],
+            swings
+                .iter()
+                .map(|s| (s.index, s.price))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn zigzag_average_true_range() {
+        let close = vec![10.0, 13.0, 12.0, 11.0, 14.0, 15.0, 12.0];
+        let highs: Vec<f64> = close.iter().map(|c| c + 1.0).collect();
+        let lows: Vec<f64> = close.iter().map(|c| c - 1.0).collect();
+        let threshold = ZigZagThreshold::AverageTrueRange {
+            period: 3,
+            multiplier: 1.0,
+        };
+        let swings = zigzag(
+            &highs,
+            &lows,
+            &close,
+            threshold,
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                (0, 9.0, None, Some(2)),
+                (1, 14.0, None, Some(3)),
+                (3, 10.0, Some(SwingStructure::HigherLow), Some(4)),
+                (5, 16.0, Some(SwingStructure::HigherHigh), Some(6)),
+                (6, 11.0, Some(SwingStructure::HigherLow), None),
+            ],
+            swings
+                .iter()
+                .map(|s| (s.index, s.price, s.structure, s.confirmed_at))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn zigzag_custom_central_tendency() {
+        struct Mean;
+
+        impl CentralTendency for Mean {
+            fn central_value(&self, prices: &[f64]) -> crate::Result<f64> {
+                Ok(prices.iter().sum::<f64>() / prices.len() as f64)
+            }
+        }
+
+        let close = vec![10.0, 13.0, 12.0, 11.0, 14.0, 15.0, 12.0];
+        let highs: Vec<f64> = close.iter().map(|c| c + 1.0).collect();
+        let lows: Vec<f64> = close.iter().map(|c| c - 1.0).collect();
+        let threshold = ZigZagThreshold::AverageTrueRange {
+            period: 3,
+            multiplier: 1.0,
+        };
+        assert_eq!(
+            zigzag(
+                &highs,
+                &lows,
+                &close,
+                threshold,
+                ConstantModelType::SimpleMovingAverage,
+            )
+            .unwrap(),
+            zigzag(&highs, &lows, &close, threshold, Mean).unwrap()
+        );
+    }
+
+    #[test]
+    fn zigzag_no_reversal() {
+        let prices = vec![100.0, 101.0, 100.5, 101.5];
+        assert!(zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn zigzag_errors() {
+        let prices = vec![100.0, 101.0, 100.5];
+        assert!(zigzag(
+            &[],
+            &[],
+            &[],
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .is_err());
+        assert!(zigzag(
+            &prices,
+            &prices[..2],
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+        assert!(zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.0),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .is_err());
+        let threshold = ZigZagThreshold::AverageTrueRange {
+            period: 4,
+            multiplier: 1.0,
+        };
+        assert!(zigzag(
+            &prices,
+            &prices,
+            &prices,
+            threshold,
+            ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn break_of_structure_bearish() {
+        let prices = vec![100.0, 110.0, 104.0, 99.0, 106.0, 93.0, 98.0, 90.0];
+        let swings = zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        let breaks = break_of_structure(&prices, &swings).unwrap();
+        assert_eq!(
+            vec![
+                (3, 0, 100.0, Position::Short),
+                (5, 3, 99.0, Position::Short),
+                (7, 5, 93.0, Position::Short)
+            ],
+            breaks
+                .iter()
+                .map(|b| (b.index, b.swing_index, b.level, b.direction))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn break_of_structure_errors() {
+        let prices = vec![100.0, 110.0, 104.0, 99.0];
+        let swings = zigzag(
+            &prices,
+            &prices,
+            &prices,
+            ZigZagThreshold::Percent(0.05),
+            ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert!(break_of_structure(&[], &swings).is_err());
+        assert!(break_of_structure(&prices[..2], &swings).is_err());
+    }
+
+    const SUPPORT_RESISTANCE_PRICES: [f64; 12] = [
+        100.0, 105.0, 110.0, 104.0, 100.4, 106.0, 110.3, 105.0, 99.8, 104.0, 109.8, 103.0,
+    ];
+
+    #[test]
+    fn support_resistance_zones_volume_strength() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        let mut volume = vec![1.0; 12];
+        volume[2] = 13.0;
+        let zones = support_resistance_zones(&prices, &prices, Some(&volume), 3, 1, 1.0).unwrap();
+        assert_eq!(
+            vec![1.5, 0.5, 7.5],
+            zones.iter().map(|zone| zone.strength).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn support_resistance_zones_adjacent_buckets() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        let zones = support_resistance_zones(&prices, &prices, None, 3, 1, 0.25).unwrap();
+        assert_eq!(
+            vec![
+                (99.8, 100.0, 2, 0, 8),
+                (100.4, 100.4, 1, 4, 4),
+                (103.0, 103.0, 1, 11, 11),
+                (109.8, 110.3, 3, 2, 10)
+            ],
+            zones
+                .iter()
+                .map(|zone| (
+                    zone.lower,
+                    zone.upper,
+                    zone.touches,
+                    zone.first_touch,
+                    zone.last_touch
+                ))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn support_resistance_zones_errors() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        assert!(support_resistance_zones(&[], &[], None, 3, 1, 1.0).is_err());
+        assert!(support_resistance_zones(&prices, &prices[..11], None, 3, 1, 1.0).is_err());
+        assert!(support_resistance_zones(&prices, &prices, None, 13, 1, 1.0).is_err());
+        assert!(support_resistance_zones(&prices, &prices, None, 3, 1, 0.0).is_err());
+        assert!(support_resistance_zones(&prices, &prices, Some(&[1.0; 11]), 3, 1, 1.0).is_err());
+        assert!(support_resistance_zones(&prices, &prices, Some(&[0.0; 12]), 3, 1, 1.0).is_err());
+    }
+
+    #[test]
+    fn trend_line_touches_from_start() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        let lines = trend_line_touches(
+            &prices,
+            &prices,
+            None,
+            &[(5, 11, 0.0, 100.0), (0, 11, 0.0, 50.0)],
+            3,
+            1,
+            0.005,
+        )
+        .unwrap();
+        assert_eq!(
+            (1, Some(8), Some(8), 1.0),
+            (
+                lines[0].touches,
+                lines[0].first_touch,
+                lines[0].last_touch,
+                lines[0].strength
+            )
+        );
+        assert_eq!(
+            (0, None, None, 0.0),
+            (
+                lines[1].touches,
+                lines[1].first_touch,
+                lines[1].last_touch,
+                lines[1].strength
+            )
+        );
+    }
+
+    #[test]
+    fn trend_line_touches_extend_past_segment_end() {
+        // The line is fitted up to index 4 but the swing low at 8 still retests it
+        let prices = vec![
+            100.0, 105.0, 110.0, 104.0, 100.4, 106.0, 110.3, 105.0, 99.8, 104.0, 109.8, 103.0,
+        ];
+        let lines =
+            trend_line_touches(&prices, &prices, None, &[(0, 4, 0.1, 99.9)], 3, 1, 0.01).unwrap();
+        assert_eq!(
+            (3, Some(0), Some(8)),
+            (lines[0].touches, lines[0].first_touch, lines[0].last_touch)
+        );
+    }
+
+    #[test]
+    fn trend_line_touches_break_down_trends() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        let trends = break_down_trends(&prices, TrendBreakConfig::default()).unwrap();
+        let lines = trend_line_touches(&prices, &prices, None, &trends, 3, 1, 0.01).unwrap();
+        assert_eq!(trends.len(), lines.len());
+        for (line, trend) in lines.iter().zip(trends.iter()) {
+            assert_eq!(
+                (trend.0, trend.2, trend.3),
+                (line.start, line.slope, line.intercept)
+            );
+        }
+    }
+
+    #[test]
+    fn trend_line_touches_errors() {
+        let prices = SUPPORT_RESISTANCE_PRICES;
+        let lines = [(0, 11, 0.0, 100.0)];
+        assert!(trend_line_touches(&[], &[], None, &lines, 3, 1, 0.01).is_err());
+        assert!(trend_line_touches(&prices, &prices, None, &lines, 3, 1, -0.01).is_err());
+        assert!(trend_line_touches(&prices, &prices, Some(&[1.0; 3]), &lines, 3, 1, 0.01).is_err());
+    }
 }
@@ -1,13 +1,15 @@
 //! # Correlation Indicators
 //!
 //! The `correlation_indicators` module provides functions to measure the co-movement
-//! and statistical relationship between two different price series or assets.
+//! and statistical relationship between two different price series or assets, or across
+//! many assets at once.
 //!
 //! ## When to Use
 //! Use correlation indicators when you want to:
 //! - Quantify how closely two assets move together
 //! - Assess diversification or hedging effectiveness
 //! - Explore relationships between assets
+//! - Build covariance matrices for portfolio construction and risk models
 //!
 //! ## Structure
 //! - **single**: Functions that return a single value for a slice of prices.
@@ -17,29 +19,35 @@
 //!
 //! ### Bulk
 //! - [`correlate_asset_prices`](bulk::correlate_asset_prices): Correlation between two price series
+//! - [`correlation_matrix`](bulk::correlation_matrix): Rolling N x N correlation matrix
+//! - [`covariance_matrix`](bulk::covariance_matrix): Rolling N x N covariance matrix
+//! - [`ledoit_wolf_covariance`](bulk::ledoit_wolf_covariance): Rolling Ledoit-Wolf shrunk covariance matrix
 //!
 //! ### Single
 //! - [`correlate_asset_prices`](single::correlate_asset_prices): Correlation between two price series
+//! - [`correlation_matrix`](single::correlation_matrix): N x N correlation matrix
+//! - [`covariance_matrix`](single::covariance_matrix): N x N covariance matrix
+//! - [`is_positive_semi_definite`](single::is_positive_semi_definite): Checks a matrix is positive semi-definite
+//! - [`ledoit_wolf_covariance`](single::ledoit_wolf_covariance): Ledoit-Wolf shrunk covariance matrix
+//! - [`nearest_correlation_matrix`](single::nearest_correlation_matrix): Repairs a matrix into a valid correlation matrix
 //!
 //! ## API Details
-//! - All functions require two slices of `f64` prices (for the two assets).
+//! - Pairwise functions require two slices of `f64` prices (for the two assets).
+//! - Matrix functions accept a slice of aligned series (`Vec<Vec<f64>>`, `&[&[f64]]`, ...)
+//!   and return row-major `Vec<Vec<f64>>` matrices.
 //! - See each function for further details, panics, and usage examples.
 //!
 //! ---
 
 /// **single**: Functions that return a single value for a slice of prices
 pub mod single {
-    use crate::basic_indicators::single::{
-        absolute_deviation, cauchy_iqr_scale, laplace_std_equivalent, log_standard_deviation,
-        median, mode, standard_deviation, student_t_adjusted_std,
-    };
-    use crate::moving_average::single::moving_average;
-    use crate::validation::{assert_non_empty, assert_same_len, unsupported_type};
-    use crate::volatility_indicators::single::ulcer_index;
-    use crate::{
-        AbsDevConfig, CentralPoint, ConstantModelType, DeviationAggregate, DeviationModel,
-        MovingAverageType,
+    use crate::basic_indicators::single::mean;
+    use crate::linear_algebra::{frobenius_norm, from_eigen, symmetric_eigen};
+    use crate::validation::{
+        assert_non_empty, assert_non_negative, assert_positive, assert_positive_usize,
+        assert_same_len,
     };
+    use crate::{CentralTendency, Deviation, TechnicalIndicatorError};
 
     /// Calculates the correlation between two assets prices.
     ///
@@ -47,8 +55,8 @@ pub mod single {
     ///
     /// * `prices_asset_a` - Slice of prices
     /// * `prices_asset_b` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     ///
     /// # Returns
     ///
@@ -87,8 +95,8 @@ pub mod single {
     pub fn correlate_asset_prices(
         prices_asset_a: &[f64],
         prices_asset_b: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
     ) -> crate::Result<f64> {
         let length = prices_asset_a.len();
         assert_same_len(&[
@@ -97,55 +105,8 @@ pub mod single {
         ])?;
         assert_non_empty("prices_asset_a", prices_asset_a)?;
 
-        let asset_a_average = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices_asset_a, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices_asset_a, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices_asset_a, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices_asset_a,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices_asset_a)?,
-            ConstantModelType::SimpleMovingMode => mode(prices_asset_a)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
-
-        let asset_b_average = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices_asset_b, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices_asset_b, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices_asset_b, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices_asset_b,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices_asset_b)?,
-            ConstantModelType::SimpleMovingMode => mode(prices_asset_b)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let asset_a_average = constant_model_type.central_value(prices_asset_a)?;
+        let asset_b_average = constant_model_type.central_value(prices_asset_b)?;
 
         let joint_average_return: f64 = (0..length)
             .map(|i| (prices_asset_a[i] - asset_a_average) * (prices_asset_b[i] - asset_b_average))
@@ -153,104 +114,411 @@ pub mod single {
 
         let covariance = joint_average_return / length as f64;
 
-        let asset_a_deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices_asset_a)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices_asset_a,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices_asset_a,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices_asset_a,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices_asset_a, config)?
+        let asset_a_deviation = deviation_model.deviation(prices_asset_a)?;
+        let asset_b_deviation = deviation_model.deviation(prices_asset_b)?;
+        Ok(covariance / (asset_a_deviation * asset_b_deviation))
+    }
+
+    /// Calculates the covariance matrix of many aligned asset price series.
+    ///
+    /// Each series is centered once with the chosen [`ConstantModelType`](crate::ConstantModelType), then
+    /// every pair of assets shares the same population covariance calculation
+    /// as [`correlate_asset_prices`].
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    ///
+    /// # Returns
+    ///
+    /// An N x N covariance matrix where N is the number of assets
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()` or any series is empty
+    /// * The series are not all the same length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![
+    ///     vec![100.0, 102.0, 103.0, 101.0, 99.0],
+    ///     vec![200.0, 204.0, 206.0, 202.0, 198.0],
+    ///     vec![50.0, 49.0, 48.0, 50.0, 51.0],
+    /// ];
+    ///
+    /// let covariance =
+    ///     centaur_technical_indicators::correlation_indicators::single::covariance_matrix(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         vec![2.0, 4.0, -1.4],
+    ///         vec![4.0, 8.0, -2.8],
+    ///         vec![-1.4, -2.8, 1.0399999999999998],
+    ///     ],
+    ///     covariance
+    /// );
+    /// ```
+    pub fn covariance_matrix<T: AsRef<[f64]>>(
+        prices: &[T],
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<Vec<f64>>> {
+        let length = assert_aligned(prices)?;
+        let centered = prices
+            .iter()
+            .map(|series| {
+                let series = series.as_ref();
+                let center = constant_model_type.central_value(series)?;
+                Ok(series.iter().map(|price| price - center).collect())
+            })
+            .collect::<crate::Result<Vec<Vec<f64>>>>()?;
+        Ok(cross_products(&centered, length))
+    }
+
+    /// Calculates the correlation matrix of many aligned asset price series.
+    ///
+    /// Entry (i, j) equals [`correlate_asset_prices`] for assets i and j, but
+    /// central values and deviations are only calculated once per asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    ///
+    /// # Returns
+    ///
+    /// An N x N correlation matrix where N is the number of assets
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()` or any series is empty
+    /// * The series are not all the same length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![
+    ///     vec![100.0, 102.0, 103.0, 101.0, 99.0],
+    ///     vec![200.0, 204.0, 206.0, 202.0, 198.0],
+    ///     vec![50.0, 49.0, 48.0, 50.0, 51.0],
+    /// ];
+    ///
+    /// let correlation =
+    ///     centaur_technical_indicators::correlation_indicators::single::correlation_matrix(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         vec![0.9999999999999998, 0.9999999999999998, -0.970725343394151],
+    ///         vec![0.9999999999999998, 0.9999999999999998, -0.970725343394151],
+    ///         vec![-0.970725343394151, -0.970725343394151, 1.0000000000000002],
+    ///     ],
+    ///     correlation
+    /// );
+    /// ```
+    pub fn correlation_matrix<T: AsRef<[f64]>>(
+        prices: &[T],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+    ) -> crate::Result<Vec<Vec<f64>>> {
+        let mut covariance = covariance_matrix(prices, constant_model_type)?;
+        let deviations = prices
+            .iter()
+            .map(|series| deviation_model.deviation(series.as_ref()))
+            .collect::<crate::Result<Vec<f64>>>()?;
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value /= deviations[i] * deviations[j];
             }
-            DeviationModel::UlcerIndex => ulcer_index(prices_asset_a)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices_asset_a)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices_asset_a, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices_asset_a)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices_asset_a)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices_asset_a,
-                precision,
-                low,
-                high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
-
-        let asset_b_deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices_asset_b)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices_asset_b,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices_asset_b,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices_asset_b,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices_asset_b, config)?
+        }
+        Ok(covariance)
+    }
+
+    /// Calculates the Ledoit-Wolf shrunk covariance matrix of many aligned series.
+    ///
+    /// The sample covariance (around the mean) is shrunk towards a scaled
+    /// identity matrix using the optimal intensity from Ledoit and Wolf (2004).
+    /// Shrinkage produces a well conditioned covariance matrix when there are
+    /// few observations relative to the number of assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (shrunk covariance matrix, shrinkage intensity between 0 and 1)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()` or any series is empty
+    /// * The series are not all the same length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let returns = vec![
+    ///     vec![0.01, -0.02, 0.015, 0.003, -0.007],
+    ///     vec![0.012, -0.018, 0.01, 0.001, -0.004],
+    ///     vec![-0.005, 0.01, -0.002, 0.004, 0.006],
+    /// ];
+    ///
+    /// let (covariance, shrinkage) =
+    ///     centaur_technical_indicators::correlation_indicators::single::ledoit_wolf_covariance(
+    ///         &returns
+    ///     ).unwrap();
+    /// assert_eq!(0.27438412089340025, shrinkage);
+    /// assert_eq!(3, covariance.len());
+    /// ```
+    pub fn ledoit_wolf_covariance<T: AsRef<[f64]>>(
+        prices: &[T],
+    ) -> crate::Result<(Vec<Vec<f64>>, f64)> {
+        let length = assert_aligned(prices)?;
+        let assets = prices.len() as f64;
+        let observations = length as f64;
+        let centered: Vec<Vec<f64>> = prices
+            .iter()
+            .map(|series| {
+                let series = series.as_ref();
+                let center = mean(series)?;
+                Ok(series.iter().map(|price| price - center).collect())
+            })
+            .collect::<crate::Result<Vec<Vec<f64>>>>()?;
+        let mut covariance = cross_products(&centered, length);
+
+        let mu = (0..prices.len()).map(|i| covariance[i][i]).sum::<f64>() / assets;
+        let squared_norm: f64 = covariance.iter().flatten().map(|x| x * x).sum();
+        let delta = (squared_norm - assets * mu * mu) / assets;
+        let fourth_moments: f64 = (0..length)
+            .map(|k| centered.iter().map(|x| x[k] * x[k]).sum::<f64>().powi(2))
+            .sum();
+        let beta =
+            ((fourth_moments / observations - squared_norm) / (assets * observations)).min(delta);
+        let shrinkage = if beta <= 0.0 { 0.0 } else { beta / delta };
+
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value *= 1.0 - shrinkage;
+                if i == j {
+                    *value += shrinkage * mu;
+                }
             }
-            DeviationModel::UlcerIndex => ulcer_index(prices_asset_b)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices_asset_b)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices_asset_b, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices_asset_b)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices_asset_b)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices_asset_b,
-                precision,
-                low,
-                high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
-        Ok(covariance / (asset_a_deviation * asset_b_deviation))
+        }
+        Ok((covariance, shrinkage))
+    }
+
+    /// Determines whether a symmetric matrix is positive semi-definite.
+    ///
+    /// Uses the eigenvalues of the matrix, allowing for small negative values
+    /// caused by floating point error.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - Square symmetric matrix (e.g. a correlation or covariance matrix)
+    /// * `tolerance` - Smallest eigenvalue allowed is `-tolerance`
+    ///
+    /// # Returns
+    ///
+    /// `true` if every eigenvalue is at least `-tolerance`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `matrix.is_empty()`
+    /// * `matrix` is not square
+    /// * `matrix` is not symmetric
+    /// * `tolerance` < 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let valid = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+    /// assert!(
+    ///     centaur_technical_indicators::correlation_indicators::single::is_positive_semi_definite(
+    ///         &valid, 1e-10
+    ///     ).unwrap()
+    /// );
+    ///
+    /// let invalid = vec![
+    ///     vec![1.0, 0.9, -0.9],
+    ///     vec![0.9, 1.0, 0.9],
+    ///     vec![-0.9, 0.9, 1.0],
+    /// ];
+    /// assert!(
+    ///     !centaur_technical_indicators::correlation_indicators::single::is_positive_semi_definite(
+    ///         &invalid, 1e-10
+    ///     ).unwrap()
+    /// );
+    /// ```
+    pub fn is_positive_semi_definite(matrix: &[Vec<f64>], tolerance: f64) -> crate::Result<bool> {
+        assert_symmetric(matrix)?;
+        assert_non_negative("tolerance", tolerance)?;
+        let (eigenvalues, _) = symmetric_eigen(matrix);
+        Ok(eigenvalues.iter().all(|&value| value >= -tolerance))
+    }
+
+    /// Finds the nearest valid correlation matrix to a symmetric matrix.
+    ///
+    /// Uses Higham's (2002) alternating projections with Dykstra's correction,
+    /// alternating between the positive semi-definite cone and the set of
+    /// matrices with a unit diagonal. Useful to repair correlation matrices
+    /// built from pairwise, rolling or robust estimates.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - Square symmetric matrix
+    /// * `max_iterations` - Maximum number of projections
+    /// * `tolerance` - Stop once the relative change between iterations falls below this value
+    ///
+    /// # Returns
+    ///
+    /// The nearest correlation matrix in the Frobenius norm
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `matrix.is_empty()`
+    /// * `matrix` is not square
+    /// * `matrix` is not symmetric
+    /// * `max_iterations` == 0
+    /// * `tolerance` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let invalid = vec![
+    ///     vec![1.0, 0.9, -0.9],
+    ///     vec![0.9, 1.0, 0.9],
+    ///     vec![-0.9, 0.9, 1.0],
+    /// ];
+    /// let repaired =
+    ///     centaur_technical_indicators::correlation_indicators::single::nearest_correlation_matrix(
+    ///         &invalid, 100, 1e-10
+    ///     ).unwrap();
+    /// assert!(
+    ///     centaur_technical_indicators::correlation_indicators::single::is_positive_semi_definite(
+    ///         &repaired, 1e-8
+    ///     ).unwrap()
+    /// );
+    /// assert_eq!(1.0, repaired[0][0]);
+    /// ```
+    pub fn nearest_correlation_matrix(
+        matrix: &[Vec<f64>],
+        max_iterations: usize,
+        tolerance: f64,
+    ) -> crate::Result<Vec<Vec<f64>>> {
+        assert_symmetric(matrix)?;
+        assert_positive_usize("max_iterations", max_iterations)?;
+        assert_positive("tolerance", tolerance)?;
+
+        let n = matrix.len();
+        let mut correction = vec![vec![0.0; n]; n];
+        let mut y = matrix.to_vec();
+        for _ in 0..max_iterations {
+            let r: Vec<Vec<f64>> = (0..n)
+                .map(|i| (0..n).map(|j| y[i][j] - correction[i][j]).collect())
+                .collect();
+            let (eigenvalues, eigenvectors) = symmetric_eigen(&r);
+            let clipped: Vec<f64> = eigenvalues.iter().map(|value| value.max(0.0)).collect();
+            let x = from_eigen(&clipped, &eigenvectors);
+            for i in 0..n {
+                for j in 0..n {
+                    correction[i][j] = x[i][j] - r[i][j];
+                }
+            }
+            let mut next = x;
+            for (i, row) in next.iter_mut().enumerate() {
+                row[i] = 1.0;
+            }
+            let change: Vec<Vec<f64>> = (0..n)
+                .map(|i| (0..n).map(|j| next[i][j] - y[i][j]).collect())
+                .collect();
+            let converged = frobenius_norm(&change) / frobenius_norm(&next) < tolerance;
+            y = next;
+            if converged {
+                break;
+            }
+        }
+        Ok(y)
+    }
+
+    /// Validates a set of aligned series and returns their common length
+    #[inline]
+    fn assert_aligned<T: AsRef<[f64]>>(prices: &[T]) -> crate::Result<usize> {
+        assert_non_empty("prices", prices)?;
+        let names: Vec<String> = (0..prices.len())
+            .map(|i| format!("prices[{}]", i))
+            .collect();
+        let series: Vec<(&str, &[f64])> = names
+            .iter()
+            .zip(prices)
+            .map(|(name, series)| (name.as_str(), series.as_ref()))
+            .collect();
+        assert_same_len(&series)?;
+        assert_non_empty("prices[0]", series[0].1)?;
+        Ok(series[0].1.len())
+    }
+
+    /// Validates that a matrix is square and symmetric
+    #[inline]
+    fn assert_symmetric(matrix: &[Vec<f64>]) -> crate::Result<()> {
+        assert_non_empty("matrix", matrix)?;
+        let n = matrix.len();
+        for (i, row) in matrix.iter().enumerate() {
+            if row.len() != n {
+                return Err(TechnicalIndicatorError::Custom {
+                    message: format!("matrix must be square: row {} has length {}", i, row.len()),
+                });
+            }
+            for (j, value) in row.iter().enumerate() {
+                if (value - matrix[j][i]).abs() > 1e-12 * value.abs().max(1.0) {
+                    return Err(TechnicalIndicatorError::Custom {
+                        message: format!(
+                            "matrix must be symmetric: ({}, {}) != ({}, {})",
+                            i, j, j, i
+                        ),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Population cross products of centered series
+    #[inline]
+    fn cross_products(centered: &[Vec<f64>], length: usize) -> Vec<Vec<f64>> {
+        let n = centered.len();
+        let mut matrix = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in i..n {
+                let value = centered[i]
+                    .iter()
+                    .zip(&centered[j])
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>()
+                    / length as f64;
+                matrix[i][j] = value;
+                matrix[j][i] = value;
+            }
+        }
+        matrix
     }
 }
 
 /// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
 pub mod bulk {
     use crate::correlation_indicators::single;
-    use crate::validation::{assert_period, assert_same_len};
-    use crate::{ConstantModelType, DeviationModel};
+    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
+    use crate::{CentralTendency, Deviation};
 
     /// Calculates the correlation between two asset prices over a period
     ///
@@ -258,8 +526,8 @@ pub mod bulk {
     ///
     /// * `prices_asset_a` - Slice of prices
     /// * `prices_asset_b` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `period`: Period over which to calculate the correlation
     ///
     /// # Returns
@@ -304,8 +572,8 @@ pub mod bulk {
     pub fn correlate_asset_prices(
         prices_asset_a: &[f64],
         prices_asset_b: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
         period: usize,
     ) -> crate::Result<Vec<f64>> {
         let length = prices_asset_a.len();
@@ -320,12 +588,195 @@ pub mod bulk {
                 single::correlate_asset_prices(
                     &prices_asset_a[i..i + period],
                     &prices_asset_b[i..i + period],
-                    constant_model_type,
-                    deviation_model,
+                    &constant_model_type,
+                    &deviation_model,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the rolling covariance matrix of many aligned asset price series
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `period` - Period over which to calculate the covariance matrix
+    ///
+    /// # Returns
+    ///
+    /// A vector of N x N covariance matrices, one per window
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * The series are not all the same length
+    /// * `period` == 0 or `period` > series length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![
+    ///     vec![100.0, 102.0, 103.0, 101.0, 99.0],
+    ///     vec![200.0, 204.0, 206.0, 202.0, 198.0],
+    /// ];
+    ///
+    /// let covariance =
+    ///     centaur_technical_indicators::correlation_indicators::bulk::covariance_matrix(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         4
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         vec![vec![1.25, 2.5], vec![2.5, 5.0]],
+    ///         vec![vec![2.1875, 4.375], vec![4.375, 8.75]],
+    ///     ],
+    ///     covariance
+    /// );
+    /// ```
+    #[inline]
+    pub fn covariance_matrix<T: AsRef<[f64]>>(
+        prices: &[T],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<Vec<Vec<f64>>>> {
+        let length = rolling_length(prices, period)?;
+        (0..=length - period)
+            .map(|i| single::covariance_matrix(&windows(prices, i, period), &constant_model_type))
+            .collect()
+    }
+
+    /// Calculates the rolling correlation matrix of many aligned asset price series
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    /// * `period` - Period over which to calculate the correlation matrix
+    ///
+    /// # Returns
+    ///
+    /// A vector of N x N correlation matrices, one per window
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * The series are not all the same length
+    /// * `period` == 0 or `period` > series length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![
+    ///     vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0],
+    ///     vec![200.0, 204.0, 206.0, 202.0, 198.0, 193.0, 189.0],
+    /// ];
+    ///
+    /// let correlation =
+    ///     centaur_technical_indicators::correlation_indicators::bulk::correlation_matrix(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         5
+    ///     ).unwrap();
+    /// assert_eq!(3, correlation.len());
+    /// assert_eq!(0.9340577351598457, correlation[1][0][1]);
+    /// assert_eq!(0.34094365457352693, correlation[2][1][0]);
+    /// ```
+    #[inline]
+    pub fn correlation_matrix<T: AsRef<[f64]>>(
+        prices: &[T],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+        period: usize,
+    ) -> crate::Result<Vec<Vec<Vec<f64>>>> {
+        let length = rolling_length(prices, period)?;
+        (0..=length - period)
+            .map(|i| {
+                single::correlation_matrix(
+                    &windows(prices, i, period),
+                    &constant_model_type,
+                    &deviation_model,
                 )
             })
             .collect()
     }
+
+    /// Calculates the rolling Ledoit-Wolf shrunk covariance matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of aligned price (or return) series, one per asset
+    /// * `period` - Period over which to calculate the covariance matrix
+    ///
+    /// # Returns
+    ///
+    /// A vector of (shrunk covariance matrix, shrinkage intensity) tuples, one per window
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * The series are not all the same length
+    /// * `period` == 0 or `period` > series length
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let returns = vec![
+    ///     vec![0.01, -0.02, 0.015, 0.003, -0.007, 0.002],
+    ///     vec![0.012, -0.018, 0.01, 0.001, -0.004, 0.003],
+    ///     vec![-0.005, 0.01, -0.002, 0.004, 0.006, -0.001],
+    /// ];
+    ///
+    /// let shrunk =
+    ///     centaur_technical_indicators::correlation_indicators::bulk::ledoit_wolf_covariance(
+    ///         &returns,
+    ///         5
+    ///     ).unwrap();
+    /// assert_eq!(2, shrunk.len());
+    /// assert_eq!(0.27438412089340025, shrunk[0].1);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn ledoit_wolf_covariance<T: AsRef<[f64]>>(
+        prices: &[T],
+        period: usize,
+    ) -> crate::Result<Vec<(Vec<Vec<f64>>, f64)>> {
+        let length = rolling_length(prices, period)?;
+        (0..=length - period)
+            .map(|i| single::ledoit_wolf_covariance(&windows(prices, i, period)))
+            .collect()
+    }
+
+    /// Validates rolling inputs and returns the common series length
+    #[inline]
+    fn rolling_length<T: AsRef<[f64]>>(prices: &[T], period: usize) -> crate::Result<usize> {
+        assert_non_empty("prices", prices)?;
+        let length = prices[0].as_ref().len();
+        for (i, series) in prices.iter().enumerate() {
+            let series = series.as_ref();
+            assert_same_len(&[
+                ("prices[0]", prices[0].as_ref()),
+                (&format!("prices[{}]", i), series),
+            ])?;
+        }
+        assert_period(period, length)?;
+        Ok(length)
+    }
+
+    /// Window of every series starting at `start`
+    #[inline]
+    fn windows<T: AsRef<[f64]>>(prices: &[T], start: usize, period: usize) -> Vec<&[f64]> {
+        prices
+            .iter()
+            .map(|series| &series.as_ref()[start..start + period])
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -424,7 +875,7 @@ mod tests {
             single::correlate_asset_prices(
                 &prices_a,
                 &prices_b,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 crate::DeviationModel::StandardDeviation
             )
             .unwrap()
@@ -473,7 +924,7 @@ mod tests {
                 &prices_a,
                 &prices_b,
                 crate::ConstantModelType::SimpleMovingAverage,
-                crate::DeviationModel::ModeAbsoluteDeviation
+                crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 }
             )
             .unwrap()
         );
@@ -663,4 +1114,299 @@ mod tests {
         .unwrap();
         assert_eq!(0.8888888888888888, result);
     }
+
+    #[test]
+    fn single_covariance_matrix() {
+        let prices = vec![
+            vec![100.46, 100.53, 100.38, 100.19, 100.21],
+            vec![74.71, 71.98, 68.33, 63.6, 65.92],
+        ];
+        let covariance =
+            single::covariance_matrix(&prices, crate::ConstantModelType::SimpleMovingAverage)
+                .unwrap();
+        assert_eq!(covariance[0][1], covariance[1][0]);
+        assert_eq!(
+            crate::basic_indicators::single::variance(&prices[0]).unwrap(),
+            covariance[0][0]
+        );
+    }
+
+    #[test]
+    fn single_correlation_matrix_matches_pairwise() {
+        let prices = vec![
+            vec![100.46, 100.53, 100.38, 100.19, 100.21],
+            vec![74.71, 71.98, 68.33, 63.6, 65.92],
+            vec![50.1, 50.7, 51.2, 50.3, 49.9],
+        ];
+        let models = [
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::ConstantModelType::ExponentialMovingAverage,
+            crate::ConstantModelType::SimpleMovingMedian,
+        ];
+        let deviations = [
+            crate::DeviationModel::StandardDeviation,
+            crate::DeviationModel::MeanAbsoluteDeviation,
+            crate::DeviationModel::UlcerIndex,
+        ];
+        for model in models {
+            for deviation in deviations {
+                let matrix = single::correlation_matrix(&prices, model, deviation).unwrap();
+                for i in 0..3 {
+                    for j in 0..3 {
+                        assert_eq!(
+                            single::correlate_asset_prices(
+                                &prices[i], &prices[j], model, deviation
+                            )
+                            .unwrap(),
+                            matrix[i][j]
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_correlation_matrix_slices() {
+        let a = [100.46, 100.53, 100.38, 100.19, 100.21];
+        let b = [74.71, 71.98, 68.33, 63.6, 65.92];
+        let matrix = single::correlation_matrix(
+            &[&a[..], &b[..]],
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+        )
+        .unwrap();
+        assert_eq!(0.9042213658878326, matrix[0][1]);
+    }
+
+    #[test]
+    fn single_correlation_matrix_length_error() {
+        let prices = vec![vec![100.46, 100.53, 100.38], vec![74.71, 71.98]];
+        assert!(single::correlation_matrix(
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_covariance_matrix_empty_error() {
+        let prices: Vec<Vec<f64>> = Vec::new();
+        assert!(
+            single::covariance_matrix(&prices, crate::ConstantModelType::SimpleMovingAverage)
+                .is_err()
+        );
+        let prices: Vec<Vec<f64>> = vec![Vec::new(), Vec::new()];
+        assert!(
+            single::covariance_matrix(&prices, crate::ConstantModelType::SimpleMovingAverage)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn single_ledoit_wolf_covariance() {
+        let returns = vec![
+            vec![0.01, -0.02, 0.015, 0.003, -0.007],
+            vec![0.012, -0.018, 0.01, 0.001, -0.004],
+            vec![-0.005, 0.01, -0.002, 0.004, 0.006],
+        ];
+        let sample =
+            single::covariance_matrix(&returns, crate::ConstantModelType::SimpleMovingAverage)
+                .unwrap();
+        let (shrunk, shrinkage) = single::ledoit_wolf_covariance(&returns).unwrap();
+        assert_eq!(0.27438412089340025, shrinkage);
+        assert_eq!((1.0 - shrinkage) * sample[0][1], shrunk[0][1]);
+        let trace: f64 = (0..3).map(|i| sample[i][i]).sum();
+        let shrunk_trace: f64 = (0..3).map(|i| shrunk[i][i]).sum();
+        assert!((trace - shrunk_trace).abs() < 1e-15);
+    }
+
+    #[test]
+    fn single_ledoit_wolf_covariance_single_asset() {
+        let returns = vec![vec![0.01, -0.02, 0.015, 0.003, -0.007]];
+        let (shrunk, shrinkage) = single::ledoit_wolf_covariance(&returns).unwrap();
+        assert_eq!(0.0, shrinkage);
+        assert_eq!(
+            crate::basic_indicators::single::variance(&returns[0]).unwrap(),
+            shrunk[0][0]
+        );
+    }
+
+    #[test]
+    fn single_ledoit_wolf_covariance_error() {
+        let returns = vec![vec![0.01, -0.02, 0.015], vec![0.012, -0.018]];
+        assert!(single::ledoit_wolf_covariance(&returns).is_err());
+    }
+
+    #[test]
+    fn single_is_positive_semi_definite() {
+        let matrix = vec![
+            vec![1.0, 0.5, 0.2],
+            vec![0.5, 1.0, 0.3],
+            vec![0.2, 0.3, 1.0],
+        ];
+        assert!(single::is_positive_semi_definite(&matrix, 0.0).unwrap());
+        let matrix = vec![
+            vec![1.0, 0.9, -0.9],
+            vec![0.9, 1.0, 0.9],
+            vec![-0.9, 0.9, 1.0],
+        ];
+        assert!(!single::is_positive_semi_definite(&matrix, 1e-10).unwrap());
+    }
+
+    #[test]
+    fn single_is_positive_semi_definite_singular() {
+        let matrix = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+        assert!(single::is_positive_semi_definite(&matrix, 1e-12).unwrap());
+    }
+
+    #[test]
+    fn single_is_positive_semi_definite_square_error() {
+        let matrix = vec![vec![1.0, 0.5], vec![0.5]];
+        assert!(single::is_positive_semi_definite(&matrix, 1e-10).is_err());
+    }
+
+    #[test]
+    fn single_is_positive_semi_definite_symmetric_error() {
+        let matrix = vec![vec![1.0, 0.5], vec![0.4, 1.0]];
+        assert!(single::is_positive_semi_definite(&matrix, 1e-10).is_err());
+    }
+
+    #[test]
+    fn single_is_positive_semi_definite_tolerance_error() {
+        let matrix = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+        assert!(single::is_positive_semi_definite(&matrix, -1.0).is_err());
+    }
+
+    #[test]
+    fn single_nearest_correlation_matrix() {
+        let matrix = vec![
+            vec![1.0, 0.9, -0.9],
+            vec![0.9, 1.0, 0.9],
+            vec![-0.9, 0.9, 1.0],
+        ];
+        let nearest = single::nearest_correlation_matrix(&matrix, 500, 1e-12).unwrap();
+        assert!(single::is_positive_semi_definite(&nearest, 1e-8).unwrap());
+        for (i, row) in nearest.iter().enumerate() {
+            assert_eq!(1.0, row[i]);
+            assert!(row.iter().all(|value| value.abs() <= 1.0));
+        }
+        assert!(nearest[0][1] < 0.9);
+        assert!(nearest[0][2] > -0.9);
+    }
+
+    #[test]
+    fn single_nearest_correlation_matrix_already_valid() {
+        let matrix = vec![
+            vec![1.0, 0.5, 0.2],
+            vec![0.5, 1.0, 0.3],
+            vec![0.2, 0.3, 1.0],
+        ];
+        let nearest = single::nearest_correlation_matrix(&matrix, 100, 1e-12).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((matrix[i][j] - nearest[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn single_nearest_correlation_matrix_iterations_error() {
+        let matrix = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+        assert!(single::nearest_correlation_matrix(&matrix, 0, 1e-10).is_err());
+    }
+
+    #[test]
+    fn single_nearest_correlation_matrix_tolerance_error() {
+        let matrix = vec![vec![1.0, 0.5], vec![0.5, 1.0]];
+        assert!(single::nearest_correlation_matrix(&matrix, 10, 0.0).is_err());
+    }
+
+    #[test]
+    fn bulk_covariance_matrix() {
+        let prices = vec![
+            vec![100.46, 100.53, 100.38, 100.19, 100.21],
+            vec![74.71, 71.98, 68.33, 63.6, 65.92],
+        ];
+        let covariance =
+            bulk::covariance_matrix(&prices, crate::ConstantModelType::SimpleMovingAverage, 3)
+                .unwrap();
+        assert_eq!(3, covariance.len());
+        assert_eq!(
+            single::covariance_matrix(
+                &[&prices[0][2..5], &prices[1][2..5]],
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap(),
+            covariance[2]
+        );
+    }
+
+    #[test]
+    fn bulk_correlation_matrix() {
+        let prices_a = vec![100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28];
+        let prices_b = vec![74.71, 71.98, 68.33, 63.6, 65.92, 68.25, 66.95];
+        let pairwise = bulk::correlate_asset_prices(
+            &prices_a,
+            &prices_b,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            5,
+        )
+        .unwrap();
+        let matrices = bulk::correlation_matrix(
+            &[prices_a, prices_b],
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            5,
+        )
+        .unwrap();
+        let off_diagonal: Vec<f64> = matrices.iter().map(|m| m[0][1]).collect();
+        assert_eq!(pairwise, off_diagonal);
+    }
+
+    #[test]
+    fn bulk_correlation_matrix_period_error() {
+        let prices = vec![vec![100.46, 100.53, 100.38], vec![74.71, 71.98, 68.33]];
+        assert!(bulk::correlation_matrix(
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            4
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_correlation_matrix_length_error() {
+        let prices = vec![vec![100.46, 100.53, 100.38], vec![74.71, 71.98]];
+        assert!(bulk::correlation_matrix(
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            2
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_ledoit_wolf_covariance() {
+        let returns = vec![
+            vec![0.01, -0.02, 0.015, 0.003, -0.007, 0.002],
+            vec![0.012, -0.018, 0.01, 0.001, -0.004, 0.003],
+        ];
+        let shrunk = bulk::ledoit_wolf_covariance(&returns, 5).unwrap();
+        assert_eq!(2, shrunk.len());
+        assert_eq!(
+            single::ledoit_wolf_covariance(&[&returns[0][1..], &returns[1][1..]]).unwrap(),
+            shrunk[1]
+        );
+    }
+
+    #[test]
+    fn bulk_ledoit_wolf_covariance_error() {
+        let returns = vec![vec![0.01, -0.02, 0.015], vec![0.012, -0.018, 0.01]];
+        assert!(bulk::ledoit_wolf_covariance(&returns, 0).is_err());
+    }
 }
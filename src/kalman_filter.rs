@@ -0,0 +1,562 @@
+//! # Kalman Filter
+//!
+//! The `kalman_filter` module provides small linear Kalman filters for adaptive
+//! smoothing of prices and for estimating time-varying hedge ratios.
+//!
+//! Unlike a moving average, a Kalman filter has no window: it carries a state
+//! estimate and its uncertainty forward, and the balance between the process
+//! noise and the observation noise decides how quickly it adapts.
+//!
+//! ## When to Use
+//! Use these functions when you want to:
+//! - Smooth prices with a local level (random walk plus noise) model
+//! - Track both level and slope with a local linear trend model
+//! - Estimate a hedge ratio that adapts over time between two assets
+//!
+//! ## Structure
+//! - **single**: Functions that run the filter over a slice and return the final state.
+//! - **bulk**: Functions that run the filter over a slice and return the filtered state at each observation.
+//!
+//! ## Included Functions
+//! ### Bulk
+//! - [`hedge_ratio`](bulk::hedge_ratio): Dynamic hedge ratio, intercept, forecast error and its deviation
+//! - [`local_level`](bulk::local_level): Local level smoother
+//! - [`local_trend`](bulk::local_trend): Local linear trend smoother (level and slope)
+//!
+//! ### Single
+//! - [`hedge_ratio`](single::hedge_ratio): Latest dynamic hedge ratio and intercept
+//! - [`local_level`](single::local_level): Latest local level estimate
+//! - [`local_trend`](single::local_trend): Latest local linear trend estimate (level and slope)
+//!
+//! ## API Details
+//! - Noise parameters are variances. Higher process noise (or lower observation noise) makes the filter react faster.
+//! - [`single::local_level`] returns one central value for a slice, so it can be used wherever a moving average would be.
+//! - See function-level docs for formulas, panics, and usage examples.
+//!
+//! ---
+
+/// **single**: Functions that run the filter over a slice and return the final state.
+pub mod single {
+    use crate::kalman_filter::bulk;
+
+    /// Calculates the latest local level of a slice of prices
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `process_noise` - Variance of the level between observations (>= 0.0)
+    /// * `observation_noise` - Variance of prices around the level (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// The filtered level after the last price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `process_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let level = centaur_technical_indicators::kalman_filter::single::local_level(
+    ///     &prices,
+    ///     1.0,
+    ///     1.0
+    /// ).unwrap();
+    /// assert_eq!(100.58181818181818, level);
+    /// ```
+    #[inline]
+    pub fn local_level(
+        prices: &[f64],
+        process_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<f64> {
+        let levels = bulk::local_level(prices, process_noise, observation_noise)?;
+        Ok(levels[levels.len() - 1])
+    }
+
+    /// Calculates the latest local linear trend of a slice of prices
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `level_noise` - Variance of the level between observations (>= 0.0)
+    /// * `slope_noise` - Variance of the slope between observations (>= 0.0)
+    /// * `observation_noise` - Variance of prices around the level (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (level, slope) after the last price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `level_noise` or `slope_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 101.0, 102.0, 103.0, 104.0];
+    ///
+    /// let (level, slope) = centaur_technical_indicators::kalman_filter::single::local_trend(
+    ///     &prices,
+    ///     0.1,
+    ///     0.01,
+    ///     1.0
+    /// ).unwrap();
+    /// assert_eq!((103.82568575000835, 0.8940092351929144), (level, slope));
+    /// ```
+    #[inline]
+    pub fn local_trend(
+        prices: &[f64],
+        level_noise: f64,
+        slope_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<(f64, f64)> {
+        let trends = bulk::local_trend(prices, level_noise, slope_noise, observation_noise)?;
+        Ok(trends[trends.len() - 1])
+    }
+
+    /// Calculates the latest dynamic hedge ratio of asset a on asset b
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    /// * `process_noise` - Variance of the hedge ratio and intercept between observations (>= 0.0)
+    /// * `observation_noise` - Variance of asset a around the fitted value (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (hedge ratio, intercept) after the last observation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.is_empty()`
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    /// * `process_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_a = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let prices_b = vec![50.0, 51.0, 51.5, 50.5, 49.5];
+    ///
+    /// let (hedge_ratio, intercept) =
+    ///     centaur_technical_indicators::kalman_filter::single::hedge_ratio(
+    ///         &prices_a,
+    ///         &prices_b,
+    ///         0.0001,
+    ///         0.001
+    ///     ).unwrap();
+    /// assert_eq!((1.9999999836799853, 8.079057191575774e-7), (hedge_ratio, intercept));
+    /// ```
+    #[inline]
+    pub fn hedge_ratio(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+        process_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<(f64, f64)> {
+        let states = bulk::hedge_ratio(
+            prices_asset_a,
+            prices_asset_b,
+            process_noise,
+            observation_noise,
+        )?;
+        let (hedge_ratio, intercept, _, _) = states[states.len() - 1];
+        Ok((hedge_ratio, intercept))
+    }
+}
+
+/// **bulk**: Functions that run the filter over a slice and return the filtered state at each observation.
+pub mod bulk {
+    use crate::validation::{
+        assert_non_empty, assert_non_negative, assert_positive, assert_same_len,
+    };
+
+    /// Prior variance of the hedge ratio and intercept before the first observation
+    const DIFFUSE_VARIANCE: f64 = 1e7;
+
+    /// Calculates the local level of prices at each observation
+    ///
+    /// The level follows a random walk observed with noise. The filter starts
+    /// at the first price with variance `observation_noise`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `process_noise` - Variance of the level between observations (>= 0.0)
+    /// * `observation_noise` - Variance of prices around the level (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// A vector of filtered levels, one per price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `process_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let levels = centaur_technical_indicators::kalman_filter::bulk::local_level(
+    ///     &prices,
+    ///     1.0,
+    ///     1.0
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![100.0, 101.33333333333333, 102.375, 101.52380952380952, 100.58181818181818],
+    ///     levels
+    /// );
+    /// ```
+    pub fn local_level(
+        prices: &[f64],
+        process_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<Vec<f64>> {
+        assert_non_empty("prices", prices)?;
+        assert_non_negative("process_noise", process_noise)?;
+        assert_positive("observation_noise", observation_noise)?;
+
+        let mut level = prices[0];
+        let mut variance = observation_noise;
+        let mut levels = Vec::with_capacity(prices.len());
+        levels.push(level);
+        for &price in &prices[1..] {
+            variance += process_noise;
+            let gain = variance / (variance + observation_noise);
+            level += gain * (price - level);
+            variance *= 1.0 - gain;
+            levels.push(level);
+        }
+        Ok(levels)
+    }
+
+    /// Calculates the local linear trend of prices at each observation
+    ///
+    /// The level grows by the slope each observation and both follow random
+    /// walks. The filter starts at the first price with a slope of 0.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `level_noise` - Variance of the level between observations (>= 0.0)
+    /// * `slope_noise` - Variance of the slope between observations (>= 0.0)
+    /// * `observation_noise` - Variance of prices around the level (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// A vector of (level, slope) tuples, one per price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `level_noise` or `slope_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 101.0, 102.0];
+    ///
+    /// let trend = centaur_technical_indicators::kalman_filter::bulk::local_trend(
+    ///     &prices,
+    ///     0.1,
+    ///     0.01,
+    ///     1.0
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![(100.0, 0.0), (100.6774193548387, 0.3225806451612903), (101.67845659163987, 0.6473394876050202)],
+    ///     trend
+    /// );
+    /// ```
+    pub fn local_trend(
+        prices: &[f64],
+        level_noise: f64,
+        slope_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        assert_non_empty("prices", prices)?;
+        assert_non_negative("level_noise", level_noise)?;
+        assert_non_negative("slope_noise", slope_noise)?;
+        assert_positive("observation_noise", observation_noise)?;
+
+        let (mut level, mut slope) = (prices[0], 0.0);
+        let mut covariance = [[observation_noise, 0.0], [0.0, observation_noise]];
+        let mut trends = Vec::with_capacity(prices.len());
+        trends.push((level, slope));
+        for &price in &prices[1..] {
+            level += slope;
+            let [[p00, p01], [p10, p11]] = covariance;
+            covariance = [
+                [p00 + p01 + p10 + p11 + level_noise, p01 + p11],
+                [p10 + p11, p11 + slope_noise],
+            ];
+            let innovation_variance = covariance[0][0] + observation_noise;
+            let gain = [
+                covariance[0][0] / innovation_variance,
+                covariance[1][0] / innovation_variance,
+            ];
+            let error = price - level;
+            level += gain[0] * error;
+            slope += gain[1] * error;
+            let [[p00, p01], [p10, p11]] = covariance;
+            covariance = [
+                [(1.0 - gain[0]) * p00, (1.0 - gain[0]) * p01],
+                [p10 - gain[1] * p00, p11 - gain[1] * p01],
+            ];
+            trends.push((level, slope));
+        }
+        Ok(trends)
+    }
+
+    /// Calculates the dynamic hedge ratio of asset a on asset b at each observation
+    ///
+    /// The hedge ratio and intercept follow random walks and are observed
+    /// through `a = intercept + hedge_ratio * b + noise`. The filter starts from
+    /// a diffuse prior (both at 0.0 with a large variance), so the first
+    /// observations pin them down. With `process_noise` of 0.0 the filter is
+    /// recursive least squares and converges to the OLS hedge ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    /// * `process_noise` - Variance of the hedge ratio and intercept between observations (>= 0.0)
+    /// * `observation_noise` - Variance of asset a around the fitted value (> 0.0)
+    ///
+    /// # Returns
+    ///
+    /// A vector of (hedge ratio, intercept, forecast error, forecast standard deviation) tuples.
+    /// The forecast error is the spread of asset a against the prior estimate, and
+    /// dividing it by the forecast standard deviation gives a z-score.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.is_empty()`
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    /// * `process_noise` < 0.0
+    /// * `observation_noise` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_a = vec![100.0, 102.0, 103.0];
+    /// let prices_b = vec![50.0, 51.0, 51.5];
+    ///
+    /// let states = centaur_technical_indicators::kalman_filter::bulk::hedge_ratio(
+    ///     &prices_a,
+    ///     &prices_b,
+    ///     0.0001,
+    ///     0.001
+    /// ).unwrap();
+    /// assert_eq!(3, states.len());
+    /// assert_eq!(1.9999999589195896, states[2].0);
+    /// ```
+    pub fn hedge_ratio(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+        process_noise: f64,
+        observation_noise: f64,
+    ) -> crate::Result<Vec<(f64, f64, f64, f64)>> {
+        assert_same_len(&[
+            ("prices_asset_a", prices_asset_a),
+            ("prices_asset_b", prices_asset_b),
+        ])?;
+        assert_non_empty("prices_asset_a", prices_asset_a)?;
+        assert_non_negative("process_noise", process_noise)?;
+        assert_positive("observation_noise", observation_noise)?;
+
+        let mut state = [0.0, 0.0];
+        let mut covariance = [[DIFFUSE_VARIANCE, 0.0], [0.0, DIFFUSE_VARIANCE]];
+        let mut states = Vec::with_capacity(prices_asset_a.len());
+        for (&a, &b) in prices_asset_a.iter().zip(prices_asset_b) {
+            covariance[0][0] += process_noise;
+            covariance[1][1] += process_noise;
+            let observation = [b, 1.0];
+            let forecast_error = a - (state[0] * b + state[1]);
+            let projected = [
+                covariance[0][0] * observation[0] + covariance[0][1] * observation[1],
+                covariance[1][0] * observation[0] + covariance[1][1] * observation[1],
+            ];
+            let forecast_variance =
+                observation[0] * projected[0] + observation[1] * projected[1] + observation_noise;
+            let gain = [
+                projected[0] / forecast_variance,
+                projected[1] / forecast_variance,
+            ];
+            state[0] += gain[0] * forecast_error;
+            state[1] += gain[1] * forecast_error;
+            // Joseph form, (I - KH) P (I - KH)' + K R K', so the large prior
+            // variance does not cancel away the precision of the update
+            let reduction = [
+                [1.0 - gain[0] * observation[0], -gain[0] * observation[1]],
+                [-gain[1] * observation[0], 1.0 - gain[1] * observation[1]],
+            ];
+            let reduced = reduction.map(|row| {
+                [
+                    row[0] * covariance[0][0] + row[1] * covariance[1][0],
+                    row[0] * covariance[0][1] + row[1] * covariance[1][1],
+                ]
+            });
+            covariance = [0, 1].map(|i| {
+                [0, 1].map(|j| {
+                    reduced[i][0] * reduction[j][0]
+                        + reduced[i][1] * reduction[j][1]
+                        + gain[i] * gain[j] * observation_noise
+                })
+            });
+            states.push((state[0], state[1], forecast_error, forecast_variance.sqrt()));
+        }
+        Ok(states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_local_level() {
+        let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
+        assert_eq!(
+            100.32619770634793,
+            single::local_level(&prices, 0.1, 0.5).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_local_level_no_process_noise_is_mean() {
+        let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
+        let level = single::local_level(&prices, 0.0, 1.0).unwrap();
+        let mean = crate::basic_indicators::single::mean(&prices).unwrap();
+        assert!((level - mean).abs() < 1e-12);
+    }
+
+    #[test]
+    fn single_local_level_error() {
+        assert!(single::local_level(&[], 0.1, 1.0).is_err());
+    }
+
+    #[test]
+    fn single_local_level_noise_error() {
+        let prices = vec![100.2, 100.46, 100.53];
+        assert!(single::local_level(&prices, -0.1, 1.0).is_err());
+        assert!(single::local_level(&prices, 0.1, 0.0).is_err());
+    }
+
+    #[test]
+    fn single_local_trend() {
+        let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
+        assert_eq!(
+            (100.31144424380307, -0.013534535850271819),
+            single::local_trend(&prices, 0.1, 0.01, 0.5).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_local_trend_error() {
+        let prices = vec![100.2, 100.46, 100.53];
+        assert!(single::local_trend(&[], 0.1, 0.01, 1.0).is_err());
+        assert!(single::local_trend(&prices, 0.1, -0.01, 1.0).is_err());
+    }
+
+    #[test]
+    fn single_hedge_ratio() {
+        let prices_a = vec![100.46, 100.53, 100.38, 100.19, 100.21];
+        let prices_b = vec![74.71, 71.98, 68.33, 63.6, 65.92];
+        assert_eq!(
+            (0.02718710958736911, 98.4179282730882),
+            single::hedge_ratio(&prices_a, &prices_b, 0.0001, 0.001).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_hedge_ratio_error() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 71.98];
+        assert!(single::hedge_ratio(&prices_a, &prices_b, 0.0001, 0.001).is_err());
+    }
+
+    #[test]
+    fn bulk_local_level() {
+        let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
+        let levels = bulk::local_level(&prices, 0.1, 0.5).unwrap();
+        assert_eq!(5, levels.len());
+        assert_eq!(100.2, levels[0]);
+        assert_eq!(
+            single::local_level(&prices[..3], 0.1, 0.5).unwrap(),
+            levels[2]
+        );
+    }
+
+    #[test]
+    fn bulk_local_level_error() {
+        assert!(bulk::local_level(&[], 0.1, 0.5).is_err());
+    }
+
+    #[test]
+    fn bulk_local_trend_follows_linear_prices() {
+        let prices: Vec<f64> = (0..50).map(|i| 100.0 + 0.5 * i as f64).collect();
+        let trends = bulk::local_trend(&prices, 0.01, 0.001, 1.0).unwrap();
+        let (level, slope) = trends[trends.len() - 1];
+        assert!((level - prices[49]).abs() < 0.05);
+        assert!((slope - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn bulk_local_trend_error() {
+        let prices = vec![100.2, 100.46, 100.53];
+        assert!(bulk::local_trend(&prices, 0.1, 0.01, 0.0).is_err());
+    }
+
+    #[test]
+    fn bulk_hedge_ratio_converges() {
+        let prices_b: Vec<f64> = (0..100)
+            .map(|i| 50.0 + (i as f64 * 0.3).sin() * 2.0 + i as f64 * 0.05)
+            .collect();
+        let prices_a: Vec<f64> = prices_b.iter().map(|b| 1.5 * b).collect();
+        let states = bulk::hedge_ratio(&prices_a, &prices_b, 0.001, 0.001).unwrap();
+        let (hedge_ratio, intercept, error, deviation) = states[states.len() - 1];
+        assert!((hedge_ratio - 1.5).abs() < 0.05);
+        assert!(intercept.abs() < 0.05);
+        assert!(error.abs() < 3.0 * deviation);
+    }
+
+    #[test]
+    fn bulk_hedge_ratio_no_process_noise_is_ols() {
+        let prices_a = vec![
+            100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28, 100.34,
+        ];
+        let prices_b = vec![74.71, 71.98, 68.33, 63.6, 65.92, 69.14, 67.87, 70.22];
+        let states = bulk::hedge_ratio(&prices_a, &prices_b, 0.0, 0.001).unwrap();
+        let (hedge_ratio, intercept, _, _) = states[states.len() - 1];
+        let (ols_hedge_ratio, ols_intercept) =
+            crate::pairs::single::hedge_ratio(&prices_a, &prices_b).unwrap();
+        assert!((hedge_ratio - ols_hedge_ratio).abs() < 1e-6);
+        assert!((intercept - ols_intercept).abs() < 1e-4);
+    }
+
+    #[test]
+    fn bulk_hedge_ratio_error() {
+        assert!(bulk::hedge_ratio(&[], &[], 0.0001, 0.001).is_err());
+        assert!(bulk::hedge_ratio(&[100.0], &[50.0], -0.0001, 0.001).is_err());
+    }
+}
@@ -33,14 +33,24 @@
 //! ```
 //!
 //! ## Modules
+//! - [`bars`] - Time, tick, volume, dollar, range and imbalance bars from trades
 //! - [`basic_indicators`] - Fundamental stats (mean, median, std, etc.)
 //! - [`candle_indicators`] - Candle chart tools (Ichimoku, bands, envelopes, etc.)
+//! - [`change_points`] - Change point detection: CUSUM, PELT, Bayesian online
+//! - [`chart_patterns`] - Double tops, head and shoulders, triangles, wedges, flags
+//! - [`chart_transforms`] - Heikin-Ashi, Renko, Kagi and Point-and-Figure charts
 //! - [`chart_trends`] - Trend and peak/valley analysis
 //! - [`correlation_indicators`] - Asset correlation metrics
+//! - [`kalman_filter`] - Kalman filter smoothers and dynamic hedge ratios
+//! - [`microstructure`] - Order book, trade classification and liquidity measures from tick data
 //! - [`momentum_indicators`] - Momentum and oscillator indicators
 //! - [`moving_average`] - Moving averages: simple, smoothed, exponential, McGinley, etc.
 //! - [`other_indicators`] - ROI, true range, internal bar strength, etc.
+//! - [`pairs`] - Pairs trading: hedge ratios, spreads, cointegration
+//! - [`regression_indicators`] - Rolling linear regression: slope, R², forecast, bands
+//! - [`sessions`] - Trading sessions, session-anchored indicators, overnight gaps, session VWAP and pivots
 //! - [`strength_indicators`] - Volume and vigor metrics
+//! - [`timeframes`] - Resampling and multi-timeframe alignment without look-ahead
 //! - [`trend_indicators`] - Trend direction and strength
 //! - [`volatility_indicators`] - Volatility measures
 //!
@@ -65,20 +75,32 @@
 
 #![allow(unreachable_patterns)]
 
+pub mod bars;
 pub mod basic_indicators;
 pub mod candle_indicators;
+pub mod change_points;
+pub mod chart_patterns;
+pub mod chart_transforms;
 pub mod chart_trends;
 pub mod correlation_indicators;
+pub mod kalman_filter;
+pub mod microstructure;
 pub mod momentum_indicators;
 pub mod moving_average;
 pub mod other_indicators;
+pub mod pairs;
+pub mod regression_indicators;
+pub mod sessions;
 pub mod strength_indicators;
+pub mod timeframes;
 pub mod trend_indicators;
 pub mod volatility_indicators;
 
 mod error;
 pub use error::*;
 
+mod linear_algebra;
+
 mod types;
 pub use types::*;
 
@@ -0,0 +1,250 @@
+//! Internal linear algebra helpers
+//!
+//! Small, dependency-free routines shared by the indicators that work on
+//! matrices (multi-asset correlation, regressions). Matrices are stored as
+//! row-major `Vec<Vec<f64>>`.
+
+/// Maximum number of Jacobi sweeps before giving up on convergence
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Builds an `n` x `n` identity matrix
+#[inline]
+pub fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect()
+}
+
+/// Eigen decomposition of a symmetric matrix using cyclic Jacobi rotations
+///
+/// # Arguments
+///
+/// * `matrix` - Square symmetric matrix
+///
+/// # Returns
+///
+/// A tuple of (eigenvalues, eigenvectors) where the eigenvectors are the
+/// columns of the returned matrix. Eigenvalues are not sorted.
+pub fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut v = identity(n);
+    let norm: f64 = a.iter().flatten().map(|x| x * x).sum();
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * norm {
+            break;
+        }
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (old_p, old_q) = (*apk, *aqk);
+                    *apk = c * old_p - s * old_q;
+                    *aqk = s * old_p + c * old_q;
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
+
+/// Rebuilds a symmetric matrix from its eigen decomposition, `V diag(values) V'`
+#[inline]
+pub fn from_eigen(values: &[f64], vectors: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = values.len();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    (0..n)
+                        .map(|k| vectors[i][k] * values[k] * vectors[j][k])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Frobenius norm of a matrix
+#[inline]
+pub fn frobenius_norm(matrix: &[Vec<f64>]) -> f64 {
+    matrix.iter().flatten().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Inverts a square matrix using Gauss-Jordan elimination with partial pivoting
+///
+/// Returns `None` if the matrix is singular
+pub fn invert(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut inverse = identity(n);
+    let tolerance =
+        f64::EPSILON * n as f64 * a.iter().flatten().fold(0.0, |m: f64, x| m.max(x.abs()));
+    for column in 0..n {
+        let pivot =
+            (column..n).max_by(|&i, &j| a[i][column].abs().total_cmp(&a[j][column].abs()))?;
+        if a[pivot][column].abs() <= tolerance {
+            return None;
+        }
+        a.swap(column, pivot);
+        inverse.swap(column, pivot);
+        let scale = a[column][column];
+        for k in 0..n {
+            a[column][k] /= scale;
+            inverse[column][k] /= scale;
+        }
+        for row in 0..n {
+            if row == column {
+                continue;
+            }
+            let factor = a[row][column];
+            if factor == 0.0 {
+                continue;
+            }
+            for k in 0..n {
+                a[row][k] -= factor * a[column][k];
+                inverse[row][k] -= factor * inverse[column][k];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+/// Ordinary least squares regression of `y` on the regressors in `x`
+///
+/// Each row of `x` is one observation. Returns the coefficients and their
+/// standard errors, or `None` if the regressors are collinear or there are
+/// not more observations than regressors.
+pub fn ordinary_least_squares(x: &[Vec<f64>], y: &[f64]) -> Option<(Vec<f64>, Vec<f64>)> {
+    let observations = x.len();
+    let regressors = x.first()?.len();
+    if observations <= regressors {
+        return None;
+    }
+    let xtx: Vec<Vec<f64>> = (0..regressors)
+        .map(|i| {
+            (0..regressors)
+                .map(|j| x.iter().map(|row| row[i] * row[j]).sum())
+                .collect()
+        })
+        .collect();
+    let xty: Vec<f64> = (0..regressors)
+        .map(|i| x.iter().zip(y).map(|(row, value)| row[i] * value).sum())
+        .collect();
+    let inverse = invert(&xtx)?;
+    let coefficients: Vec<f64> = inverse
+        .iter()
+        .map(|row| row.iter().zip(&xty).map(|(a, b)| a * b).sum())
+        .collect();
+    let residual_sum_of_squares: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(row, value)| {
+            let fitted: f64 = row.iter().zip(&coefficients).map(|(a, b)| a * b).sum();
+            (value - fitted).powi(2)
+        })
+        .sum();
+    let residual_variance = residual_sum_of_squares / (observations - regressors) as f64;
+    let standard_errors = (0..regressors)
+        .map(|i| (residual_variance * inverse[i][i]).sqrt())
+        .collect();
+    Some((coefficients, standard_errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symmetric_eigen_diagonal() {
+        let matrix = vec![vec![2.0, 0.0], vec![0.0, 3.0]];
+        let (values, vectors) = symmetric_eigen(&matrix);
+        assert_eq!(vec![2.0, 3.0], values);
+        assert_eq!(identity(2), vectors);
+    }
+
+    #[test]
+    fn symmetric_eigen_reconstructs() {
+        let matrix = vec![
+            vec![4.0, 1.0, 0.5],
+            vec![1.0, 3.0, -0.2],
+            vec![0.5, -0.2, 1.0],
+        ];
+        let (values, vectors) = symmetric_eigen(&matrix);
+        let rebuilt = from_eigen(&values, &vectors);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((matrix[i][j] - rebuilt[i][j]).abs() < 1e-12);
+            }
+        }
+        let trace: f64 = values.iter().sum();
+        assert!((8.0 - trace).abs() < 1e-12);
+    }
+
+    #[test]
+    fn frobenius_norm_identity() {
+        assert_eq!(2.0, frobenius_norm(&identity(4)));
+    }
+
+    #[test]
+    fn invert_matrix() {
+        let matrix = vec![vec![4.0, 7.0], vec![2.0, 6.0]];
+        let inverse = invert(&matrix).unwrap();
+        assert_eq!(
+            vec![
+                vec![0.6000000000000001, -0.7000000000000001],
+                vec![-0.2, 0.4]
+            ],
+            inverse
+        );
+    }
+
+    #[test]
+    fn invert_singular_matrix() {
+        let matrix = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert!(invert(&matrix).is_none());
+    }
+
+    #[test]
+    fn ordinary_least_squares_exact_fit() {
+        let x = vec![
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+            vec![1.0, 4.0],
+        ];
+        let y = vec![3.0, 5.0, 7.0, 9.0];
+        let (coefficients, standard_errors) = ordinary_least_squares(&x, &y).unwrap();
+        assert!((coefficients[0] - 1.0).abs() < 1e-12);
+        assert!((coefficients[1] - 2.0).abs() < 1e-12);
+        assert!(standard_errors.iter().all(|se| *se < 1e-6));
+    }
+
+    #[test]
+    fn ordinary_least_squares_too_few_observations() {
+        let x = vec![vec![1.0, 1.0], vec![1.0, 2.0]];
+        assert!(ordinary_least_squares(&x, &[1.0, 2.0]).is_none());
+    }
+}
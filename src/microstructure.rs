@@ -0,0 +1,1132 @@
+//! # Market Microstructure
+//!
+//! The `microstructure` module provides tick-level measures computed from trades
+//! and quotes rather than bars: order book pressure, trade classification, order
+//! flow and liquidity estimators.
+//!
+//! ## When to Use
+//! Use microstructure functions when you want to:
+//! - Measure order book pressure and a size-weighted fair price
+//! - Classify trades as buyer or seller initiated
+//! - Track order flow (cumulative volume delta, VPIN)
+//! - Estimate price impact, illiquidity and the effective spread
+//!
+//! ## Structure
+//! - **single**: Functions that return a single value for a slice of trades or one quote.
+//! - **bulk**: Functions that compute values over a slice of trades or quotes and return a vector.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`amihud_illiquidity`](bulk::amihud_illiquidity): Rolling Amihud illiquidity
+//! - [`cumulative_volume_delta`](bulk::cumulative_volume_delta): Running sum of buy minus sell volume
+//! - [`kyle_lambda`](bulk::kyle_lambda): Rolling Kyle's lambda (price impact)
+//! - [`lee_ready`](bulk::lee_ready): Lee-Ready trade classification
+//! - [`microprice`](bulk::microprice): Size-weighted mid price
+//! - [`order_book_imbalance`](bulk::order_book_imbalance): Bid/ask size imbalance
+//! - [`roll_spread`](bulk::roll_spread): Rolling Roll effective spread
+//! - [`tick_rule`](bulk::tick_rule): Tick rule trade classification
+//! - [`vpin`](bulk::vpin): Volume-synchronized probability of informed trading
+//!
+//! ### Single
+//! - [`amihud_illiquidity`](single::amihud_illiquidity): Amihud illiquidity
+//! - [`kyle_lambda`](single::kyle_lambda): Kyle's lambda (price impact)
+//! - [`lee_ready`](single::lee_ready): Lee-Ready classification of one trade
+//! - [`microprice`](single::microprice): Size-weighted mid price
+//! - [`order_book_imbalance`](single::order_book_imbalance): Bid/ask size imbalance
+//! - [`roll_spread`](single::roll_spread): Roll effective spread
+//! - [`tick_rule`](single::tick_rule): Tick rule classification of one trade
+//!
+//! ## API Details
+//! - Trades are given as aligned slices of prices and sizes, quotes as aligned slices of bids, asks and sizes.
+//! - Quotes passed alongside trades should be the prevailing quotes at the time of each trade.
+//! - Trade classifications use [`TradeSide`](crate::TradeSide); `Unknown` trades count as neither buys nor sells.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+/// **single**: Functions that return a single value for a slice of trades or one quote
+pub mod single {
+    use crate::pairs::single::hedge_ratio;
+    use crate::validation::{
+        assert_finite, assert_min_length, assert_non_empty, assert_non_negative, assert_positive,
+        assert_same_len,
+    };
+    use crate::{TechnicalIndicatorError, TradeSide};
+
+    /// Calculates the order book imbalance between bid and ask sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_size` - Size at the best bid
+    /// * `ask_size` - Size at the best ask
+    ///
+    /// # Returns
+    ///
+    /// `(bid_size - ask_size) / (bid_size + ask_size)`, between -1.0 and 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bid_size` or `ask_size` < 0.0
+    /// * `bid_size + ask_size` == 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let imbalance =
+    ///     centaur_technical_indicators::microstructure::single::order_book_imbalance(300.0, 100.0)
+    ///         .unwrap();
+    /// assert_eq!(0.5, imbalance);
+    /// ```
+    #[inline]
+    pub fn order_book_imbalance(bid_size: f64, ask_size: f64) -> crate::Result<f64> {
+        assert_non_negative("bid_size", bid_size)?;
+        assert_non_negative("ask_size", ask_size)?;
+        assert_positive("bid_size + ask_size", bid_size + ask_size)?;
+        Ok((bid_size - ask_size) / (bid_size + ask_size))
+    }
+
+    /// Calculates the microprice, the mid price weighted by the opposite side's size.
+    ///
+    /// # Arguments
+    ///
+    /// * `bid` - Best bid price
+    /// * `ask` - Best ask price
+    /// * `bid_size` - Size at the best bid
+    /// * `ask_size` - Size at the best ask
+    ///
+    /// # Returns
+    ///
+    /// `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bid` > `ask`
+    /// * `bid_size` or `ask_size` < 0.0
+    /// * `bid_size + ask_size` == 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let microprice =
+    ///     centaur_technical_indicators::microstructure::single::microprice(
+    ///         100.0, 100.1, 300.0, 100.0
+    ///     ).unwrap();
+    /// assert_eq!(100.075, microprice);
+    /// ```
+    #[inline]
+    pub fn microprice(bid: f64, ask: f64, bid_size: f64, ask_size: f64) -> crate::Result<f64> {
+        assert_quote(bid, ask)?;
+        order_book_imbalance(bid_size, ask_size)?;
+        Ok((bid * ask_size + ask * bid_size) / (bid_size + ask_size))
+    }
+
+    /// Classifies a trade with the tick rule.
+    ///
+    /// An uptick is a buy and a downtick is a sell. A zero tick keeps the
+    /// classification of the previous trade.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Trade price
+    /// * `previous_price` - Price of the previous trade
+    /// * `previous_side` - Classification of the previous trade (`TradeSide::Unknown` if none)
+    ///
+    /// # Returns
+    ///
+    /// The [`TradeSide`] of the trade
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let side = centaur_technical_indicators::microstructure::single::tick_rule(
+    ///     100.1, 100.0, TradeSide::Unknown
+    /// );
+    /// assert_eq!(TradeSide::Buy, side);
+    ///
+    /// let side = centaur_technical_indicators::microstructure::single::tick_rule(
+    ///     100.1, 100.1, side
+    /// );
+    /// assert_eq!(TradeSide::Buy, side);
+    /// ```
+    #[inline]
+    pub fn tick_rule(price: f64, previous_price: f64, previous_side: TradeSide) -> TradeSide {
+        if price > previous_price {
+            TradeSide::Buy
+        } else if price < previous_price {
+            TradeSide::Sell
+        } else {
+            previous_side
+        }
+    }
+
+    /// Classifies a trade with the Lee-Ready algorithm.
+    ///
+    /// Trades above the quote midpoint are buys, trades below are sells and
+    /// trades at the midpoint fall back to the tick rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Trade price
+    /// * `bid` - Prevailing best bid
+    /// * `ask` - Prevailing best ask
+    /// * `previous_price` - Price of the previous trade
+    /// * `previous_side` - Tick rule classification of the previous trade (`TradeSide::Unknown` if none)
+    ///
+    /// # Returns
+    ///
+    /// The [`TradeSide`] of the trade
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bid` > `ask`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let side = centaur_technical_indicators::microstructure::single::lee_ready(
+    ///     100.02, 100.0, 100.1, 100.05, TradeSide::Unknown
+    /// ).unwrap();
+    /// assert_eq!(TradeSide::Sell, side);
+    ///
+    /// let side = centaur_technical_indicators::microstructure::single::lee_ready(
+    ///     100.05, 100.0, 100.1, 100.02, TradeSide::Sell
+    /// ).unwrap();
+    /// assert_eq!(TradeSide::Buy, side);
+    /// ```
+    #[inline]
+    pub fn lee_ready(
+        price: f64,
+        bid: f64,
+        ask: f64,
+        previous_price: f64,
+        previous_side: TradeSide,
+    ) -> crate::Result<TradeSide> {
+        assert_quote(bid, ask)?;
+        let midpoint = (bid + ask) / 2.0;
+        Ok(if price > midpoint {
+            TradeSide::Buy
+        } else if price < midpoint {
+            TradeSide::Sell
+        } else {
+            tick_rule(price, previous_price, previous_side)
+        })
+    }
+
+    /// Calculates Kyle's lambda, the price impact per unit of signed volume.
+    ///
+    /// Regresses each price change on the signed volume of the trade that caused it.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    /// * `volumes` - Slice of trade sizes
+    /// * `sides` - Slice of trade classifications
+    ///
+    /// # Returns
+    ///
+    /// The slope of price changes on signed volume
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * Fewer than 3 trades are provided
+    /// * Any volume is < 0.0, NaN or infinite
+    /// * The signed volume is constant
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let prices = vec![100.0, 100.02, 100.01, 100.04, 100.03];
+    /// let volumes = vec![100.0, 200.0, 100.0, 300.0, 100.0];
+    /// let sides = vec![TradeSide::Buy, TradeSide::Buy, TradeSide::Sell, TradeSide::Buy, TradeSide::Sell];
+    ///
+    /// let lambda = centaur_technical_indicators::microstructure::single::kyle_lambda(
+    ///     &prices, &volumes, &sides
+    /// ).unwrap();
+    /// assert_eq!(9.999999999999265e-5, lambda);
+    /// ```
+    pub fn kyle_lambda(prices: &[f64], volumes: &[f64], sides: &[TradeSide]) -> crate::Result<f64> {
+        assert_same_len(&[("prices", prices), ("volumes", volumes)])?;
+        assert_trades(volumes, sides)?;
+        assert_min_length("prices", 3, prices.len())?;
+        let price_changes: Vec<f64> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let signed_volumes: Vec<f64> = volumes[1..]
+            .iter()
+            .zip(&sides[1..])
+            .map(|(volume, side)| signed(*volume, *side))
+            .collect();
+        Ok(hedge_ratio(&price_changes, &signed_volumes)?.0)
+    }
+
+    /// Calculates the Amihud illiquidity ratio.
+    ///
+    /// The average absolute return per unit of traded value (price * volume).
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `volumes` - Slice of volumes
+    ///
+    /// # Returns
+    ///
+    /// The mean of `|return| / (price * volume)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.len()` != `volumes.len()`
+    /// * Fewer than 2 prices are provided
+    /// * Any price, or any volume after the first, is <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 101.0, 100.0];
+    /// let volumes = vec![1000.0, 2000.0, 1000.0];
+    ///
+    /// let illiquidity = centaur_technical_indicators::microstructure::single::amihud_illiquidity(
+    ///     &prices, &volumes
+    /// ).unwrap();
+    /// assert_eq!(7.425742574257432e-8, illiquidity);
+    /// ```
+    pub fn amihud_illiquidity(prices: &[f64], volumes: &[f64]) -> crate::Result<f64> {
+        assert_same_len(&[("prices", prices), ("volumes", volumes)])?;
+        assert_min_length("prices", 2, prices.len())?;
+        let mut total = 0.0;
+        for (window, volume) in prices.windows(2).zip(&volumes[1..]) {
+            assert_positive("prices", window[0])?;
+            assert_positive("prices", window[1])?;
+            assert_positive("volumes", *volume)?;
+            total += (window[1] / window[0] - 1.0).abs() / (window[1] * volume);
+        }
+        Ok(total / (prices.len() - 1) as f64)
+    }
+
+    /// Calculates the Roll estimator of the effective bid-ask spread.
+    ///
+    /// Bid-ask bounce makes consecutive price changes negatively correlated;
+    /// the spread is `2 * sqrt(-cov(dp[t], dp[t-1]))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    ///
+    /// # Returns
+    ///
+    /// The estimated spread, or 0.0 when the autocovariance is not negative
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fewer than 3 prices are provided
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 100.1, 100.0, 100.1, 100.0, 100.1];
+    ///
+    /// let spread = centaur_technical_indicators::microstructure::single::roll_spread(&prices).unwrap();
+    /// assert_eq!(0.19999999999998863, spread);
+    /// ```
+    pub fn roll_spread(prices: &[f64]) -> crate::Result<f64> {
+        assert_min_length("prices", 3, prices.len())?;
+        let changes: Vec<f64> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let current = &changes[1..];
+        let previous = &changes[..changes.len() - 1];
+        let length = current.len() as f64;
+        let mean_current = current.iter().sum::<f64>() / length;
+        let mean_previous = previous.iter().sum::<f64>() / length;
+        let covariance = current
+            .iter()
+            .zip(previous)
+            .map(|(c, p)| (c - mean_current) * (p - mean_previous))
+            .sum::<f64>()
+            / length;
+        if covariance >= 0.0 {
+            return Ok(0.0);
+        }
+        Ok(2.0 * (-covariance).sqrt())
+    }
+
+    /// Signed volume of a trade, positive for buys and negative for sells
+    #[inline]
+    pub(crate) fn signed(volume: f64, side: TradeSide) -> f64 {
+        match side {
+            TradeSide::Buy => volume,
+            TradeSide::Sell => -volume,
+            TradeSide::Unknown => 0.0,
+        }
+    }
+
+    /// Validates a slice of trade sizes and their classifications
+    #[inline]
+    pub(crate) fn assert_trades(volumes: &[f64], sides: &[TradeSide]) -> crate::Result<()> {
+        assert_non_empty("volumes", volumes)?;
+        for &volume in volumes {
+            assert_non_negative("volumes", volume)?;
+            assert_finite("volumes", volume)?;
+        }
+        if volumes.len() != sides.len() {
+            return Err(TechnicalIndicatorError::MismatchedLength {
+                names: vec![
+                    ("volumes".to_string(), volumes.len()),
+                    ("sides".to_string(), sides.len()),
+                ],
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates that a quote is not crossed
+    #[inline]
+    fn assert_quote(bid: f64, ask: f64) -> crate::Result<()> {
+        if bid > ask {
+            return Err(TechnicalIndicatorError::InvalidValue {
+                name: "bid".to_string(),
+                value: bid,
+                reason: format!("cannot be greater than ask ({})", ask),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// **bulk**: Functions that compute values over a slice of trades or quotes and return a vector.
+pub mod bulk {
+    use crate::microstructure::single;
+    use crate::microstructure::single::assert_trades;
+    use crate::validation::{
+        assert_non_empty, assert_period, assert_positive, assert_positive_usize, assert_same_len,
+    };
+    use crate::{TechnicalIndicatorError, TradeSide};
+
+    /// Calculates the order book imbalance for each quote
+    ///
+    /// # Arguments
+    ///
+    /// * `bid_sizes` - Slice of sizes at the best bid
+    /// * `ask_sizes` - Slice of sizes at the best ask
+    ///
+    /// # Returns
+    ///
+    /// A vector of imbalances between -1.0 and 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bid_sizes.is_empty()`
+    /// * `bid_sizes.len()` != `ask_sizes.len()`
+    /// * Any size < 0.0, or both sizes of a quote are 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let bid_sizes = vec![300.0, 100.0, 200.0];
+    /// let ask_sizes = vec![100.0, 100.0, 600.0];
+    ///
+    /// let imbalance = centaur_technical_indicators::microstructure::bulk::order_book_imbalance(
+    ///     &bid_sizes, &ask_sizes
+    /// ).unwrap();
+    /// assert_eq!(vec![0.5, 0.0, -0.5], imbalance);
+    /// ```
+    #[inline]
+    pub fn order_book_imbalance(bid_sizes: &[f64], ask_sizes: &[f64]) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("bid_sizes", bid_sizes), ("ask_sizes", ask_sizes)])?;
+        assert_non_empty("bid_sizes", bid_sizes)?;
+        bid_sizes
+            .iter()
+            .zip(ask_sizes)
+            .map(|(bid_size, ask_size)| single::order_book_imbalance(*bid_size, *ask_size))
+            .collect()
+    }
+
+    /// Calculates the microprice for each quote
+    ///
+    /// # Arguments
+    ///
+    /// * `bids` - Slice of best bid prices
+    /// * `asks` - Slice of best ask prices
+    /// * `bid_sizes` - Slice of sizes at the best bid
+    /// * `ask_sizes` - Slice of sizes at the best ask
+    ///
+    /// # Returns
+    ///
+    /// A vector of microprices
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bids.is_empty()`
+    /// * The slices are not all the same length
+    /// * Any quote is crossed, has a negative size, or has no size on either side
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let bids = vec![100.0, 100.0];
+    /// let asks = vec![100.1, 100.2];
+    /// let bid_sizes = vec![300.0, 100.0];
+    /// let ask_sizes = vec![100.0, 100.0];
+    ///
+    /// let microprice = centaur_technical_indicators::microstructure::bulk::microprice(
+    ///     &bids, &asks, &bid_sizes, &ask_sizes
+    /// ).unwrap();
+    /// assert_eq!(vec![100.075, 100.1], microprice);
+    /// ```
+    #[inline]
+    pub fn microprice(
+        bids: &[f64],
+        asks: &[f64],
+        bid_sizes: &[f64],
+        ask_sizes: &[f64],
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[
+            ("bids", bids),
+            ("asks", asks),
+            ("bid_sizes", bid_sizes),
+            ("ask_sizes", ask_sizes),
+        ])?;
+        assert_non_empty("bids", bids)?;
+        (0..bids.len())
+            .map(|i| single::microprice(bids[i], asks[i], bid_sizes[i], ask_sizes[i]))
+            .collect()
+    }
+
+    /// Classifies each trade with the tick rule
+    ///
+    /// The first trade is `TradeSide::Unknown`, as is every trade before the
+    /// first price change.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`TradeSide`], one per trade
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.is_empty()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let prices = vec![100.0, 100.1, 100.1, 100.0];
+    ///
+    /// let sides = centaur_technical_indicators::microstructure::bulk::tick_rule(&prices).unwrap();
+    /// assert_eq!(
+    ///     vec![TradeSide::Unknown, TradeSide::Buy, TradeSide::Buy, TradeSide::Sell],
+    ///     sides
+    /// );
+    /// ```
+    #[inline]
+    pub fn tick_rule(prices: &[f64]) -> crate::Result<Vec<TradeSide>> {
+        assert_non_empty("prices", prices)?;
+        let mut side = TradeSide::Unknown;
+        let mut sides = Vec::with_capacity(prices.len());
+        sides.push(side);
+        for window in prices.windows(2) {
+            side = single::tick_rule(window[1], window[0], side);
+            sides.push(side);
+        }
+        Ok(sides)
+    }
+
+    /// Classifies each trade with the Lee-Ready algorithm
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    /// * `bids` - Slice of prevailing best bids
+    /// * `asks` - Slice of prevailing best asks
+    ///
+    /// # Returns
+    ///
+    /// A vector of [`TradeSide`], one per trade
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * The slices are not all the same length
+    /// * Any quote is crossed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let prices = vec![100.08, 100.05, 100.02];
+    /// let bids = vec![100.0, 100.0, 100.0];
+    /// let asks = vec![100.1, 100.1, 100.1];
+    ///
+    /// let sides = centaur_technical_indicators::microstructure::bulk::lee_ready(
+    ///     &prices, &bids, &asks
+    /// ).unwrap();
+    /// assert_eq!(vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Sell], sides);
+    /// ```
+    #[inline]
+    pub fn lee_ready(prices: &[f64], bids: &[f64], asks: &[f64]) -> crate::Result<Vec<TradeSide>> {
+        assert_same_len(&[("prices", prices), ("bids", bids), ("asks", asks)])?;
+        let tick_sides = tick_rule(prices)?;
+        (0..prices.len())
+            .map(|i| {
+                let (previous_price, previous_side) = if i == 0 {
+                    (prices[0], TradeSide::Unknown)
+                } else {
+                    (prices[i - 1], tick_sides[i - 1])
+                };
+                single::lee_ready(prices[i], bids[i], asks[i], previous_price, previous_side)
+            })
+            .collect()
+    }
+
+    /// Calculates the cumulative volume delta (buy volume minus sell volume)
+    ///
+    /// # Arguments
+    ///
+    /// * `volumes` - Slice of trade sizes
+    /// * `sides` - Slice of trade classifications
+    ///
+    /// # Returns
+    ///
+    /// A vector of running volume deltas, one per trade
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `volumes.is_empty()`
+    /// * `volumes.len()` != `sides.len()`
+    /// * Any volume is < 0.0, NaN or infinite
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let volumes = vec![100.0, 50.0, 30.0, 80.0];
+    /// let sides = vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Unknown, TradeSide::Buy];
+    ///
+    /// let delta = centaur_technical_indicators::microstructure::bulk::cumulative_volume_delta(
+    ///     &volumes, &sides
+    /// ).unwrap();
+    /// assert_eq!(vec![100.0, 50.0, 50.0, 130.0], delta);
+    /// ```
+    #[inline]
+    pub fn cumulative_volume_delta(
+        volumes: &[f64],
+        sides: &[TradeSide],
+    ) -> crate::Result<Vec<f64>> {
+        assert_trades(volumes, sides)?;
+        let mut delta = 0.0;
+        Ok(volumes
+            .iter()
+            .zip(sides)
+            .map(|(volume, side)| {
+                delta += single::signed(*volume, *side);
+                delta
+            })
+            .collect())
+    }
+
+    /// Calculates the volume-synchronized probability of informed trading (VPIN)
+    ///
+    /// Trades are poured into buckets of equal volume, splitting a trade across
+    /// buckets when needed (`TradeSide::Unknown` volume is split evenly between
+    /// buys and sells). VPIN is the average order imbalance `|buy - sell| / bucket_volume`
+    /// over the last `window` completed buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `volumes` - Slice of trade sizes
+    /// * `sides` - Slice of trade classifications
+    /// * `bucket_volume` - Volume in each bucket
+    /// * `window` - Number of buckets to average over
+    ///
+    /// # Returns
+    ///
+    /// A vector of VPIN values, one per completed bucket after the first `window - 1`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `volumes.is_empty()`
+    /// * `volumes.len()` != `sides.len()`
+    /// * Any volume is < 0.0, NaN or infinite
+    /// * `bucket_volume` <= 0.0, or so small that the buckets cannot be allocated
+    /// * `window` == 0 or fewer than `window` buckets are completed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let volumes = vec![100.0, 50.0, 150.0, 100.0];
+    /// let sides = vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Buy, TradeSide::Sell];
+    ///
+    /// let vpin = centaur_technical_indicators::microstructure::bulk::vpin(
+    ///     &volumes, &sides, 100.0, 2
+    /// ).unwrap();
+    /// assert_eq!(vec![0.5, 0.5, 1.0], vpin);
+    /// ```
+    pub fn vpin(
+        volumes: &[f64],
+        sides: &[TradeSide],
+        bucket_volume: f64,
+        window: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_trades(volumes, sides)?;
+        assert_positive("bucket_volume", bucket_volume)?;
+        assert_positive_usize("window", window)?;
+
+        let total_volume: f64 = volumes.iter().sum();
+        let mut imbalances: Vec<f64> = Vec::new();
+        imbalances
+            .try_reserve_exact((total_volume / bucket_volume) as usize)
+            .map_err(|_| TechnicalIndicatorError::InvalidValue {
+                name: "bucket_volume".to_string(),
+                value: bucket_volume,
+                reason: format!("is too small for a total volume of {}", total_volume),
+            })?;
+        let (mut buy, mut sell) = (0.0, 0.0);
+        for (&volume, &side) in volumes.iter().zip(sides) {
+            let space = bucket_volume - buy - sell;
+            if volume < space {
+                let (trade_buy, trade_sell) = split_volume(volume, side);
+                buy += trade_buy;
+                sell += trade_sell;
+                continue;
+            }
+            let (trade_buy, trade_sell) = split_volume(space, side);
+            imbalances.push((buy + trade_buy - sell - trade_sell).abs() / bucket_volume);
+
+            // Every full bucket left in the trade holds a single side
+            let rest = volume - space;
+            let full_buckets = (rest / bucket_volume).floor();
+            let full_imbalance = match side {
+                TradeSide::Unknown => 0.0,
+                _ => 1.0,
+            };
+            imbalances.resize(imbalances.len() + full_buckets as usize, full_imbalance);
+            (buy, sell) = split_volume((rest - full_buckets * bucket_volume).max(0.0), side);
+        }
+        assert_period(window, imbalances.len())?;
+        Ok(imbalances
+            .windows(window)
+            .map(|w| w.iter().sum::<f64>() / window as f64)
+            .collect())
+    }
+
+    /// Buy and sell portions of a trade, splitting `TradeSide::Unknown` evenly
+    #[inline]
+    fn split_volume(volume: f64, side: TradeSide) -> (f64, f64) {
+        match side {
+            TradeSide::Buy => (volume, 0.0),
+            TradeSide::Sell => (0.0, volume),
+            TradeSide::Unknown => (volume / 2.0, volume / 2.0),
+        }
+    }
+
+    /// Calculates Kyle's lambda over a rolling window of trades
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    /// * `volumes` - Slice of trade sizes
+    /// * `sides` - Slice of trade classifications
+    /// * `period` - Number of trades in each window
+    ///
+    /// # Returns
+    ///
+    /// A vector of price impact coefficients
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `period` < 3 or `period` > `prices.len()`
+    /// * Any volume is < 0.0, NaN or infinite
+    /// * The signed volume is constant over a window
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::TradeSide;
+    ///
+    /// let prices = vec![100.0, 100.02, 100.01, 100.04, 100.03];
+    /// let volumes = vec![100.0, 200.0, 100.0, 300.0, 100.0];
+    /// let sides = vec![TradeSide::Buy, TradeSide::Buy, TradeSide::Sell, TradeSide::Buy, TradeSide::Sell];
+    ///
+    /// let lambda = centaur_technical_indicators::microstructure::bulk::kyle_lambda(
+    ///     &prices, &volumes, &sides, 4
+    /// ).unwrap();
+    /// assert_eq!(2, lambda.len());
+    /// ```
+    #[inline]
+    pub fn kyle_lambda(
+        prices: &[f64],
+        volumes: &[f64],
+        sides: &[TradeSide],
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("prices", prices), ("volumes", volumes)])?;
+        assert_trades(volumes, sides)?;
+        assert_period(period, prices.len())?;
+        (0..=prices.len() - period)
+            .map(|i| {
+                single::kyle_lambda(
+                    &prices[i..i + period],
+                    &volumes[i..i + period],
+                    &sides[i..i + period],
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Amihud illiquidity ratio over a rolling window
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `volumes` - Slice of volumes
+    /// * `period` - Period over which to calculate the ratio
+    ///
+    /// # Returns
+    ///
+    /// A vector of illiquidity ratios
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.len()` != `volumes.len()`
+    /// * `period` < 2 or `period` > `prices.len()`
+    /// * Any price or volume is <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 101.0, 100.0, 100.0];
+    /// let volumes = vec![1000.0, 2000.0, 1000.0, 1000.0];
+    ///
+    /// let illiquidity = centaur_technical_indicators::microstructure::bulk::amihud_illiquidity(
+    ///     &prices, &volumes, 3
+    /// ).unwrap();
+    /// assert_eq!(vec![7.425742574257432e-8, 4.950495049504955e-8], illiquidity);
+    /// ```
+    #[inline]
+    pub fn amihud_illiquidity(
+        prices: &[f64],
+        volumes: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("prices", prices), ("volumes", volumes)])?;
+        assert_period(period, prices.len())?;
+        prices
+            .windows(period)
+            .zip(volumes.windows(period))
+            .map(|(p, v)| single::amihud_illiquidity(p, v))
+            .collect()
+    }
+
+    /// Calculates the Roll effective spread over a rolling window
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of trade prices
+    /// * `period` - Number of trades in each window
+    ///
+    /// # Returns
+    ///
+    /// A vector of estimated spreads
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `period` < 3 or `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 100.1, 100.0, 100.1, 100.0, 100.1];
+    ///
+    /// let spread = centaur_technical_indicators::microstructure::bulk::roll_spread(&prices, 5).unwrap();
+    /// assert_eq!(2, spread.len());
+    /// ```
+    #[inline]
+    pub fn roll_spread(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_period(period, prices.len())?;
+        prices.windows(period).map(single::roll_spread).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TradeSide;
+
+    #[test]
+    fn single_order_book_imbalance() {
+        assert_eq!(0.5, single::order_book_imbalance(300.0, 100.0).unwrap());
+        assert_eq!(-1.0, single::order_book_imbalance(0.0, 100.0).unwrap());
+    }
+
+    #[test]
+    fn single_order_book_imbalance_error() {
+        assert!(single::order_book_imbalance(0.0, 0.0).is_err());
+        assert!(single::order_book_imbalance(-1.0, 10.0).is_err());
+    }
+
+    #[test]
+    fn single_microprice() {
+        assert_eq!(
+            100.05,
+            single::microprice(100.0, 100.1, 100.0, 100.0).unwrap()
+        );
+        assert_eq!(100.1, single::microprice(100.0, 100.1, 100.0, 0.0).unwrap());
+    }
+
+    #[test]
+    fn single_microprice_crossed_error() {
+        assert!(single::microprice(100.1, 100.0, 100.0, 100.0).is_err());
+    }
+
+    #[test]
+    fn single_tick_rule() {
+        assert_eq!(
+            TradeSide::Sell,
+            single::tick_rule(99.9, 100.0, TradeSide::Buy)
+        );
+        assert_eq!(
+            TradeSide::Unknown,
+            single::tick_rule(100.0, 100.0, TradeSide::Unknown)
+        );
+    }
+
+    #[test]
+    fn single_lee_ready_midpoint_uses_tick_rule() {
+        assert_eq!(
+            TradeSide::Sell,
+            single::lee_ready(100.05, 100.0, 100.1, 100.06, TradeSide::Buy).unwrap()
+        );
+        assert_eq!(
+            TradeSide::Buy,
+            single::lee_ready(100.05, 100.0, 100.1, 100.05, TradeSide::Buy).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_lee_ready_error() {
+        assert!(single::lee_ready(100.05, 100.2, 100.1, 100.06, TradeSide::Buy).is_err());
+    }
+
+    #[test]
+    fn single_kyle_lambda() {
+        let prices = vec![100.0, 100.02, 100.01, 100.04, 100.03];
+        let volumes = vec![100.0, 200.0, 100.0, 300.0, 100.0];
+        let sides = vec![
+            TradeSide::Buy,
+            TradeSide::Buy,
+            TradeSide::Sell,
+            TradeSide::Buy,
+            TradeSide::Sell,
+        ];
+        let lambda = single::kyle_lambda(&prices, &volumes, &sides).unwrap();
+        assert!((lambda - 0.0001).abs() < 1e-12);
+    }
+
+    #[test]
+    fn single_kyle_lambda_length_error() {
+        let prices = vec![100.0, 100.02, 100.01];
+        let volumes = vec![100.0, 200.0, 100.0];
+        let sides = vec![TradeSide::Buy, TradeSide::Buy];
+        assert!(single::kyle_lambda(&prices, &volumes, &sides).is_err());
+    }
+
+    #[test]
+    fn single_amihud_illiquidity() {
+        let prices = vec![100.0, 101.0, 100.0];
+        let volumes = vec![1000.0, 2000.0, 1000.0];
+        assert_eq!(
+            7.425742574257432e-8,
+            single::amihud_illiquidity(&prices, &volumes).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_amihud_illiquidity_error() {
+        let prices = vec![100.0, 101.0, 100.0];
+        let volumes = vec![1000.0, 0.0, 1000.0];
+        assert!(single::amihud_illiquidity(&prices, &volumes).is_err());
+        assert!(single::amihud_illiquidity(&prices[..1], &volumes[..1]).is_err());
+    }
+
+    #[test]
+    fn single_amihud_illiquidity_zero_last_price_error() {
+        let prices = vec![100.0, 101.0, 0.0];
+        let volumes = vec![1000.0, 2000.0, 1000.0];
+        assert!(single::amihud_illiquidity(&prices, &volumes).is_err());
+    }
+
+    #[test]
+    fn single_roll_spread_trending() {
+        let prices = vec![100.0, 101.0, 102.0, 103.0, 104.0];
+        assert_eq!(0.0, single::roll_spread(&prices).unwrap());
+    }
+
+    #[test]
+    fn single_roll_spread_error() {
+        assert!(single::roll_spread(&[100.0, 100.1]).is_err());
+    }
+
+    #[test]
+    fn bulk_order_book_imbalance_error() {
+        assert!(bulk::order_book_imbalance(&[100.0], &[100.0, 200.0]).is_err());
+        assert!(bulk::order_book_imbalance(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn bulk_microprice_error() {
+        assert!(bulk::microprice(&[100.0], &[100.1], &[100.0], &[]).is_err());
+    }
+
+    #[test]
+    fn bulk_tick_rule() {
+        let prices = vec![100.0, 100.0, 99.9, 99.9, 100.0];
+        assert_eq!(
+            vec![
+                TradeSide::Unknown,
+                TradeSide::Unknown,
+                TradeSide::Sell,
+                TradeSide::Sell,
+                TradeSide::Buy
+            ],
+            bulk::tick_rule(&prices).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_tick_rule_error() {
+        assert!(bulk::tick_rule(&[]).is_err());
+    }
+
+    #[test]
+    fn bulk_lee_ready_error() {
+        assert!(bulk::lee_ready(&[100.0, 100.1], &[99.9], &[100.2]).is_err());
+    }
+
+    #[test]
+    fn bulk_cumulative_volume_delta_error() {
+        assert!(bulk::cumulative_volume_delta(&[100.0, 50.0], &[TradeSide::Buy]).is_err());
+    }
+
+    #[test]
+    fn bulk_vpin_splits_trades() {
+        let volumes = vec![150.0, 50.0];
+        let sides = vec![TradeSide::Buy, TradeSide::Sell];
+        assert_eq!(
+            vec![1.0, 0.0],
+            bulk::vpin(&volumes, &sides, 100.0, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_vpin_unknown_is_balanced() {
+        let volumes = vec![100.0];
+        let sides = vec![TradeSide::Unknown];
+        assert_eq!(vec![0.0], bulk::vpin(&volumes, &sides, 100.0, 1).unwrap());
+    }
+
+    #[test]
+    fn bulk_vpin_error() {
+        let volumes = vec![100.0, 50.0];
+        let sides = vec![TradeSide::Buy, TradeSide::Sell];
+        assert!(bulk::vpin(&volumes, &sides, 100.0, 2).is_err());
+        assert!(bulk::vpin(&volumes, &sides, 0.0, 1).is_err());
+        assert!(bulk::vpin(&volumes, &sides, 100.0, 0).is_err());
+    }
+
+    #[test]
+    fn bulk_vpin_invalid_volume_error() {
+        let sides = vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Buy];
+        assert!(bulk::vpin(&[100.0, -50.0, 100.0], &sides, 100.0, 1).is_err());
+        assert!(bulk::vpin(&[100.0, f64::NAN, 100.0], &sides, 100.0, 1).is_err());
+        assert!(bulk::vpin(&[100.0, f64::INFINITY, 100.0], &sides, 100.0, 1).is_err());
+    }
+
+    #[test]
+    fn bulk_vpin_large_trade() {
+        let volumes = vec![350.0, 50.0, 100.0];
+        let sides = vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Unknown];
+        assert_eq!(
+            vec![1.0, 1.0, 1.0, 0.0, 0.0],
+            bulk::vpin(&volumes, &sides, 100.0, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_vpin_too_many_buckets_error() {
+        let sides = vec![TradeSide::Buy, TradeSide::Sell];
+        assert!(bulk::vpin(&[1e17, 1.0], &sides, 1.0, 1).is_err());
+    }
+
+    #[test]
+    fn bulk_kyle_lambda() {
+        let prices = vec![100.0, 100.02, 100.01, 100.04, 100.03];
+        let volumes = vec![100.0, 200.0, 100.0, 300.0, 100.0];
+        let sides = vec![
+            TradeSide::Buy,
+            TradeSide::Buy,
+            TradeSide::Sell,
+            TradeSide::Buy,
+            TradeSide::Sell,
+        ];
+        let lambda = bulk::kyle_lambda(&prices, &volumes, &sides, 4).unwrap();
+        assert_eq!(
+            single::kyle_lambda(&prices[1..], &volumes[1..], &sides[1..]).unwrap(),
+            lambda[1]
+        );
+    }
+
+    #[test]
+    fn bulk_kyle_lambda_error() {
+        let prices = vec![100.0, 100.02, 100.01];
+        let volumes = vec![100.0, 200.0, 100.0];
+        let sides = vec![TradeSide::Buy, TradeSide::Buy, TradeSide::Sell];
+        assert!(bulk::kyle_lambda(&prices, &volumes, &sides, 4).is_err());
+    }
+
+    #[test]
+    fn bulk_amihud_illiquidity_error() {
+        let prices = vec![100.0, 101.0, 100.0];
+        let volumes = vec![1000.0, 2000.0];
+        assert!(bulk::amihud_illiquidity(&prices, &volumes, 2).is_err());
+    }
+
+    #[test]
+    fn bulk_roll_spread() {
+        let prices = vec![100.0, 100.1, 100.0, 100.1, 100.0, 100.1];
+        assert_eq!(
+            vec![
+                single::roll_spread(&prices[..5]).unwrap(),
+                single::roll_spread(&prices[1..]).unwrap()
+            ],
+            bulk::roll_spread(&prices, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_roll_spread_error() {
+        assert!(bulk::roll_spread(&[100.0, 100.1, 100.0], 4).is_err());
+    }
+}
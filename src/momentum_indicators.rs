@@ -33,6 +33,16 @@
 //! - [`williams_percent_r`](bulk::williams_percent_r): Williams %R
 //! - [`percentage_price_oscillator`](bulk::percentage_price_oscillator): Percentage Price Oscillator (PPO)
 //! - [`chande_momentum_oscillator`](bulk::chande_momentum_oscillator): Chande Momentum Oscillator (CMO)
+//! - [`stochastic_rsi`](bulk::stochastic_rsi): Stochastic RSI
+//! - [`ultimate_oscillator`](bulk::ultimate_oscillator): Ultimate Oscillator
+//! - [`awesome_oscillator`](bulk::awesome_oscillator): Awesome Oscillator
+//! - [`accelerator_oscillator`](bulk::accelerator_oscillator): Accelerator Oscillator
+//! - [`connors_rsi`](bulk::connors_rsi): Connors RSI
+//! - [`trix`](bulk::trix): TRIX
+//! - [`know_sure_thing`](bulk::know_sure_thing): Know Sure Thing (KST)
+//! - [`coppock_curve`](bulk::coppock_curve): Coppock Curve
+//! - [`detrended_price_oscillator`](bulk::detrended_price_oscillator): Detrended Price Oscillator (DPO)
+//! - [`schaff_trend_cycle`](bulk::schaff_trend_cycle): Schaff Trend Cycle (STC)
 //!
 //! ### Single
 //! - [`chaikin_oscillator`](single::chaikin_oscillator): Chaikin Oscillator
@@ -51,6 +61,16 @@
 //! - [`williams_percent_r`](single::williams_percent_r): Williams %R
 //! - [`percentage_price_oscillator`](single::percentage_price_oscillator): Percentage Price Oscillator (PPO)
 //! - [`chande_momentum_oscillator`](single::chande_momentum_oscillator): Chande Momentum Oscillator (CMO)
+//! - [`stochastic_rsi`](single::stochastic_rsi): Stochastic RSI
+//! - [`ultimate_oscillator`](single::ultimate_oscillator): Ultimate Oscillator
+//! - [`awesome_oscillator`](single::awesome_oscillator): Awesome Oscillator
+//! - [`accelerator_oscillator`](single::accelerator_oscillator): Accelerator Oscillator
+//! - [`connors_rsi`](single::connors_rsi): Connors RSI
+//! - [`trix`](single::trix): TRIX
+//! - [`know_sure_thing`](single::know_sure_thing): Know Sure Thing (KST)
+//! - [`coppock_curve`](single::coppock_curve): Coppock Curve
+//! - [`detrended_price_oscillator`](single::detrended_price_oscillator): Detrended Price Oscillator (DPO)
+//! - [`schaff_trend_cycle`](single::schaff_trend_cycle): Schaff Trend Cycle (STC)
 //!
 //! ## API Details
 //! - All indicators accept slices of `f64` prices and relevant parameters (periods, multipliers, etc.).
@@ -61,18 +81,11 @@
 
 /// **single**: Functions that return a single value for a slice of prices.
 pub mod single {
-    use crate::basic_indicators::single::{
-        absolute_deviation, cauchy_iqr_scale, laplace_std_equivalent, log_standard_deviation, max,
-        median, min, mode, standard_deviation, student_t_adjusted_std,
-    };
-    use crate::moving_average::single::{mcginley_dynamic, moving_average};
+    use crate::basic_indicators::single::{max, min};
+    use crate::moving_average::single::mcginley_dynamic;
     use crate::strength_indicators::single::accumulation_distribution;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
-    use crate::volatility_indicators::single::ulcer_index;
-    use crate::{
-        AbsDevConfig, CentralPoint, ConstantModelType, DeviationAggregate, DeviationModel,
-        MovingAverageType,
-    };
+    use crate::validation::{assert_min_length, assert_non_empty, assert_period, assert_same_len};
+    use crate::{CentralTendency, Deviation};
     use std::cmp::Ordering;
 
     /// Calculates the Relative Strength Index (RSI)
@@ -80,7 +93,7 @@ pub mod single {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
@@ -119,7 +132,7 @@ pub mod single {
     #[inline]
     pub fn relative_strength_index(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         let (previous_gains, previous_loss) = previous_gains_loss(prices)?;
         if previous_gains.is_empty() {
@@ -129,44 +142,10 @@ pub mod single {
             return Ok(100.0);
         }
 
-        let (previous_average_gains, previous_average_loss) = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => (
-                moving_average(&previous_gains, MovingAverageType::Simple)?,
-                moving_average(&previous_loss, MovingAverageType::Simple)?,
-            ),
-            ConstantModelType::SmoothedMovingAverage => (
-                moving_average(&previous_gains, MovingAverageType::Smoothed)?,
-                moving_average(&previous_loss, MovingAverageType::Smoothed)?,
-            ),
-            ConstantModelType::ExponentialMovingAverage => (
-                moving_average(&previous_gains, MovingAverageType::Exponential)?,
-                moving_average(&previous_loss, MovingAverageType::Exponential)?,
-            ),
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => (
-                moving_average(
-                    &previous_gains,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-                moving_average(
-                    &previous_loss,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-            ),
-            ConstantModelType::SimpleMovingMedian => {
-                (median(&previous_gains)?, median(&previous_loss)?)
-            }
-            ConstantModelType::SimpleMovingMode => (mode(&previous_gains)?, mode(&previous_loss)?),
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let (previous_average_gains, previous_average_loss) = (
+            constant_model_type.central_value(&previous_gains)?,
+            constant_model_type.central_value(&previous_loss)?,
+        );
 
         if previous_average_loss == 0.0 {
             Ok(0.0)
@@ -215,7 +194,7 @@ pub mod single {
     /// # Arguments
     ///
     /// * `stochastics` - Slice of stochastics
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
@@ -254,34 +233,11 @@ pub mod single {
     #[inline]
     pub fn slow_stochastic(
         stochastics: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("stochastics", stochastics)?;
 
-        match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(stochastics, MovingAverageType::Simple)
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(stochastics, MovingAverageType::Smoothed)
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(stochastics, MovingAverageType::Exponential)
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                stochastics,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            ),
-            ConstantModelType::SimpleMovingMedian => median(stochastics),
-            ConstantModelType::SimpleMovingMode => mode(stochastics),
-            _ => Err(unsupported_type("ConstantModelType")),
-        }
+        constant_model_type.central_value(stochastics)
     }
 
     /// Calculates the slowest stochastic
@@ -289,7 +245,7 @@ pub mod single {
     /// # Arguments
     ///
     /// * `slow_stochastics` - Slice of slow stochastics
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -324,34 +280,11 @@ pub mod single {
     #[inline]
     pub fn slowest_stochastic(
         slow_stochastics: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("slow_stochastics", slow_stochastics)?;
 
-        match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(slow_stochastics, MovingAverageType::Simple)
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(slow_stochastics, MovingAverageType::Smoothed)
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(slow_stochastics, MovingAverageType::Exponential)
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                slow_stochastics,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            ),
-            ConstantModelType::SimpleMovingMedian => median(slow_stochastics),
-            ConstantModelType::SimpleMovingMode => mode(slow_stochastics),
-            _ => Err(unsupported_type("ConstantModelType")),
-        }
+        constant_model_type.central_value(slow_stochastics)
     }
 
     /// Calculates the Williams %R
@@ -550,8 +483,8 @@ pub mod single {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `constant_multiplier` - Scale factor (Usually 0.015)
     ///
     /// # Errors
@@ -594,78 +527,15 @@ pub mod single {
     #[inline]
     pub fn commodity_channel_index(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
         constant_multiplier: f64,
     ) -> crate::Result<f64> {
         assert_non_empty("prices", prices)?;
 
-        let moving_constant = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices)?,
-            ConstantModelType::SimpleMovingMode => mode(prices)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let moving_constant = constant_model_type.central_value(prices)?;
 
-        let deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices, config)?
-            }
-            DeviationModel::UlcerIndex => ulcer_index(prices)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices, precision, low, high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
+        let deviation = deviation_model.deviation(prices)?;
         if deviation == 0.0 {
             Ok(0.0)
         } else {
@@ -679,7 +549,7 @@ pub mod single {
     ///
     /// * `prices` - Slice of prices
     /// * `previous_mcginley_dynamic` - Previous McGinley dynamic. 0.0 if none
-    /// * `deviation_model` - Variant of [`DeviationModel`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
     /// * `constant_multiplier` - Scale factor. Normally 0.015
     ///
     /// # Errors
@@ -715,7 +585,7 @@ pub mod single {
     pub fn mcginley_dynamic_commodity_channel_index(
         prices: &[f64],
         previous_mcginley_dynamic: f64,
-        deviation_model: DeviationModel,
+        deviation_model: impl Deviation,
         constant_multiplier: f64,
     ) -> crate::Result<(f64, f64)> {
         assert_non_empty("prices", prices)?;
@@ -725,47 +595,7 @@ pub mod single {
         let mcginley_dynamic =
             mcginley_dynamic(last_price, previous_mcginley_dynamic, prices.len())?;
 
-        let deviation = match deviation_model {
-            DeviationModel::StandardDeviation => standard_deviation(prices)?,
-            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mean,
-                    aggregate: DeviationAggregate::Mean,
-                },
-            )?,
-            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Median,
-                    aggregate: DeviationAggregate::Median,
-                },
-            )?,
-            DeviationModel::ModeAbsoluteDeviation => absolute_deviation(
-                prices,
-                AbsDevConfig {
-                    center: CentralPoint::Mode,
-                    aggregate: DeviationAggregate::Mode,
-                },
-            )?,
-            DeviationModel::CustomAbsoluteDeviation { config } => {
-                absolute_deviation(prices, config)?
-            }
-            DeviationModel::UlcerIndex => ulcer_index(prices)?,
-            DeviationModel::LogStandardDeviation => log_standard_deviation(prices)?,
-            DeviationModel::StudentT { df } => student_t_adjusted_std(prices, df)?,
-            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices)?,
-            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices)?,
-            DeviationModel::EmpiricalQuantileRange {
-                low,
-                high,
-                precision,
-            } => crate::basic_indicators::single::empirical_quantile_range_from_distribution(
-                prices, precision, low, high,
-            )?,
-            #[allow(unreachable_patterns)]
-            _ => return Err(unsupported_type("DeviationModel")),
-        };
+        let deviation = deviation_model.deviation(prices)?;
         if deviation == 0.0 {
             Ok((0.0, mcginley_dynamic))
         } else {
@@ -782,8 +612,8 @@ pub mod single {
     ///
     /// * `prices` - Slice of prices
     /// * `short_period` - Length of the short period
-    /// * `short_period_model` - Variant of [`ConstantModelType`]
-    /// * `long_period_model` - Variant of [`ConstantModelType`]
+    /// * `short_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `long_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -818,63 +648,17 @@ pub mod single {
     pub fn macd_line(
         prices: &[f64],
         short_period: usize,
-        short_period_model: ConstantModelType,
-        long_period_model: ConstantModelType,
+        short_period_model: impl CentralTendency,
+        long_period_model: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("prices", prices)?;
         let length = prices.len();
         assert_period(short_period, length)?;
 
         let short_period_slice = &prices[length - short_period..];
-        let short_period_average = match short_period_model {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                short_period_slice,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(short_period_slice)?,
-            ConstantModelType::SimpleMovingMode => mode(short_period_slice)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let short_period_average = short_period_model.central_value(short_period_slice)?;
 
-        let long_period_average = match long_period_model {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(prices, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(prices, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(prices, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                prices,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(prices)?,
-            ConstantModelType::SimpleMovingMode => mode(prices)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let long_period_average = long_period_model.central_value(prices)?;
         Ok(short_period_average - long_period_average)
     }
 
@@ -883,7 +667,7 @@ pub mod single {
     /// # Arguments
     ///
     /// * `macds` - Slice of MACDs
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
@@ -915,33 +699,10 @@ pub mod single {
     #[inline]
     pub fn signal_line(
         macds: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("macds", macds)?;
-        match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(macds, MovingAverageType::Simple)
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(macds, MovingAverageType::Smoothed)
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(macds, MovingAverageType::Exponential)
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                macds,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            ),
-            ConstantModelType::SimpleMovingMedian => median(macds),
-            ConstantModelType::SimpleMovingMode => mode(macds),
-            _ => Err(unsupported_type("ConstantModelType")),
-        }
+        constant_model_type.central_value(macds)
     }
 
     /// Calculates the McGinley dynamic MACD line
@@ -1015,8 +776,8 @@ pub mod single {
     /// * `volume` - Slice of transction volumes
     /// * `short_period` - Short period for the Accumulation Distribution
     /// * `previous_accumulation_distribution` - Previous accumulation distribution
-    /// * `short_period_model` - Variant of [`ConstantModelType`]
-    /// * `long_period_model` - Variant of [`ConstantModelType`]  
+    /// * `short_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `long_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]  
     ///
     /// # Returns
     ///
@@ -1065,6 +826,7 @@ pub mod single {
     ///     ).unwrap();
     /// assert_eq!((-333.3333333333333, -260.0), chaikin_oscillator);
     /// ```
+    #[allow(clippy::too_many_arguments)]
     pub fn chaikin_oscillator(
         highs: &[f64],
         lows: &[f64],
@@ -1072,8 +834,8 @@ pub mod single {
         volume: &[f64],
         short_period: usize,
         previous_accumulation_distribution: f64,
-        short_period_model: ConstantModelType,
-        long_period_model: ConstantModelType,
+        short_period_model: impl CentralTendency,
+        long_period_model: impl CentralTendency,
     ) -> crate::Result<(f64, f64)> {
         let long_period = highs.len();
         assert_same_len(&[
@@ -1103,55 +865,9 @@ pub mod single {
         }
         let short_period_slice = &ad[long_period - short_period..];
 
-        let short_period_average = match short_period_model {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(short_period_slice, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                short_period_slice,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(short_period_slice)?,
-            ConstantModelType::SimpleMovingMode => mode(short_period_slice)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let short_period_average = short_period_model.central_value(short_period_slice)?;
 
-        let long_period_average = match long_period_model {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(&ad, MovingAverageType::Simple)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(&ad, MovingAverageType::Smoothed)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(&ad, MovingAverageType::Exponential)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                &ad,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(&ad)?,
-            ConstantModelType::SimpleMovingMode => mode(&ad)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let long_period_average = long_period_model.central_value(&ad)?;
 
         Ok((short_period_average - long_period_average, ad[ad.len() - 1]))
     }
@@ -1162,7 +878,7 @@ pub mod single {
     ///
     /// * `prices` - Slice of prices
     /// * `short_period` - Length of short period
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -1189,49 +905,17 @@ pub mod single {
     pub fn percentage_price_oscillator(
         prices: &[f64],
         short_period: usize,
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("prices", prices)?;
         let long_period = prices.len();
         assert_period(short_period, long_period)?;
 
         let short_period_slice = &prices[long_period - short_period..];
-        let (short_period, long_period) = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => (
-                moving_average(short_period_slice, MovingAverageType::Simple)?,
-                moving_average(prices, MovingAverageType::Simple)?,
-            ),
-            ConstantModelType::SmoothedMovingAverage => (
-                moving_average(short_period_slice, MovingAverageType::Smoothed)?,
-                moving_average(prices, MovingAverageType::Smoothed)?,
-            ),
-            ConstantModelType::ExponentialMovingAverage => (
-                moving_average(short_period_slice, MovingAverageType::Exponential)?,
-                moving_average(prices, MovingAverageType::Exponential)?,
-            ),
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => (
-                moving_average(
-                    short_period_slice,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-                moving_average(
-                    prices,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-            ),
-            ConstantModelType::SimpleMovingMedian => (median(short_period_slice)?, median(prices)?),
-            ConstantModelType::SimpleMovingMode => (mode(short_period_slice)?, mode(prices)?),
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let (short_period, long_period) = (
+            constant_model_type.central_value(short_period_slice)?,
+            constant_model_type.central_value(prices)?,
+        );
 
         Ok(((short_period - long_period) / long_period) * 100.0)
     }
@@ -1273,571 +957,1289 @@ pub mod single {
         Ok(((gains_sum - loss_sum) / (gains_sum + loss_sum)) * 100.0)
     }
 
-    #[inline]
-    fn previous_gains_loss(prices: &[f64]) -> crate::Result<(Vec<f64>, Vec<f64>)> {
-        assert_non_empty("prices", prices)?;
-        let len = prices.len();
-        let mut previous_gains = Vec::with_capacity(len - 1);
-        let mut previous_loss = Vec::with_capacity(len - 1);
-        for i in 1..len {
-            let diff = prices[i] - prices[i - 1];
-            if diff > 0.0 {
-                previous_gains.push(diff);
-            } else if diff < 0.0 {
-                previous_loss.push(prices[i - 1] - prices[i]);
-            };
-        }
-        Ok((previous_gains, previous_loss))
-    }
-
-    #[inline]
-    fn cmp_f64(a: &f64, b: &f64) -> Ordering {
-        if a < b {
-            Ordering::Less
-        } else if a > b {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
-        }
-    }
-}
-
-/// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
-pub mod bulk {
-    use crate::momentum_indicators::single;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
-    use crate::{ConstantModelType, DeviationModel};
-
-    /// Calculates the Relative strength index (RSI)
+    /// Calculates the Stochastic RSI
+    ///
+    /// Calculates the RSI over every `rsi_period` window of `prices` and returns
+    /// the stochastic oscillator of those RSIs.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `period` - Period over which to calculate the RSI
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for the RSI
+    /// * `rsi_period` - Period over which to calculate each RSI
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if `period` > `prices.len()`
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `rsi_period` == 0 or `rsi_period` > `prices.len()`
     ///
     /// # Examples
     ///
-    /// ```
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
-    /// let period: usize = 3;
-    /// let defaut_rsi =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
-    ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], defaut_rsi);
-    ///
-    /// let ema_rsi =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
-    ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], ema_rsi);
-    ///
-    /// let moving_median_rsi =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
-    ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], moving_median_rsi);
-    ///
-    /// let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28];
-    /// let period: usize = 5;
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
     ///
-    /// let personalised_rsi =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
+    /// let stochastic_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::single::stochastic_rsi(
     ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::PersonalisedMovingAverage{alpha_num: 5.0, alpha_den:
-    ///         4.0},
-    ///         period
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         3
     ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![34.51776649746324, 12.837837837836929, 34.51776649746182, 61.26126126126377],
-    ///     personalised_rsi
-    /// );
+    /// assert_eq!(33.33333333333333, stochastic_rsi);
     /// ```
     #[inline]
-    pub fn relative_strength_index(
+    pub fn stochastic_rsi(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
-        period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_period(period, length)?;
-
-        let mut rsis = Vec::with_capacity(length - period + 1);
-        for window in prices.windows(period) {
-            rsis.push(single::relative_strength_index(
-                window,
-                constant_model_type,
-            )?);
-        }
-        Ok(rsis)
+        constant_model_type: impl CentralTendency,
+        rsi_period: usize,
+    ) -> crate::Result<f64> {
+        assert_non_empty("prices", prices)?;
+        assert_period(rsi_period, prices.len())?;
+        let rsis = prices
+            .windows(rsi_period)
+            .map(|window| relative_strength_index(window, &constant_model_type))
+            .collect::<crate::Result<Vec<f64>>>()?;
+        stochastic_oscillator(&rsis)
     }
 
-    /// Calculates the Stochastic Oscillator
+    /// Calculates the Ultimate Oscillator
+    ///
+    /// Weights the average buying pressure over three periods 4:2:1. The first
+    /// value of each slice only provides the previous close, so the long period
+    /// is `close.len() - 1`.
     ///
     /// # Arguments
     ///
-    /// * `prices` - Slice of prices
-    /// * `period` - Period over which to calculate the stochastic oscillator
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `short_period` - Short period (typically 7)
+    /// * `medium_period` - Medium period (typically 14)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if the `period` > `prices.len()`
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `close.len()` < 2
+    /// * `short_period` == 0, `short_period` > `medium_period` or `medium_period` > `close.len() - 1`
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
-    /// let period: usize = 3;
-    /// let stochastic_oscillator =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::stochastic_oscillator(&prices, period).unwrap();
-    /// assert_eq!(vec![100.0, 0.0, 0.0], stochastic_oscillator);
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let ultimate_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::single::ultimate_oscillator(
+    ///         &highs, &lows, &close, 1, 2
+    ///     ).unwrap();
+    /// assert_eq!(35.064935064935064, ultimate_oscillator);
     /// ```
-    #[inline]
-    pub fn stochastic_oscillator(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_period(period, length)?;
-
-        let mut so = Vec::with_capacity(length - period + 1);
-        for window in prices.windows(period) {
-            so.push(single::stochastic_oscillator(window)?);
-        }
-        Ok(so)
+    pub fn ultimate_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        short_period: usize,
+        medium_period: usize,
+    ) -> crate::Result<f64> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_length("close", 2, close.len())?;
+        let long_period = close.len() - 1;
+        assert_period(short_period, medium_period)?;
+        assert_period(medium_period, long_period)?;
+
+        let (buying_pressure, true_range): (Vec<f64>, Vec<f64>) = (1..close.len())
+            .map(|i| {
+                let low = lows[i].min(close[i - 1]);
+                (close[i] - low, highs[i].max(close[i - 1]) - low)
+            })
+            .unzip();
+        let average = |period: usize| -> f64 {
+            let start = long_period - period;
+            buying_pressure[start..].iter().sum::<f64>() / true_range[start..].iter().sum::<f64>()
+        };
+        Ok(100.0
+            * (4.0 * average(short_period) + 2.0 * average(medium_period) + average(long_period))
+            / 7.0)
     }
 
-    /// Calculates the slow Stochastic
+    /// Calculates the Awesome Oscillator
+    ///
+    /// The difference between a short and a long average of the median price
+    /// `(high + low) / 2`. The long period is the length of the slices.
     ///
     /// # Arguments
     ///
-    /// * `stochastics` - Slice of Stochastic Oscillators
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `period` - Period over which to calculate the slow stochastic
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `short_period` - Short period (typically 5)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if `period` > `stochastics.len()`
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `short_period` == 0 or `short_period` > `highs.len()`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let stochstic_oscillators = [0.0, 25.0, 50.0, 33.0, 73.0];
-    /// let period: usize = 3;
-    ///
-    /// let simple_ma_slow_stochastic =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
-    ///         &stochstic_oscillators,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![25.0, 36.0, 52.0], simple_ma_slow_stochastic);
-    ///
-    /// let sma_slow_stochastic =
-    /// centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
-    ///     &stochstic_oscillators,
-    ///     centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
-    ///     period
-    /// ).unwrap();
-    /// assert_eq!(
-    ///     vec![31.578947368421055, 36.684210526315795, 55.526315789473685],
-    ///     sma_slow_stochastic
-    /// );
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
     ///
-    /// let median_slow_stochastic =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
-    ///         &stochstic_oscillators,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-    ///         period
+    /// let awesome_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::single::awesome_oscillator(
+    ///         &highs,
+    ///         &lows,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(vec![25.0, 33.0, 50.0], median_slow_stochastic);
+    /// assert_eq!(0.9000000000000057, awesome_oscillator);
     /// ```
     #[inline]
-    pub fn slow_stochastic(
-        stochastics: &[f64],
-        constant_model_type: ConstantModelType,
-        period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = stochastics.len();
-        assert_period(period, length)?;
-        let mut sso = Vec::with_capacity(length - period + 1);
-        for window in stochastics.windows(period) {
-            sso.push(single::slow_stochastic(window, constant_model_type)?);
-        }
-        Ok(sso)
+    pub fn awesome_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        short_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(short_period, highs.len())?;
+        let median_prices: Vec<f64> = highs
+            .iter()
+            .zip(lows)
+            .map(|(high, low)| (high + low) / 2.0)
+            .collect();
+        Ok(constant_model_type
+            .central_value(&median_prices[median_prices.len() - short_period..])?
+            - constant_model_type.central_value(&median_prices)?)
     }
 
-    /// Calculates the slowest Stochastic
+    /// Calculates the Accelerator Oscillator
+    ///
+    /// The Awesome Oscillator minus its average over the signal period. The signal
+    /// period is `highs.len() - long_period + 1`.
     ///
     /// # Arguments
     ///
-    /// * `slow_stochastics` - Slice of slow stochastics
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `period` - Period over which to calculate the slowest stochastic oscillator
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `short_period` - Short period of the Awesome Oscillator (typically 5)
+    /// * `long_period` - Long period of the Awesome Oscillator (typically 34)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if `period` > `slow_stochastics.len()`
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `short_period` == 0, `short_period` > `long_period` or `long_period` > `highs.len()`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let slow_stochstics = [75.0, 60.0, 73.0, 58.0];
-    /// let period: usize = 3;
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
     ///
-    /// let ma_slowest_stochastic =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::slowest_stochastic(
-    ///         &slow_stochstics,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![69.33333333333333, 63.666666666666664], ma_slowest_stochastic);
-    ///
-    /// let sma_slowest_stochastic =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
-    ///         &slow_stochstics,
-    ///         centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![69.31578947368422, 63.15789473684211], sma_slowest_stochastic);
-    ///
-    /// let median_slowest_stochastic =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
-    ///         &slow_stochstics,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-    ///         period
+    /// let accelerator_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::single::accelerator_oscillator(
+    ///         &highs,
+    ///         &lows,
+    ///         2,
+    ///         3,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(vec![73.0, 60.0], median_slowest_stochastic);
+    /// assert_eq!(-0.3333333333333333, accelerator_oscillator);
     /// ```
-    #[inline]
-    pub fn slowest_stochastic(
-        slow_stochastics: &[f64],
-        constant_model_type: ConstantModelType,
-        period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = slow_stochastics.len();
-        assert_period(period, length)?;
-        let mut sso = Vec::with_capacity(length - period + 1);
-        for window in slow_stochastics.windows(period) {
-            sso.push(single::slowest_stochastic(window, constant_model_type)?);
-        }
-        Ok(sso)
+    pub fn accelerator_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period, highs.len())?;
+        let awesome_oscillators = highs
+            .windows(long_period)
+            .zip(lows.windows(long_period))
+            .map(|(h, l)| awesome_oscillator(h, l, short_period, &constant_model_type))
+            .collect::<crate::Result<Vec<f64>>>()?;
+        Ok(awesome_oscillators.last().unwrap()
+            - constant_model_type.central_value(&awesome_oscillators)?)
     }
 
-    /// Calculates the Williams %R
+    /// Calculates the Connors RSI
+    ///
+    /// The average of the RSI of prices, the RSI of the up/down streak length and
+    /// the percent rank of the latest one period return. The percent rank compares
+    /// the latest return against the `prices.len() - 2` returns before it.
     ///
     /// # Arguments
     ///
-    /// * `highs` - Slice of highs
-    /// * `lows` - Slice of lows
-    /// * `close` - Slice of closing prices
-    /// * `period` - Period over which to calculate the Williams %R
+    /// * `prices` - Slice of prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for both RSIs
+    /// * `rsi_period` - Period of the price RSI (typically 3)
+    /// * `streak_period` - Period of the streak RSI (typically 2)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `highs.len()` != `lows.len()` != `close.len()`
-    /// * `period` > lengths
+    /// Returns an error if:
+    /// * `prices.len()` < 3
+    /// * `rsi_period` or `streak_period` == 0 or > `prices.len()`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let highs = vec![200.0, 210.0, 205.0, 190.0];
-    /// let lows = vec![175.0, 192.0, 200.0, 174.0];
-    /// let close = vec![192.0, 200.0, 201.0, 187.0];
-    /// let period: usize = 3;
-    /// let williams_percent_r =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::williams_percent_r(
-    ///         &highs,
-    ///         &lows,
-    ///         &close,
-    ///         period
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+    ///
+    /// let connors_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::single::connors_rsi(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         3,
+    ///         2
     ///     ).unwrap();
-    /// assert_eq!(vec![-25.71428571428571, -63.888888888888886], williams_percent_r);
+    /// assert_eq!(69.44444444444444, connors_rsi);
     /// ```
-    #[inline]
-    pub fn williams_percent_r(
-        highs: &[f64],
-        lows: &[f64],
-        close: &[f64],
-        period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = close.len();
-        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
-        assert_period(period, length)?;
-
-        let loop_max = length - period + 1;
-        let mut wprs = Vec::with_capacity(loop_max);
-        for i in 0..loop_max {
-            wprs.push(single::williams_percent_r(
-                &highs[i..i + period],
-                &lows[i..i + period],
-                close[i + period - 1],
-            )?);
-        }
-        Ok(wprs)
+    pub fn connors_rsi(
+        prices: &[f64],
+        constant_model_type: impl CentralTendency,
+        rsi_period: usize,
+        streak_period: usize,
+    ) -> crate::Result<f64> {
+        assert_min_length("prices", 3, prices.len())?;
+        assert_period(rsi_period, prices.len())?;
+        assert_period(streak_period, prices.len())?;
+
+        let streaks = up_down_streaks(prices);
+        let returns: Vec<f64> = prices.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+        let (latest, previous_returns) = returns.split_last().unwrap();
+        let percent_rank = previous_returns.iter().filter(|r| *r < latest).count() as f64
+            / previous_returns.len() as f64
+            * 100.0;
+
+        let price_rsi =
+            relative_strength_index(&prices[prices.len() - rsi_period..], &constant_model_type)?;
+        let streak_rsi = relative_strength_index(
+            &streaks[streaks.len() - streak_period..],
+            &constant_model_type,
+        )?;
+        Ok((price_rsi + streak_rsi + percent_rank) / 3.0)
     }
 
-    /// Calculates the Money Flow Index (MFI)
+    /// Calculates the TRIX, the rate of change of a triple smoothed price
+    ///
+    /// Uses the whole slice; only the last `3 * period - 1` prices affect the result.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `volume` - Slice of volumes
-    /// * `period` - Period over which to calculate the MFI
+    /// * `period` - Period of each smoothing (typically 15)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `period` > `prices.len()`
-    /// * `prices.len()` != `volume.len()`
+    /// Returns an error if:
+    /// * `period` == 0
+    /// * `prices.len()` < `3 * period - 1`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
-    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1300.0];
-    /// let period: usize = 3;
-    /// let money_flow_index =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::money_flow_index(
-    ///         &prices,
-    ///         &volume,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(vec![55.314533622559644, 0.0, 58.60655737704918], money_flow_index);
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+    ///
+    /// let trix = centaur_technical_indicators::momentum_indicators::single::trix(
+    ///     &prices,
+    ///     2,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(-1.2300123001230012, trix);
     /// ```
     #[inline]
-    pub fn money_flow_index(
+    pub fn trix(
         prices: &[f64],
-        volume: &[f64],
         period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_period(period, length)?;
-        assert_same_len(&[("prices", prices), ("volume", volume)])?;
-
-        let loop_max = length - period + 1;
-        let mut mfis = Vec::with_capacity(loop_max);
-
-        for i in 0..loop_max {
-            mfis.push(single::money_flow_index(
-                &prices[i..i + period],
-                &volume[i..i + period],
-            )?);
-        }
-        Ok(mfis)
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        let trix = super::bulk::trix(prices, period, constant_model_type)?;
+        Ok(*trix.last().unwrap())
     }
 
-    /// Calculates the Rate of Change (RoC)
+    /// Calculates Pring's Know Sure Thing (KST)
+    ///
+    /// The weighted (1, 2, 3, 4) sum of four smoothed rates of change. Uses the
+    /// whole slice; only the last `roc_periods[i] + smoothing_periods[i]` prices
+    /// (largest over `i`) affect the result.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
+    /// * `roc_periods` - Rate of change periods (typically [10, 15, 20, 30])
+    /// * `smoothing_periods` - Smoothing period of each rate of change (typically [10, 10, 10, 15])
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if `prices.is_empty()`
+    /// Returns an error if:
+    /// * Any of the periods == 0
+    /// * `prices.len()` is shorter than the longest `roc_periods[i] + smoothing_periods[i]`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 120.0, 100.0];
-    /// let rate_of_change = centaur_technical_indicators::momentum_indicators::bulk::rate_of_change(&prices).unwrap();
-    /// assert_eq!(vec![20.0, -16.666666666666664], rate_of_change);
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+    ///
+    /// let know_sure_thing =
+    ///     centaur_technical_indicators::momentum_indicators::single::know_sure_thing(
+    ///         &prices,
+    ///         [1, 2, 3, 4],
+    ///         [2, 2, 2, 2],
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(-20.060908032550827, know_sure_thing);
     /// ```
     #[inline]
-    pub fn rate_of_change(prices: &[f64]) -> crate::Result<Vec<f64>> {
-        assert_non_empty("prices", prices)?;
-        let mut rocs = Vec::with_capacity(prices.len() - 1);
-        for pair in prices.windows(2) {
-            rocs.push(single::rate_of_change(pair[1], pair[0])?);
-        }
-        Ok(rocs)
+    pub fn know_sure_thing(
+        prices: &[f64],
+        roc_periods: [usize; 4],
+        smoothing_periods: [usize; 4],
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        let know_sure_thing = super::bulk::know_sure_thing(
+            prices,
+            roc_periods,
+            smoothing_periods,
+            constant_model_type,
+        )?;
+        Ok(*know_sure_thing.last().unwrap())
     }
 
-    /// Calculates the On Balance Volume (OBV)
+    /// Calculates the Coppock Curve
+    ///
+    /// The smoothed sum of a long and a short rate of change. Uses the whole
+    /// slice; only the last `max(long_roc_period, short_roc_period) + smoothing_period`
+    /// prices affect the result.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `volume` - Slice of volumes
-    /// * `previous_on_balance_volume` - Previous OBV (use 0.0 if none)
+    /// * `long_roc_period` - Long rate of change period (typically 14)
+    /// * `short_roc_period` - Short rate of change period (typically 11)
+    /// * `smoothing_period` - Smoothing period (typically 10)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `prices.is_empty()`
-    /// * `prices.len()` != `volume.len()`
+    /// Returns an error if:
+    /// * Any of the periods == 0
+    /// * `prices.len()` < `max(long_roc_period, short_roc_period) + smoothing_period`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0];
-    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1300.0, 1400.0];
-    /// let on_balance_volume =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::on_balance_volume(
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+    ///
+    /// let coppock_curve =
+    ///     centaur_technical_indicators::momentum_indicators::single::coppock_curve(
     ///         &prices,
-    ///         &volume,
-    ///         0.0
+    ///         3,
+    ///         2,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(vec![1500.0, 2700.0, 1800.0, 500.0, 500.0], on_balance_volume);
+    /// assert_eq!(-5.363695992671797, coppock_curve);
     /// ```
     #[inline]
-    pub fn on_balance_volume(
+    pub fn coppock_curve(
         prices: &[f64],
-        volume: &[f64],
-        previous_on_balance_volume: f64,
-    ) -> crate::Result<Vec<f64>> {
-        assert_non_empty("prices", prices)?;
-        let length = prices.len();
-        assert_same_len(&[("prices", prices), ("volume", volume)])?;
-        let mut obvs = Vec::with_capacity(length - 1);
-        let mut obv =
-            single::on_balance_volume(prices[1], prices[0], volume[1], previous_on_balance_volume)?;
-        obvs.push(obv);
-        for i in 2..length {
-            obv = single::on_balance_volume(prices[i], prices[i - 1], volume[i], obv)?;
-            obvs.push(obv);
-        }
-        Ok(obvs)
+        long_roc_period: usize,
+        short_roc_period: usize,
+        smoothing_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        let coppock_curve = super::bulk::coppock_curve(
+            prices,
+            long_roc_period,
+            short_roc_period,
+            smoothing_period,
+            constant_model_type,
+        )?;
+        Ok(*coppock_curve.last().unwrap())
     }
 
-    /// Calculates the Commodity Channel Index (CCI)
+    /// Calculates the Detrended Price Oscillator (DPO)
+    ///
+    /// The price `period / 2 + 1` periods ago minus the average of the slice, where
+    /// the period is the length of the slice.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `deviation_model` - Variant of [`DeviationModel`]
-    /// * `constant_multiplier` - Scale factor (normally 0.015)
-    /// * `period` - Period over which to calculate the CCI
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// The calculated indicator value
     ///
     /// # Errors
     ///
-    /// Returns error if `period` > `prices.len()`
+    /// Returns an error if:
+    /// * `prices.len()` < 3
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
     /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
-    /// let constant_multiplier = 0.015;
-    /// let period: usize = 3;
-    ///
-    /// let default_cci =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
-    ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
-    ///         centaur_technical_indicators::DeviationModel::MeanAbsoluteDeviation,
-    ///         constant_multiplier,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![79.99999999999983, -100.00000000000001, -100.00000000000001],
-    ///     default_cci
-    /// );
     ///
-    /// let ema_sd_cci =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
+    /// let detrended_price_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::single::detrended_price_oscillator(
     ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
-    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
-    ///         constant_multiplier,
-    ///         period
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![38.1801774160603, -58.32118435197994, -46.65694748158418],
-    ///     ema_sd_cci
-    /// );
-    ///
-    /// let median_mad_cci =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
-    ///         &prices,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-    ///         centaur_technical_indicators::DeviationModel::MedianAbsoluteDeviation,
-    ///         constant_multiplier,
-    ///         period
-    ///     ).unwrap();  
-    /// assert_eq!(
-    ///     vec![66.66666666666667, -66.66666666666667, -66.66666666666667],
-    ///     median_mad_cci
-    /// );
+    /// assert_eq!(1.0, detrended_price_oscillator);
     /// ```
     #[inline]
-    pub fn commodity_channel_index(
+    pub fn detrended_price_oscillator(
         prices: &[f64],
-        constant_model_type: ConstantModelType,
-        deviation_model: DeviationModel,
-        constant_multiplier: f64,
-        period: usize,
-    ) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_period(period, length)?;
-
-        let mut ccis = Vec::with_capacity(length - period + 1);
-        for window in prices.windows(period) {
-            ccis.push(single::commodity_channel_index(
-                window,
-                constant_model_type,
-                deviation_model,
-                constant_multiplier,
-            )?);
-        }
-        Ok(ccis)
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        assert_min_length("prices", 3, prices.len())?;
+        let displaced_price = prices[prices.len() - 2 - prices.len() / 2];
+        Ok(displaced_price - constant_model_type.central_value(prices)?)
     }
 
-    /// Calculates the McGinley dynamic Commodity Channel Index
+    /// Calculates the Schaff Trend Cycle (STC)
+    ///
+    /// A stochastic of the MACD line, smoothed, put through a second stochastic and
+    /// smoothed again. A flat stochastic window keeps the previous value. Uses the
+    /// whole slice; see [`bulk::schaff_trend_cycle`](super::bulk::schaff_trend_cycle)
+    /// for the minimum length.
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `previous_mcginley_dynamic` - Previous McGinley dynamic (0.0 if none)
-    /// * `deviation_model` - Variant of [`DeviationModel`]
-    /// * `constant_multiplier` - Scale factor (normally 0.015)
-    /// * `period` - The period over which to calculate the CCI
+    /// * `short_period` - Short MACD period (typically 23)
+    /// * `long_period` - Long MACD period (typically 50)
+    /// * `cycle_period` - Stochastic period (typically 10)
+    /// * `smoothing_period` - Smoothing period after each stochastic (typically 3)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Any of the periods == 0 or `short_period` > `long_period`
+    /// * `prices.len()` < `long_period + 2 * cycle_period + 2 * smoothing_period - 4`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0, 104.0, 103.0];
+    ///
+    /// let schaff_trend_cycle =
+    ///     centaur_technical_indicators::momentum_indicators::single::schaff_trend_cycle(
+    ///         &prices,
+    ///         2,
+    ///         3,
+    ///         3,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(50.0, schaff_trend_cycle);
+    /// ```
+    #[inline]
+    pub fn schaff_trend_cycle(
+        prices: &[f64],
+        short_period: usize,
+        long_period: usize,
+        cycle_period: usize,
+        smoothing_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        let schaff_trend_cycle = super::bulk::schaff_trend_cycle(
+            prices,
+            short_period,
+            long_period,
+            cycle_period,
+            smoothing_period,
+            constant_model_type,
+        )?;
+        Ok(*schaff_trend_cycle.last().unwrap())
+    }
+
+    #[inline]
+    fn previous_gains_loss(prices: &[f64]) -> crate::Result<(Vec<f64>, Vec<f64>)> {
+        assert_non_empty("prices", prices)?;
+        let len = prices.len();
+        let mut previous_gains = Vec::with_capacity(len - 1);
+        let mut previous_loss = Vec::with_capacity(len - 1);
+        for i in 1..len {
+            let diff = prices[i] - prices[i - 1];
+            if diff > 0.0 {
+                previous_gains.push(diff);
+            } else if diff < 0.0 {
+                previous_loss.push(prices[i - 1] - prices[i]);
+            };
+        }
+        Ok((previous_gains, previous_loss))
+    }
+
+    /// Length of the current run of rising (positive) or falling (negative) prices.
+    #[inline]
+    pub(super) fn up_down_streaks(prices: &[f64]) -> Vec<f64> {
+        let mut streaks = Vec::with_capacity(prices.len());
+        streaks.push(0.0);
+        for i in 1..prices.len() {
+            let previous: f64 = streaks[i - 1];
+            streaks.push(if prices[i] > prices[i - 1] {
+                previous.max(0.0) + 1.0
+            } else if prices[i] < prices[i - 1] {
+                previous.min(0.0) - 1.0
+            } else {
+                0.0
+            });
+        }
+        streaks
+    }
+
+    #[inline]
+    fn cmp_f64(a: &f64, b: &f64) -> Ordering {
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
+pub mod bulk {
+    use crate::momentum_indicators::single;
+    use crate::validation::{
+        assert_min_length, assert_min_period, assert_non_empty, assert_period,
+        assert_positive_usize, assert_same_len,
+    };
+    use crate::{CentralTendency, Deviation};
+
+    /// Calculates the Relative strength index (RSI)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `period` - Period over which to calculate the RSI
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let period: usize = 3;
+    /// let defaut_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], defaut_rsi);
+    ///
+    /// let ema_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], ema_rsi);
+    ///
+    /// let moving_median_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![100.0, 33.33333333333333, 0.0], moving_median_rsi);
+    ///
+    /// let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28];
+    /// let period: usize = 5;
+    ///
+    /// let personalised_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::relative_strength_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::PersonalisedMovingAverage{alpha_num: 5.0, alpha_den:
+    ///         4.0},
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![34.51776649746324, 12.837837837836929, 34.51776649746182, 61.26126126126377],
+    ///     personalised_rsi
+    /// );
+    /// ```
+    #[inline]
+    pub fn relative_strength_index(
+        prices: &[f64],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = prices.len();
+        assert_period(period, length)?;
+
+        let mut rsis = Vec::with_capacity(length - period + 1);
+        for window in prices.windows(period) {
+            rsis.push(single::relative_strength_index(
+                window,
+                &constant_model_type,
+            )?);
+        }
+        Ok(rsis)
+    }
+
+    /// Calculates the Stochastic Oscillator
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to calculate the stochastic oscillator
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let period: usize = 3;
+    /// let stochastic_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::stochastic_oscillator(&prices, period).unwrap();
+    /// assert_eq!(vec![100.0, 0.0, 0.0], stochastic_oscillator);
+    /// ```
+    #[inline]
+    pub fn stochastic_oscillator(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        let length = prices.len();
+        assert_period(period, length)?;
+
+        let mut so = Vec::with_capacity(length - period + 1);
+        for window in prices.windows(period) {
+            so.push(single::stochastic_oscillator(window)?);
+        }
+        Ok(so)
+    }
+
+    /// Calculates the slow Stochastic
+    ///
+    /// # Arguments
+    ///
+    /// * `stochastics` - Slice of Stochastic Oscillators
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `period` - Period over which to calculate the slow stochastic
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `period` > `stochastics.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let stochstic_oscillators = [0.0, 25.0, 50.0, 33.0, 73.0];
+    /// let period: usize = 3;
+    ///
+    /// let simple_ma_slow_stochastic =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
+    ///         &stochstic_oscillators,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![25.0, 36.0, 52.0], simple_ma_slow_stochastic);
+    ///
+    /// let sma_slow_stochastic =
+    /// centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
+    ///     &stochstic_oscillators,
+    ///     centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
+    ///     period
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![31.578947368421055, 36.684210526315795, 55.526315789473685],
+    ///     sma_slow_stochastic
+    /// );
+    ///
+    /// let median_slow_stochastic =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
+    ///         &stochstic_oscillators,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![25.0, 33.0, 50.0], median_slow_stochastic);
+    /// ```
+    #[inline]
+    pub fn slow_stochastic(
+        stochastics: &[f64],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = stochastics.len();
+        assert_period(period, length)?;
+        let mut sso = Vec::with_capacity(length - period + 1);
+        for window in stochastics.windows(period) {
+            sso.push(single::slow_stochastic(window, &constant_model_type)?);
+        }
+        Ok(sso)
+    }
+
+    /// Calculates the slowest Stochastic
+    ///
+    /// # Arguments
+    ///
+    /// * `slow_stochastics` - Slice of slow stochastics
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `period` - Period over which to calculate the slowest stochastic oscillator
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `period` > `slow_stochastics.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let slow_stochstics = [75.0, 60.0, 73.0, 58.0];
+    /// let period: usize = 3;
+    ///
+    /// let ma_slowest_stochastic =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::slowest_stochastic(
+    ///         &slow_stochstics,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![69.33333333333333, 63.666666666666664], ma_slowest_stochastic);
+    ///
+    /// let sma_slowest_stochastic =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
+    ///         &slow_stochstics,
+    ///         centaur_technical_indicators::ConstantModelType::SmoothedMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![69.31578947368422, 63.15789473684211], sma_slowest_stochastic);
+    ///
+    /// let median_slowest_stochastic =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::slow_stochastic(
+    ///         &slow_stochstics,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![73.0, 60.0], median_slowest_stochastic);
+    /// ```
+    #[inline]
+    pub fn slowest_stochastic(
+        slow_stochastics: &[f64],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = slow_stochastics.len();
+        assert_period(period, length)?;
+        let mut sso = Vec::with_capacity(length - period + 1);
+        for window in slow_stochastics.windows(period) {
+            sso.push(single::slowest_stochastic(window, &constant_model_type)?);
+        }
+        Ok(sso)
+    }
+
+    /// Calculates the Williams %R
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period over which to calculate the Williams %R
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `highs.len()` != `lows.len()` != `close.len()`
+    /// * `period` > lengths
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![200.0, 210.0, 205.0, 190.0];
+    /// let lows = vec![175.0, 192.0, 200.0, 174.0];
+    /// let close = vec![192.0, 200.0, 201.0, 187.0];
+    /// let period: usize = 3;
+    /// let williams_percent_r =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::williams_percent_r(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![-25.71428571428571, -63.888888888888886], williams_percent_r);
+    /// ```
+    #[inline]
+    pub fn williams_percent_r(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = close.len();
+        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
+        assert_period(period, length)?;
+
+        let loop_max = length - period + 1;
+        let mut wprs = Vec::with_capacity(loop_max);
+        for i in 0..loop_max {
+            wprs.push(single::williams_percent_r(
+                &highs[i..i + period],
+                &lows[i..i + period],
+                close[i + period - 1],
+            )?);
+        }
+        Ok(wprs)
+    }
+
+    /// Calculates the Money Flow Index (MFI)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `volume` - Slice of volumes
+    /// * `period` - Period over which to calculate the MFI
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `period` > `prices.len()`
+    /// * `prices.len()` != `volume.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1300.0];
+    /// let period: usize = 3;
+    /// let money_flow_index =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::money_flow_index(
+    ///         &prices,
+    ///         &volume,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(vec![55.314533622559644, 0.0, 58.60655737704918], money_flow_index);
+    /// ```
+    #[inline]
+    pub fn money_flow_index(
+        prices: &[f64],
+        volume: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = prices.len();
+        assert_period(period, length)?;
+        assert_same_len(&[("prices", prices), ("volume", volume)])?;
+
+        let loop_max = length - period + 1;
+        let mut mfis = Vec::with_capacity(loop_max);
+
+        for i in 0..loop_max {
+            mfis.push(single::money_flow_index(
+                &prices[i..i + period],
+                &volume[i..i + period],
+            )?);
+        }
+        Ok(mfis)
+    }
+
+    /// Calculates the Rate of Change (RoC)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `prices.is_empty()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 120.0, 100.0];
+    /// let rate_of_change = centaur_technical_indicators::momentum_indicators::bulk::rate_of_change(&prices).unwrap();
+    /// assert_eq!(vec![20.0, -16.666666666666664], rate_of_change);
+    /// ```
+    #[inline]
+    pub fn rate_of_change(prices: &[f64]) -> crate::Result<Vec<f64>> {
+        assert_non_empty("prices", prices)?;
+        let mut rocs = Vec::with_capacity(prices.len() - 1);
+        for pair in prices.windows(2) {
+            rocs.push(single::rate_of_change(pair[1], pair[0])?);
+        }
+        Ok(rocs)
+    }
+
+    /// Calculates the On Balance Volume (OBV)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `volume` - Slice of volumes
+    /// * `previous_on_balance_volume` - Previous OBV (use 0.0 if none)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `prices.is_empty()`
+    /// * `prices.len()` != `volume.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1300.0, 1400.0];
+    /// let on_balance_volume =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::on_balance_volume(
+    ///         &prices,
+    ///         &volume,
+    ///         0.0
+    ///     ).unwrap();
+    /// assert_eq!(vec![1500.0, 2700.0, 1800.0, 500.0, 500.0], on_balance_volume);
+    /// ```
+    #[inline]
+    pub fn on_balance_volume(
+        prices: &[f64],
+        volume: &[f64],
+        previous_on_balance_volume: f64,
+    ) -> crate::Result<Vec<f64>> {
+        assert_non_empty("prices", prices)?;
+        let length = prices.len();
+        assert_same_len(&[("prices", prices), ("volume", volume)])?;
+        let mut obvs = Vec::with_capacity(length - 1);
+        let mut obv =
+            single::on_balance_volume(prices[1], prices[0], volume[1], previous_on_balance_volume)?;
+        obvs.push(obv);
+        for i in 2..length {
+            obv = single::on_balance_volume(prices[i], prices[i - 1], volume[i], obv)?;
+            obvs.push(obv);
+        }
+        Ok(obvs)
+    }
+
+    /// Calculates the Commodity Channel Index (CCI)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    /// * `constant_multiplier` - Scale factor (normally 0.015)
+    /// * `period` - Period over which to calculate the CCI
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let constant_multiplier = 0.015;
+    /// let period: usize = 3;
+    ///
+    /// let default_cci =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::MeanAbsoluteDeviation,
+    ///         constant_multiplier,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![79.99999999999983, -100.00000000000001, -100.00000000000001],
+    ///     default_cci
+    /// );
+    ///
+    /// let ema_sd_cci =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
+    ///         centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///         constant_multiplier,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![38.1801774160603, -58.32118435197994, -46.65694748158418],
+    ///     ema_sd_cci
+    /// );
+    ///
+    /// let median_mad_cci =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::commodity_channel_index(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
+    ///         centaur_technical_indicators::DeviationModel::MedianAbsoluteDeviation,
+    ///         constant_multiplier,
+    ///         period
+    ///     ).unwrap();  
+    /// assert_eq!(
+    ///     vec![66.66666666666667, -66.66666666666667, -66.66666666666667],
+    ///     median_mad_cci
+    /// );
+    /// ```
+    #[inline]
+    pub fn commodity_channel_index(
+        prices: &[f64],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = prices.len();
+        assert_period(period, length)?;
+
+        let mut ccis = Vec::with_capacity(length - period + 1);
+        for window in prices.windows(period) {
+            ccis.push(single::commodity_channel_index(
+                window,
+                &constant_model_type,
+                &deviation_model,
+                constant_multiplier,
+            )?);
+        }
+        Ok(ccis)
+    }
+
+    /// Calculates the McGinley dynamic Commodity Channel Index
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `previous_mcginley_dynamic` - Previous McGinley dynamic (0.0 if none)
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    /// * `constant_multiplier` - Scale factor (normally 0.015)
+    /// * `period` - The period over which to calculate the CCI
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 102.0];
+    /// let constant_multiplier = 0.015;
+    ///
+    /// let mcginley_cci =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::mcginley_dynamic_commodity_channel_index(
+    ///         &prices,
+    ///         0.0,
+    ///         centaur_technical_indicators::DeviationModel::MeanAbsoluteDeviation,
+    ///         constant_multiplier,
+    ///         5_usize
+    ///     ).unwrap();
+    /// assert_eq!(vec![(0.0, 99.0), (146.8770632107927, 99.53246533805869)], mcginley_cci);
+    /// ```
+    #[inline]
+    pub fn mcginley_dynamic_commodity_channel_index(
+        prices: &[f64],
+        previous_mcginley_dynamic: f64,
+        deviation_model: impl Deviation,
+        constant_multiplier: f64,
+        period: usize,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        let length = prices.len();
+        assert_period(period, length)?;
+
+        let mut ccis = Vec::with_capacity(length - period + 1);
+        let mut cci = single::mcginley_dynamic_commodity_channel_index(
+            &prices[..period],
+            previous_mcginley_dynamic,
+            &deviation_model,
+            constant_multiplier,
+        )?;
+        ccis.push(cci);
+        for i in 1..=length - period {
+            cci = single::mcginley_dynamic_commodity_channel_index(
+                &prices[i..i + period],
+                cci.1,
+                &deviation_model,
+                constant_multiplier,
+            )?;
+            ccis.push(cci);
+        }
+        Ok(ccis)
+    }
+
+    /// Calculates the Moving Average Convergence Divergence (MACD) line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `short_period` - The length of the short period
+    /// * `short_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `long_period` - The length of the long period
+    /// * `long_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `short_period` > `long_period`
+    /// * `long_period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0];
+    /// let short_period: usize = 3;
+    /// let long_period: usize = 5;
+    ///
+    /// let macd =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::macd_line(
+    ///         &prices,
+    ///         short_period,
+    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
+    ///         long_period,
+    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![-0.46851726472581845, -0.7379823967501835, 0.031821259309410266],
+    ///     macd
+    /// );
+    ///
+    /// let macd =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::macd_line(
+    ///         &prices,
+    ///         short_period,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         long_period,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian).unwrap();
+    /// assert_eq!(vec![0.0, -1.3333333333333286, -1.0], macd);
+    /// ```
+    #[inline]
+    pub fn macd_line(
+        prices: &[f64],
+        short_period: usize,
+        short_period_model: impl CentralTendency,
+        long_period: usize,
+        long_period_model: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_period(short_period, long_period)?;
+
+        let length = prices.len();
+        assert_period(long_period, length)?;
+
+        let mut macds = Vec::with_capacity(length - long_period + 1);
+        for window in prices.windows(long_period) {
+            macds.push(single::macd_line(
+                window,
+                short_period,
+                &short_period_model,
+                &long_period_model,
+            )?);
+        }
+        Ok(macds)
+    }
+
+    /// Calculates the MACD signal line
+    /// divergence.
+    ///
+    /// # Arguments
+    ///
+    /// * `macds` - Slice of MACDs
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `period` - Period over which to calculate the signal line
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
     ///
     /// # Errors
     ///
@@ -1846,59 +2248,254 @@ pub mod bulk {
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 102.0];
-    /// let constant_multiplier = 0.015;
+    /// let macds = vec![
+    ///     -0.0606702775897219, -0.0224170616113781, 0.0057887610020515,
+    ///     0.0318212593094103, -0.737982396750169
+    /// ];
+    /// let period: usize = 3;
     ///
-    /// let mcginley_cci =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::mcginley_dynamic_commodity_channel_index(
+    /// let ema_signal_line =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::signal_line(
+    ///         &macds,
+    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![-0.011764193829181728, 0.0166350710900523, -0.4117854724828291],
+    ///     ema_signal_line
+    /// );
+    ///
+    /// let median_signal_line =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::signal_line(
+    ///         &macds,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
+    ///         period
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![-0.0224170616113781, 0.0057887610020515, 0.0057887610020515],
+    ///     median_signal_line
+    /// );
+    /// ```
+    #[inline]
+    pub fn signal_line(
+        macds: &[f64],
+        constant_model_type: impl CentralTendency,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        let length = macds.len();
+        assert_period(period, length)?;
+
+        let mut signals = Vec::with_capacity(length - period + 1);
+        for window in macds.windows(period) {
+            signals.push(single::signal_line(window, &constant_model_type)?);
+        }
+        Ok(signals)
+    }
+
+    /// Calculates the McGinley dynamic MACD line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `short_period` - The length of the short period
+    /// * `previous_short_mcginley` - Previous short model McGinley dynamic (if none use 0.0)
+    /// * `long_period` - The length of the long period
+    /// * `previous_long_mcginley` - Previous long model McGinley dynamic (if none use 0.0)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `prices.is_empty()`
+    /// * `long_period` > `prices.len()`
+    /// * `short_period` >= `long_period`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0];
+    /// let short_period: usize = 3;
+    /// let long_period: usize = 5;
+    /// let mcginley_dynamic_macd =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::mcginley_dynamic_macd_line(
     ///         &prices,
+    ///         short_period,
     ///         0.0,
-    ///         centaur_technical_indicators::DeviationModel::MeanAbsoluteDeviation,
-    ///         constant_multiplier,
-    ///         5_usize
+    ///         long_period,
+    ///         0.0
     ///     ).unwrap();
-    /// assert_eq!(vec![(0.0, 99.0), (146.8770632107927, 99.53246533805869)], mcginley_cci);
+    /// assert_eq!(
+    ///     vec![
+    ///         (0.0, 99.0, 99.0),
+    ///         (0.0, 99.0, 99.0),
+    ///         (0.35497689203913296, 99.88744223009782, 99.53246533805869)
+    ///     ], mcginley_dynamic_macd
+    /// );
     /// ```
     #[inline]
-    pub fn mcginley_dynamic_commodity_channel_index(
+    pub fn mcginley_dynamic_macd_line(
         prices: &[f64],
-        previous_mcginley_dynamic: f64,
-        deviation_model: DeviationModel,
-        constant_multiplier: f64,
-        period: usize,
-    ) -> crate::Result<Vec<(f64, f64)>> {
+        short_period: usize,
+        previous_short_mcginley: f64,
+        long_period: usize,
+        previous_long_mcginley: f64,
+    ) -> crate::Result<Vec<(f64, f64, f64)>> {
+        assert_non_empty("prices", prices)?;
+        assert_period(short_period, long_period)?;
         let length = prices.len();
-        assert_period(period, length)?;
+        assert_period(long_period, length)?;
 
-        let mut ccis = Vec::with_capacity(length - period + 1);
-        let mut cci = single::mcginley_dynamic_commodity_channel_index(
-            &prices[..period],
-            previous_mcginley_dynamic,
-            deviation_model,
-            constant_multiplier,
+        let loop_max = length - long_period + 1;
+        let mut macds = Vec::with_capacity(loop_max);
+        let mut macd = single::mcginley_dynamic_macd_line(
+            &prices[..long_period],
+            short_period,
+            previous_short_mcginley,
+            previous_long_mcginley,
+        )?;
+        macds.push(macd);
+
+        for i in 1..loop_max {
+            macd = single::mcginley_dynamic_macd_line(
+                &prices[i..long_period + i],
+                short_period,
+                macd.1,
+                macd.2,
+            )?;
+            macds.push(macd);
+        }
+        Ok(macds)
+    }
+
+    /// Calculates the  Chaikin Oscillator (CO)
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `short_period` - Short period over which to calculate the AD
+    /// * `long_period` - Long period over which to calculate the AD
+    /// * `previous_accumulation_distribution` - Previous AD value (if none use 0.0)
+    /// * `short_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `long_period_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    ///
+    /// # Returns
+    ///
+    /// A vector of tuples, each containing (chaikin_oscillator, accumulation_distribution)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `highs.len()` != `lows.len()` != `close.len()` != `volume.len()`
+    /// * `long_period` > lengths
+    /// * `short_period` >= `long_period`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![103.0, 102.0, 105.0, 109.0, 106.0, 102.0, 107.0];
+    /// let lows = vec![99.0, 99.0, 100.0, 103.0, 98.0, 94.0, 96.0];
+    /// let close = vec![102.0, 100.0, 103.0, 106.0, 100.0, 97.0, 105.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 1500.0, 2000.0, 3000.0, 1250.0];
+    /// let short_period: usize = 3;
+    /// let long_period: usize = 5;
+    /// let previous = 0.0;
+    ///
+    /// let chaikin_oscillator = centaur_technical_indicators::momentum_indicators::bulk::chaikin_oscillator(
+    ///     &highs,
+    ///     &lows,
+    ///     &close,
+    ///     &volume,
+    ///     short_period,
+    ///     long_period,
+    ///     previous,
+    ///     centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
+    ///     centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![(-179.95937711577525, -760.0), (-339.790115098172, -2770.0), (-203.39139533452362, -3484.5454545454545)],
+    ///     chaikin_oscillator
+    /// );
+    ///
+    /// let previous = 500.0;
+    /// let chaikin_oscillator = centaur_technical_indicators::momentum_indicators::bulk::chaikin_oscillator(
+    ///     &highs,
+    ///     &lows,
+    ///     &close,
+    ///     &volume,
+    ///     short_period,
+    ///     long_period,
+    ///     previous,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingMedian
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![(-333.3333333333333, -260.0), (-676.6666666666667, -2270.0), (-280.30303030303, -2984.5454545454545)],
+    ///     chaikin_oscillator
+    /// );
+    /// ```
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn chaikin_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        short_period: usize,
+        long_period: usize,
+        previous_accumulation_distribution: f64,
+        short_period_model: impl CentralTendency,
+        long_period_model: impl CentralTendency,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        let length = highs.len();
+        assert_same_len(&[
+            ("highs", highs),
+            ("lows", lows),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period, length)?;
+
+        let loop_max = length - long_period + 1;
+        let mut cos = Vec::with_capacity(loop_max);
+        let mut co = single::chaikin_oscillator(
+            &highs[..long_period],
+            &lows[..long_period],
+            &close[..long_period],
+            &volume[..long_period],
+            short_period,
+            previous_accumulation_distribution,
+            &short_period_model,
+            &long_period_model,
         )?;
-        ccis.push(cci);
-        for i in 1..=length - period {
-            cci = single::mcginley_dynamic_commodity_channel_index(
-                &prices[i..i + period],
-                cci.1,
-                deviation_model,
-                constant_multiplier,
+        cos.push(co);
+
+        for i in 1..loop_max {
+            co = single::chaikin_oscillator(
+                &highs[i..i + long_period],
+                &lows[i..i + long_period],
+                &close[i..i + long_period],
+                &volume[i..i + long_period],
+                short_period,
+                co.1,
+                &short_period_model,
+                &long_period_model,
             )?;
-            ccis.push(cci);
+            cos.push(co);
         }
-        Ok(ccis)
+        Ok(cos)
     }
 
-    /// Calculates the Moving Average Convergence Divergence (MACD) line
+    /// Calculates the Percentage Price Oscillator (PPO)
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `short_period` - The length of the short period
-    /// * `short_period_model` - Variant of [`ConstantModelType`]
-    /// * `long_period` - The length of the long period
-    /// * `long_period_model` - Variant of [`ConstantModelType`]
+    /// * `short_period` - Length of short period.
+    /// * `long_period` - Length of long period
+    /// * `constant_model_type` Variant of [`ConstantModelType`](crate::ConstantModelType)
     ///
     /// # Returns
     ///
@@ -1907,330 +2504,588 @@ pub mod bulk {
     /// # Errors
     ///
     /// Returns error if:
-    /// * `short_period` > `long_period`
-    /// * `long_period` > `prices.len()`
+    /// * `short_period` >= `long_period`
+    /// * `prices.is_empty()`
+    /// * 'long_period` > `prices.len()`
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0];
+    /// let prices = vec![100.0, 103.0, 106.0, 100.0, 97.0, 105.0, 102.0];
     /// let short_period: usize = 3;
     /// let long_period: usize = 5;
     ///
-    /// let macd =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::macd_line(
+    /// let percentage_price_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::percentage_price_oscillator(
     ///         &prices,
     ///         short_period,
-    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
     ///         long_period,
     ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage
     ///     ).unwrap();
+    ///
     /// assert_eq!(
-    ///     vec![-0.46851726472581845, -0.7379823967501835, 0.031821259309410266],
-    ///     macd
+    ///     vec![-1.0681349863189704, -0.06036684467148623, 0.14936271906531415],
+    ///     percentage_price_oscillator
     /// );
-    ///
-    /// let macd =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::macd_line(
-    ///         &prices,
-    ///         short_period,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
-    ///         long_period,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian).unwrap();
-    /// assert_eq!(vec![0.0, -1.3333333333333286, -1.0], macd);
     /// ```
     #[inline]
-    pub fn macd_line(
+    pub fn percentage_price_oscillator(
         prices: &[f64],
         short_period: usize,
-        short_period_model: ConstantModelType,
         long_period: usize,
-        long_period_model: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<f64>> {
-        assert_period(short_period, long_period)?;
-
         let length = prices.len();
+        assert_period(short_period, long_period)?;
+        assert_non_empty("prices", prices)?;
         assert_period(long_period, length)?;
 
-        let mut macds = Vec::with_capacity(length - long_period + 1);
+        let mut ppos = Vec::with_capacity(length - long_period + 1);
+
         for window in prices.windows(long_period) {
-            macds.push(single::macd_line(
+            ppos.push(single::percentage_price_oscillator(
                 window,
                 short_period,
-                short_period_model,
-                long_period_model,
+                &constant_model_type,
             )?);
         }
-        Ok(macds)
+        Ok(ppos)
     }
 
-    /// Calculates the MACD signal line
-    /// divergence.
+    /// Calculates the Chande Momentum Oscillator (CMO)
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to calculate the CMO
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// * `prices.is_empty()`
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 103.0, 106.0, 100.0, 97.0, 105.0, 102.0];
+    /// let chande_momentum_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::chande_momentum_oscillator(
+    ///         &prices,
+    ///         5_usize
+    ///     ).unwrap();
+    /// assert_eq!(vec![-20.0, 10.0, -20.0], chande_momentum_oscillator)
+    /// ```
+    #[inline]
+    pub fn chande_momentum_oscillator(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        let length = prices.len();
+        assert_non_empty("prices", prices)?;
+        assert_period(period, length)?;
+
+        let mut cmos = Vec::with_capacity(length - period + 1);
+        for window in prices.windows(period) {
+            cmos.push(single::chande_momentum_oscillator(window)?);
+        }
+        Ok(cmos)
+    }
+
+    /// Calculates the Stochastic RSI
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for the RSI
+    /// * `rsi_period` - Period over which to calculate each RSI
+    /// * `stochastic_period` - Period over which to calculate the stochastic of the RSIs
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.is_empty()`
+    /// * `rsi_period` == 0 or `stochastic_period` == 0
+    /// * `rsi_period + stochastic_period - 1` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+    ///
+    /// let stochastic_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::stochastic_rsi(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         3,
+    ///         4
+    ///     ).unwrap();
+    /// assert_eq!(vec![33.33333333333333, 100.0], stochastic_rsi);
+    /// ```
+    #[inline]
+    pub fn stochastic_rsi(
+        prices: &[f64],
+        constant_model_type: impl CentralTendency,
+        rsi_period: usize,
+        stochastic_period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_non_empty("prices", prices)?;
+        assert_positive_usize("stochastic_period", stochastic_period)?;
+        assert_period(rsi_period + stochastic_period - 1, prices.len())?;
+        let rsis = relative_strength_index(prices, constant_model_type, rsi_period)?;
+        stochastic_oscillator(&rsis, stochastic_period)
+    }
+
+    /// Calculates the Ultimate Oscillator
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `short_period` - Short period (typically 7)
+    /// * `medium_period` - Medium period (typically 14)
+    /// * `long_period` - Long period (typically 28)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the `long_period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `short_period` == 0, `short_period` > `medium_period` or `medium_period` > `long_period`
+    /// * `long_period` >= `close.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+    ///
+    /// let ultimate_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::ultimate_oscillator(
+    ///         &highs, &lows, &close, 1, 2, 4
+    ///     ).unwrap();
+    /// assert_eq!(vec![35.064935064935064, 66.3265306122449], ultimate_oscillator);
+    /// ```
+    pub fn ultimate_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        short_period: usize,
+        medium_period: usize,
+        long_period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_period(long_period + 1, close.len())?;
+        (0..close.len() - long_period)
+            .map(|i| {
+                let end = i + long_period + 1;
+                single::ultimate_oscillator(
+                    &highs[i..end],
+                    &lows[i..end],
+                    &close[i..end],
+                    short_period,
+                    medium_period,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Awesome Oscillator
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `short_period` - Short period (typically 5)
+    /// * `long_period` - Long period (typically 34)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `short_period` == 0, `short_period` > `long_period` or `long_period` > `highs.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+    ///
+    /// let awesome_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::awesome_oscillator(
+    ///         &highs,
+    ///         &lows,
+    ///         2,
+    ///         3,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(vec![0.75, 0.25, 0.0], awesome_oscillator);
+    /// ```
+    #[inline]
+    pub fn awesome_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period, highs.len())?;
+        highs
+            .windows(long_period)
+            .zip(lows.windows(long_period))
+            .map(|(h, l)| single::awesome_oscillator(h, l, short_period, &constant_model_type))
+            .collect()
+    }
+
+    /// Calculates the Accelerator Oscillator
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `short_period` - Short period of the Awesome Oscillator (typically 5)
+    /// * `long_period` - Long period of the Awesome Oscillator (typically 34)
+    /// * `signal_period` - Period of the Awesome Oscillator average (typically 5)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `short_period` == 0, `short_period` > `long_period` or `signal_period` == 0
+    /// * `long_period + signal_period - 1` > `highs.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+    ///
+    /// let accelerator_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::accelerator_oscillator(
+    ///         &highs,
+    ///         &lows,
+    ///         2,
+    ///         3,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(vec![-0.25, -0.125], accelerator_oscillator);
+    /// ```
+    pub fn accelerator_oscillator(
+        highs: &[f64],
+        lows: &[f64],
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_positive_usize("signal_period", signal_period)?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period + signal_period - 1, highs.len())?;
+        let window = long_period + signal_period - 1;
+        highs
+            .windows(window)
+            .zip(lows.windows(window))
+            .map(|(h, l)| {
+                single::accelerator_oscillator(
+                    h,
+                    l,
+                    short_period,
+                    long_period,
+                    &constant_model_type,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Connors RSI
+    ///
+    /// The up/down streaks are counted over the whole slice, so a streak that
+    /// started before the RSI or rank windows keeps its full length.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for both RSIs
+    /// * `rsi_period` - Period of the price RSI (typically 3)
+    /// * `streak_period` - Period of the streak RSI (typically 2)
+    /// * `rank_period` - Number of previous returns the latest return is ranked against (typically 100)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the price at index
+    /// `max(rank_period + 1, rsi_period - 1, streak_period - 1)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `rank_period`, `rsi_period` or `streak_period` == 0
+    /// * `prices.len()` <= `max(rank_period + 1, rsi_period - 1, streak_period - 1)`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+    ///
+    /// let connors_rsi =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::connors_rsi(
+    ///         &prices,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///         3,
+    ///         2,
+    ///         4
+    ///     ).unwrap();
+    /// assert_eq!(vec![69.44444444444444, 100.0], connors_rsi);
+    /// ```
+    pub fn connors_rsi(
+        prices: &[f64],
+        constant_model_type: impl CentralTendency,
+        rsi_period: usize,
+        streak_period: usize,
+        rank_period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_positive_usize("rank_period", rank_period)?;
+        assert_positive_usize("rsi_period", rsi_period)?;
+        assert_positive_usize("streak_period", streak_period)?;
+        let first = (rank_period + 1).max(rsi_period - 1).max(streak_period - 1);
+        assert_min_length("prices", first + 1, prices.len())?;
+
+        let streaks = single::up_down_streaks(prices);
+        let returns: Vec<f64> = prices.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+        (first..prices.len())
+            .map(|i| {
+                let latest = returns[i - 1];
+                let percent_rank = returns[i - 1 - rank_period..i - 1]
+                    .iter()
+                    .filter(|r| **r < latest)
+                    .count() as f64
+                    / rank_period as f64
+                    * 100.0;
+                let price_rsi = single::relative_strength_index(
+                    &prices[i + 1 - rsi_period..=i],
+                    &constant_model_type,
+                )?;
+                let streak_rsi = single::relative_strength_index(
+                    &streaks[i + 1 - streak_period..=i],
+                    &constant_model_type,
+                )?;
+                Ok((price_rsi + streak_rsi + percent_rank) / 3.0)
+            })
+            .collect()
+    }
+
+    /// Calculates the TRIX, the rate of change of a triple smoothed price
     ///
     /// # Arguments
     ///
-    /// * `macds` - Slice of MACDs
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
-    /// * `period` - Period over which to calculate the signal line
+    /// * `prices` - Slice of prices
+    /// * `period` - Period of each smoothing (typically 15)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// A vector of calculated values, the first one for the `3 * period - 1`th price
     ///
     /// # Errors
     ///
-    /// Returns error if `period` > `prices.len()`
+    /// Returns an error if:
+    /// * `period` == 0
+    /// * `prices.len()` < `3 * period - 1`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let macds = vec![
-    ///     -0.0606702775897219, -0.0224170616113781, 0.0057887610020515,
-    ///     0.0318212593094103, -0.737982396750169
-    /// ];
-    /// let period: usize = 3;
-    ///
-    /// let ema_signal_line =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::signal_line(
-    ///         &macds,
-    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![-0.011764193829181728, 0.0166350710900523, -0.4117854724828291],
-    ///     ema_signal_line
-    /// );
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
     ///
-    /// let median_signal_line =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::signal_line(
-    ///         &macds,
-    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingMedian,
-    ///         period
-    ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![-0.0224170616113781, 0.0057887610020515, 0.0057887610020515],
-    ///     median_signal_line
-    /// );
+    /// let trix = centaur_technical_indicators::momentum_indicators::bulk::trix(
+    ///     &prices,
+    ///     2,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(vec![-0.3676470588235294, -1.2300123001230012, -0.37359900373599003], trix);
     /// ```
     #[inline]
-    pub fn signal_line(
-        macds: &[f64],
-        constant_model_type: ConstantModelType,
+    pub fn trix(
+        prices: &[f64],
         period: usize,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<f64>> {
-        let length = macds.len();
-        assert_period(period, length)?;
-
-        let mut signals = Vec::with_capacity(length - period + 1);
-        for window in macds.windows(period) {
-            signals.push(single::signal_line(window, constant_model_type)?);
-        }
-        Ok(signals)
+        assert_positive_usize("period", period)?;
+        assert_min_length("prices", 3 * period - 1, prices.len())?;
+        let single_smoothed = smooth(prices, period, &constant_model_type)?;
+        let double_smoothed = smooth(&single_smoothed, period, &constant_model_type)?;
+        let triple_smoothed = smooth(&double_smoothed, period, &constant_model_type)?;
+        rate_of_change(&triple_smoothed)
     }
 
-    /// Calculates the McGinley dynamic MACD line
+    /// Calculates Pring's Know Sure Thing (KST)
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `short_period` - The length of the short period
-    /// * `previous_short_mcginley` - Previous short model McGinley dynamic (if none use 0.0)
-    /// * `long_period` - The length of the long period
-    /// * `previous_long_mcginley` - Previous long model McGinley dynamic (if none use 0.0)
+    /// * `roc_periods` - Rate of change periods (typically [10, 15, 20, 30])
+    /// * `smoothing_periods` - Smoothing period of each rate of change (typically [10, 10, 10, 15])
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the price at the longest
+    /// `roc_periods[i] + smoothing_periods[i]`
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `prices.is_empty()`
-    /// * `long_period` > `prices.len()`
-    /// * `short_period` >= `long_period`
+    /// Returns an error if:
+    /// * Any of the periods == 0
+    /// * `prices.len()` is shorter than the longest `roc_periods[i] + smoothing_periods[i]`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 99.0, 102.0];
-    /// let short_period: usize = 3;
-    /// let long_period: usize = 5;
-    /// let mcginley_dynamic_macd =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::mcginley_dynamic_macd_line(
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+    ///
+    /// let know_sure_thing =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::know_sure_thing(
     ///         &prices,
-    ///         short_period,
-    ///         0.0,
-    ///         long_period,
-    ///         0.0
+    ///         [1, 2, 3, 4],
+    ///         [2, 2, 2, 2],
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![
-    ///         (0.0, 99.0, 99.0),
-    ///         (0.0, 99.0, 99.0),
-    ///         (0.35497689203913296, 99.88744223009782, 99.53246533805869)
-    ///     ], mcginley_dynamic_macd
-    /// );
+    /// assert_eq!(vec![-20.060908032550827, -5.201845198797435], know_sure_thing);
     /// ```
-    #[inline]
-    pub fn mcginley_dynamic_macd_line(
+    pub fn know_sure_thing(
         prices: &[f64],
-        short_period: usize,
-        previous_short_mcginley: f64,
-        long_period: usize,
-        previous_long_mcginley: f64,
-    ) -> crate::Result<Vec<(f64, f64, f64)>> {
-        assert_non_empty("prices", prices)?;
-        assert_period(short_period, long_period)?;
-        let length = prices.len();
-        assert_period(long_period, length)?;
-
-        let loop_max = length - long_period + 1;
-        let mut macds = Vec::with_capacity(loop_max);
-        let mut macd = single::mcginley_dynamic_macd_line(
-            &prices[..long_period],
-            short_period,
-            previous_short_mcginley,
-            previous_long_mcginley,
-        )?;
-        macds.push(macd);
-
-        for i in 1..loop_max {
-            macd = single::mcginley_dynamic_macd_line(
-                &prices[i..long_period + i],
-                short_period,
-                macd.1,
-                macd.2,
-            )?;
-            macds.push(macd);
+        roc_periods: [usize; 4],
+        smoothing_periods: [usize; 4],
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        for (roc_period, smoothing_period) in roc_periods.iter().zip(smoothing_periods.iter()) {
+            assert_positive_usize("roc_period", *roc_period)?;
+            assert_positive_usize("smoothing_period", *smoothing_period)?;
         }
-        Ok(macds)
+        let lookback = roc_periods
+            .iter()
+            .zip(smoothing_periods.iter())
+            .map(|(roc_period, smoothing_period)| roc_period + smoothing_period)
+            .max()
+            .unwrap();
+        assert_min_length("prices", lookback, prices.len())?;
+
+        let length = prices.len() - lookback + 1;
+        let mut know_sure_thing = vec![0.0; length];
+        for (weight, (roc_period, smoothing_period)) in
+            roc_periods.iter().zip(smoothing_periods.iter()).enumerate()
+        {
+            let rocs = rate_of_change_over(prices, *roc_period)?;
+            let smoothed = smooth(&rocs, *smoothing_period, &constant_model_type)?;
+            for (kst, roc) in know_sure_thing
+                .iter_mut()
+                .zip(&smoothed[smoothed.len() - length..])
+            {
+                *kst += (weight + 1) as f64 * roc;
+            }
+        }
+        Ok(know_sure_thing)
     }
 
-    /// Calculates the  Chaikin Oscillator (CO)
+    /// Calculates the Coppock Curve
     ///
     /// # Arguments
     ///
-    /// * `highs` - Slice of highs
-    /// * `lows` - Slice of lows
-    /// * `close` - Slice of closing prices
-    /// * `volume` - Slice of volumes
-    /// * `short_period` - Short period over which to calculate the AD
-    /// * `long_period` - Long period over which to calculate the AD
-    /// * `previous_accumulation_distribution` - Previous AD value (if none use 0.0)
-    /// * `short_period_model` - Variant of [`ConstantModelType`]
-    /// * `long_period_model` - Variant of [`ConstantModelType`]
+    /// * `prices` - Slice of prices
+    /// * `long_roc_period` - Long rate of change period (typically 14)
+    /// * `short_roc_period` - Short rate of change period (typically 11)
+    /// * `smoothing_period` - Smoothing period (typically 10)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
-    /// A vector of tuples, each containing (chaikin_oscillator, accumulation_distribution)
+    /// A vector of calculated values
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `highs.len()` != `lows.len()` != `close.len()` != `volume.len()`
-    /// * `long_period` > lengths
-    /// * `short_period` >= `long_period`
+    /// Returns an error if:
+    /// * Any of the periods == 0
+    /// * `prices.len()` < `max(long_roc_period, short_roc_period) + smoothing_period`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let highs = vec![103.0, 102.0, 105.0, 109.0, 106.0, 102.0, 107.0];
-    /// let lows = vec![99.0, 99.0, 100.0, 103.0, 98.0, 94.0, 96.0];
-    /// let close = vec![102.0, 100.0, 103.0, 106.0, 100.0, 97.0, 105.0];
-    /// let volume = vec![1000.0, 1500.0, 1200.0, 1500.0, 2000.0, 3000.0, 1250.0];
-    /// let short_period: usize = 3;
-    /// let long_period: usize = 5;
-    /// let previous = 0.0;
-    ///
-    /// let chaikin_oscillator = centaur_technical_indicators::momentum_indicators::bulk::chaikin_oscillator(
-    ///     &highs,
-    ///     &lows,
-    ///     &close,
-    ///     &volume,
-    ///     short_period,
-    ///     long_period,
-    ///     previous,
-    ///     centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage,
-    ///     centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage
-    /// ).unwrap();
-    /// assert_eq!(
-    ///     vec![(-179.95937711577525, -760.0), (-339.790115098172, -2770.0), (-203.39139533452362, -3484.5454545454545)],
-    ///     chaikin_oscillator
-    /// );
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
     ///
-    /// let previous = 500.0;
-    /// let chaikin_oscillator = centaur_technical_indicators::momentum_indicators::bulk::chaikin_oscillator(
-    ///     &highs,
-    ///     &lows,
-    ///     &close,
-    ///     &volume,
-    ///     short_period,
-    ///     long_period,
-    ///     previous,
-    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
-    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingMedian
+    /// let coppock_curve =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::coppock_curve(
+    ///         &prices,
+    ///         3,
+    ///         2,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(
-    ///     vec![(-333.3333333333333, -260.0), (-676.6666666666667, -2270.0), (-280.30303030303, -2984.5454545454545)],
-    ///     chaikin_oscillator
-    /// );
+    /// assert_eq!(vec![-3.402531886541024, -5.363695992671797, 0.05884083553986463], coppock_curve);
     /// ```
-    #[inline]
-    pub fn chaikin_oscillator(
-        highs: &[f64],
-        lows: &[f64],
-        close: &[f64],
-        volume: &[f64],
-        short_period: usize,
-        long_period: usize,
-        previous_accumulation_distribution: f64,
-        short_period_model: ConstantModelType,
-        long_period_model: ConstantModelType,
-    ) -> crate::Result<Vec<(f64, f64)>> {
-        let length = highs.len();
-        assert_same_len(&[
-            ("highs", highs),
-            ("lows", lows),
-            ("close", close),
-            ("volume", volume),
-        ])?;
-        assert_period(short_period, long_period)?;
-        assert_period(long_period, length)?;
-
-        let loop_max = length - long_period + 1;
-        let mut cos = Vec::with_capacity(loop_max);
-        let mut co = single::chaikin_oscillator(
-            &highs[..long_period],
-            &lows[..long_period],
-            &close[..long_period],
-            &volume[..long_period],
-            short_period,
-            previous_accumulation_distribution,
-            short_period_model,
-            long_period_model,
-        )?;
-        cos.push(co);
-
-        for i in 1..loop_max {
-            co = single::chaikin_oscillator(
-                &highs[i..i + long_period],
-                &lows[i..i + long_period],
-                &close[i..i + long_period],
-                &volume[i..i + long_period],
-                short_period,
-                co.1,
-                short_period_model,
-                long_period_model,
-            )?;
-            cos.push(co);
-        }
-        Ok(cos)
+    pub fn coppock_curve(
+        prices: &[f64],
+        long_roc_period: usize,
+        short_roc_period: usize,
+        smoothing_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_positive_usize("long_roc_period", long_roc_period)?;
+        assert_positive_usize("short_roc_period", short_roc_period)?;
+        assert_positive_usize("smoothing_period", smoothing_period)?;
+        let roc_period = long_roc_period.max(short_roc_period);
+        assert_min_length("prices", roc_period + smoothing_period, prices.len())?;
+
+        let long_rocs = rate_of_change_over(prices, long_roc_period)?;
+        let short_rocs = rate_of_change_over(prices, short_roc_period)?;
+        let length = prices.len() - roc_period;
+        let summed: Vec<f64> = long_rocs[long_rocs.len() - length..]
+            .iter()
+            .zip(&short_rocs[short_rocs.len() - length..])
+            .map(|(long, short)| long + short)
+            .collect();
+        smooth(&summed, smoothing_period, constant_model_type)
     }
 
-    /// Calculates the Percentage Price Oscillator (PPO)
+    /// Calculates the Detrended Price Oscillator (DPO)
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `short_period` - Length of short period.
-    /// * `long_period` - Length of long period
-    /// * `constant_model_type` Variant of [`ConstantModelType`]
+    /// * `period` - Period over which to calculate the DPO (typically 20)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
     ///
     /// # Returns
     ///
@@ -2238,94 +3093,143 @@ pub mod bulk {
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `short_period` >= `long_period`
-    /// * `prices.is_empty()`
-    /// * 'long_period` > `prices.len()`
+    /// Returns an error if:
+    /// * `period` < 3 or `period` > `prices.len()`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 103.0, 106.0, 100.0, 97.0, 105.0, 102.0];
-    /// let short_period: usize = 3;
-    /// let long_period: usize = 5;
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
     ///
-    /// let percentage_price_oscillator =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::percentage_price_oscillator(
+    /// let detrended_price_oscillator =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::detrended_price_oscillator(
     ///         &prices,
-    ///         short_period,
-    ///         long_period,
-    ///         centaur_technical_indicators::ConstantModelType::ExponentialMovingAverage
+    ///         5,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    ///
-    /// assert_eq!(
-    ///     vec![-1.0681349863189704, -0.06036684467148623, 0.14936271906531415],
-    ///     percentage_price_oscillator
-    /// );
+    /// assert_eq!(vec![1.0, 2.0], detrended_price_oscillator);
     /// ```
     #[inline]
-    pub fn percentage_price_oscillator(
+    pub fn detrended_price_oscillator(
         prices: &[f64],
-        short_period: usize,
-        long_period: usize,
-        constant_model_type: ConstantModelType,
+        period: usize,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_period(short_period, long_period)?;
-        assert_non_empty("prices", prices)?;
-        assert_period(long_period, length)?;
-
-        let mut ppos = Vec::with_capacity(length - long_period + 1);
-
-        for window in prices.windows(long_period) {
-            ppos.push(single::percentage_price_oscillator(
-                window,
-                short_period,
-                constant_model_type,
-            )?);
-        }
-        Ok(ppos)
+        assert_min_period(period, 3, prices.len())?;
+        prices
+            .windows(period)
+            .map(|window| single::detrended_price_oscillator(window, &constant_model_type))
+            .collect()
     }
 
-    /// Calculates the Chande Momentum Oscillator (CMO)
+    /// Calculates the Schaff Trend Cycle (STC)
+    ///
+    /// A flat stochastic window keeps the previous value (0 for the first window).
     ///
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `period` - Period over which to calculate the CMO
+    /// * `short_period` - Short MACD period (typically 23)
+    /// * `long_period` - Long MACD period (typically 50)
+    /// * `cycle_period` - Stochastic period (typically 10)
+    /// * `smoothing_period` - Smoothing period after each stochastic (typically 3)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
     ///
     /// # Returns
     ///
-    /// A vector of calculated values
+    /// A vector of calculated values, the first one for the
+    /// `long_period + 2 * cycle_period + 2 * smoothing_period - 4`th price
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// * `prices.is_empty()`
-    /// * `period` > `prices.len()`
+    /// Returns an error if:
+    /// * Any of the periods == 0 or `short_period` > `long_period`
+    /// * `prices.len()` < `long_period + 2 * cycle_period + 2 * smoothing_period - 4`
+    /// * `constant_model_type` is unsupported
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let prices = vec![100.0, 103.0, 106.0, 100.0, 97.0, 105.0, 102.0];
-    /// let chande_momentum_oscillator =
-    ///     centaur_technical_indicators::momentum_indicators::bulk::chande_momentum_oscillator(
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0, 104.0, 103.0];
+    ///
+    /// let schaff_trend_cycle =
+    ///     centaur_technical_indicators::momentum_indicators::bulk::schaff_trend_cycle(
     ///         &prices,
-    ///         5_usize
+    ///         2,
+    ///         3,
+    ///         3,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
     ///     ).unwrap();
-    /// assert_eq!(vec![-20.0, 10.0, -20.0], chande_momentum_oscillator)
+    /// assert_eq!(vec![50.0], schaff_trend_cycle);
     /// ```
+    pub fn schaff_trend_cycle(
+        prices: &[f64],
+        short_period: usize,
+        long_period: usize,
+        cycle_period: usize,
+        smoothing_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_positive_usize("cycle_period", cycle_period)?;
+        assert_positive_usize("smoothing_period", smoothing_period)?;
+        assert_period(short_period, long_period)?;
+        assert_min_length(
+            "prices",
+            long_period + 2 * cycle_period + 2 * smoothing_period - 4,
+            prices.len(),
+        )?;
+        let macds = macd_line(
+            prices,
+            short_period,
+            &constant_model_type,
+            long_period,
+            &constant_model_type,
+        )?;
+        let first_stochastic = carried_stochastic(&macds, cycle_period);
+        let first_smoothed = smooth(&first_stochastic, smoothing_period, &constant_model_type)?;
+        let second_stochastic = carried_stochastic(&first_smoothed, cycle_period);
+        smooth(&second_stochastic, smoothing_period, &constant_model_type)
+    }
+
+    /// Rolling central value of `values` over `period`.
     #[inline]
-    pub fn chande_momentum_oscillator(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
-        let length = prices.len();
-        assert_non_empty("prices", prices)?;
-        assert_period(period, length)?;
+    fn smooth(
+        values: &[f64],
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_period(period, values.len())?;
+        values
+            .windows(period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect()
+    }
 
-        let mut cmos = Vec::with_capacity(length - period + 1);
-        for window in prices.windows(period) {
-            cmos.push(single::chande_momentum_oscillator(window)?);
-        }
-        Ok(cmos)
+    /// Rate of change of each price against the price `period` before it.
+    #[inline]
+    fn rate_of_change_over(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_min_length("prices", period + 1, prices.len())?;
+        (period..prices.len())
+            .map(|i| single::rate_of_change(prices[i], prices[i - period]))
+            .collect()
+    }
+
+    /// Rolling stochastic of `values` that keeps the previous value when a window is flat.
+    fn carried_stochastic(values: &[f64], period: usize) -> Vec<f64> {
+        let mut previous = 0.0;
+        values
+            .windows(period)
+            .map(|window| {
+                let min = window.iter().copied().fold(f64::INFINITY, f64::min);
+                let max = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                if max > min {
+                    previous = 100.0 * (window[period - 1] - min) / (max - min);
+                }
+                previous
+            })
+            .collect()
     }
 }
 
@@ -2372,8 +3276,11 @@ mod tests {
         let prices = vec![100.2, 100.46, 100.53, 100.38, 100.19];
         assert_eq!(
             0.0,
-            single::relative_strength_index(&prices, crate::ConstantModelType::SimpleMovingMode)
-                .unwrap()
+            single::relative_strength_index(
+                &prices,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
+            )
+            .unwrap()
         );
     }
 
@@ -2382,8 +3289,11 @@ mod tests {
         let prices = vec![100.0, 103.0, 106.0, 107.0, 108.0, 105.0, 102.0];
         assert_eq!(
             39.99999999999999,
-            single::relative_strength_index(&prices, crate::ConstantModelType::SimpleMovingMode)
-                .unwrap()
+            single::relative_strength_index(
+                &prices,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
+            )
+            .unwrap()
         );
     }
 
@@ -2580,7 +3490,7 @@ mod tests {
             vec![0.0, 0.0, 0.0, 0.0],
             bulk::relative_strength_index(
                 &prices,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 period
             )
             .unwrap()
@@ -2718,16 +3628,21 @@ mod tests {
         let stochastics = vec![0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394];
         assert_eq!(
             22.75,
-            single::slow_stochastic(&stochastics, crate::ConstantModelType::SimpleMovingMode)
-                .unwrap()
+            single::slow_stochastic(
+                &stochastics,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
+            )
+            .unwrap()
         );
     }
 
     #[test]
     fn single_slow_stochastic_error() {
         let stochastics = Vec::new();
-        let result =
-            single::slow_stochastic(&stochastics, crate::ConstantModelType::SimpleMovingMode);
+        let result = single::slow_stochastic(
+            &stochastics,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
+        );
         assert!(result.is_err());
     }
 
@@ -2814,10 +3729,10 @@ mod tests {
         let stochastics = vec![0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394];
         let period: usize = 3;
         assert_eq!(
-            vec![14.666666666666666, 30.3333333333333332],
+            vec![14.666666666666666, 30.333333333333332],
             bulk::slow_stochastic(
                 &stochastics,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 period
             )
             .unwrap()
@@ -2830,7 +3745,7 @@ mod tests {
         let period: usize = 30;
         bulk::slow_stochastic(
             &stochastics,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             period,
         )
         .unwrap_err();
@@ -2903,16 +3818,21 @@ mod tests {
         let stochastics = vec![0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394];
         assert_eq!(
             22.75,
-            single::slowest_stochastic(&stochastics, crate::ConstantModelType::SimpleMovingMode)
-                .unwrap()
+            single::slowest_stochastic(
+                &stochastics,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
+            )
+            .unwrap()
         );
     }
 
     #[test]
     fn single_slowest_stochastic_error() {
         let stochastics = Vec::new();
-        let result =
-            single::slowest_stochastic(&stochastics, crate::ConstantModelType::SimpleMovingMode);
+        let result = single::slowest_stochastic(
+            &stochastics,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
+        );
         assert!(result.is_err());
     }
 
@@ -2999,10 +3919,10 @@ mod tests {
         let stochastics = vec![0.0, 5.882352941175241, 38.23529411764534, 47.36842105263394];
         let period: usize = 3;
         assert_eq!(
-            vec![14.666666666666666, 30.3333333333333332],
+            vec![14.666666666666666, 30.333333333333332],
             bulk::slowest_stochastic(
                 &stochastics,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 period
             )
             .unwrap()
@@ -3015,7 +3935,7 @@ mod tests {
         let period: usize = 30;
         bulk::slowest_stochastic(
             &stochastics,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             period,
         )
         .unwrap_err();
@@ -3370,7 +4290,7 @@ mod tests {
             single::commodity_channel_index(
                 &prices,
                 crate::ConstantModelType::SimpleMovingAverage,
-                crate::DeviationModel::ModeAbsoluteDeviation,
+                crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
                 0.015
             )
             .unwrap()
@@ -3476,7 +4396,7 @@ mod tests {
             113.63636363636031,
             single::commodity_channel_index(
                 &prices,
-                crate::ConstantModelType::SimpleMovingMode,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
                 crate::DeviationModel::MeanAbsoluteDeviation,
                 0.015
             )
@@ -3566,7 +4486,7 @@ mod tests {
             single::mcginley_dynamic_commodity_channel_index(
                 &prices,
                 100.21,
-                crate::DeviationModel::ModeAbsoluteDeviation,
+                crate::DeviationModel::ModeAbsoluteDeviation { precision: 1.0 },
                 0.015
             )
             .unwrap()
@@ -3756,8 +4676,8 @@ mod tests {
             single::macd_line(
                 &prices,
                 3_usize,
-                crate::ConstantModelType::SimpleMovingMode,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -3915,7 +4835,11 @@ mod tests {
         ];
         assert_eq!(
             0.0,
-            single::signal_line(&macds, crate::ConstantModelType::SimpleMovingMode).unwrap()
+            single::signal_line(
+                &macds,
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
+            )
+            .unwrap()
         );
     }
 
@@ -4148,8 +5072,8 @@ mod tests {
                 &volume,
                 3_usize,
                 0.0,
-                crate::ConstantModelType::SimpleMovingMode,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -4548,7 +5472,7 @@ mod tests {
             single::percentage_price_oscillator(
                 &prices,
                 3_usize,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -4560,7 +5484,7 @@ mod tests {
         let result = single::percentage_price_oscillator(
             &prices,
             30_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -4571,7 +5495,7 @@ mod tests {
         let result = single::percentage_price_oscillator(
             &prices,
             0_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -4752,4 +5676,388 @@ mod tests {
         );
         assert_eq!(50.0, cci.unwrap());
     }
+
+    #[test]
+    fn single_stochastic_rsi() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+        assert_eq!(
+            33.33333333333333,
+            single::stochastic_rsi(&prices, crate::ConstantModelType::SimpleMovingAverage, 3)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_stochastic_rsi_matches_single() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+        let model = crate::ConstantModelType::ExponentialMovingAverage;
+        let result = bulk::stochastic_rsi(&prices, model, 3, 4).unwrap();
+        assert_eq!(2, result.len());
+        assert_eq!(
+            single::stochastic_rsi(&prices[1..], model, 3).unwrap(),
+            result[1]
+        );
+    }
+
+    #[test]
+    fn bulk_stochastic_rsi_period_too_long() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0];
+        assert!(
+            bulk::stochastic_rsi(&prices, crate::ConstantModelType::SimpleMovingAverage, 3, 3)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn single_ultimate_oscillator_bounds() {
+        let highs = vec![101.0, 103.0, 104.0, 105.0];
+        let lows = vec![99.0, 100.0, 102.0, 103.0];
+        let close = vec![100.0, 103.0, 104.0, 105.0];
+        assert_eq!(
+            100.0,
+            single::ultimate_oscillator(&highs, &lows, &close, 1, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_ultimate_oscillator_invalid_periods() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+        let close = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+        assert!(single::ultimate_oscillator(&highs, &lows, &close, 3, 2).is_err());
+        assert!(single::ultimate_oscillator(&highs, &lows, &close, 2, 5).is_err());
+        assert!(single::ultimate_oscillator(&highs, &lows[1..], &close, 1, 2).is_err());
+    }
+
+    #[test]
+    fn bulk_ultimate_oscillator() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+        let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+        assert_eq!(
+            vec![35.064935064935064, 66.3265306122449],
+            bulk::ultimate_oscillator(&highs, &lows, &close, 1, 2, 4).unwrap()
+        );
+        assert!(bulk::ultimate_oscillator(&highs, &lows, &close, 1, 2, 6).is_err());
+    }
+
+    #[test]
+    fn single_awesome_oscillator_median() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+        assert_eq!(
+            1.5,
+            single::awesome_oscillator(
+                &highs,
+                &lows,
+                2,
+                crate::ConstantModelType::SimpleMovingMedian
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_awesome_oscillator() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+        assert_eq!(
+            vec![0.75, 0.25, 0.0],
+            bulk::awesome_oscillator(
+                &highs,
+                &lows,
+                2,
+                3,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+        assert!(bulk::awesome_oscillator(
+            &highs,
+            &lows,
+            4,
+            3,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_accelerator_oscillator() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+        assert_eq!(
+            -0.3333333333333333,
+            single::accelerator_oscillator(
+                &highs,
+                &lows,
+                2,
+                3,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_accelerator_oscillator() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 106.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 103.0];
+        assert_eq!(
+            vec![-0.25, -0.125],
+            bulk::accelerator_oscillator(
+                &highs,
+                &lows,
+                2,
+                3,
+                2,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+        assert!(bulk::accelerator_oscillator(
+            &highs,
+            &lows,
+            2,
+            3,
+            4,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_connors_rsi() {
+        // Price RSI 33.33, streak RSI of [-2, 1] 100, percent rank 3 / 4 = 75
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0];
+        assert_eq!(
+            69.44444444444444,
+            single::connors_rsi(&prices, crate::ConstantModelType::SimpleMovingAverage, 3, 2)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn single_connors_rsi_too_short() {
+        let prices = vec![100.0, 102.0];
+        assert!(
+            single::connors_rsi(&prices, crate::ConstantModelType::SimpleMovingAverage, 2, 2)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn bulk_connors_rsi() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+        assert_eq!(
+            vec![69.44444444444444, 100.0],
+            bulk::connors_rsi(
+                &prices,
+                crate::ConstantModelType::SimpleMovingAverage,
+                3,
+                2,
+                4
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_connors_rsi_keeps_streak_history() {
+        // The last streak RSI sees [2, 3, -1], not the [0, 1, -1] of a fresh 3 price window
+        let prices = vec![100.0, 101.0, 102.0, 103.0, 102.0];
+        assert_eq!(
+            vec![66.66666666666667, 66.66666666666667, 23.333333333333332],
+            bulk::connors_rsi(
+                &prices,
+                crate::ConstantModelType::SimpleMovingAverage,
+                3,
+                3,
+                1
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_connors_rsi_long_rsi_period() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+        assert_eq!(
+            vec![80.95238095238095],
+            bulk::connors_rsi(
+                &prices,
+                crate::ConstantModelType::SimpleMovingAverage,
+                7,
+                2,
+                2
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_connors_rsi_too_short() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+        assert!(bulk::connors_rsi(
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            3,
+            2,
+            4
+        )
+        .is_err());
+        assert!(bulk::connors_rsi(
+            &prices,
+            crate::ConstantModelType::SimpleMovingAverage,
+            3,
+            0,
+            2
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_trix() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+        assert_eq!(
+            vec![
+                -0.3676470588235294,
+                -1.2300123001230012,
+                -0.37359900373599003
+            ],
+            bulk::trix(&prices, 2, crate::ConstantModelType::SimpleMovingAverage).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_trix_matches_bulk_last() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0, 104.0];
+        let model = crate::ConstantModelType::ExponentialMovingAverage;
+        assert_eq!(
+            *bulk::trix(&prices, 2, model).unwrap().last().unwrap(),
+            single::trix(&prices[3..], 2, model).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_trix_too_short() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0];
+        assert!(bulk::trix(&prices, 2, crate::ConstantModelType::SimpleMovingAverage).is_err());
+        assert!(bulk::trix(&prices, 0, crate::ConstantModelType::SimpleMovingAverage).is_err());
+    }
+
+    #[test]
+    fn bulk_know_sure_thing_constant_growth() {
+        // Every rate of change is 10% so KST = 10 * (1 + 2 + 3 + 4)
+        let prices: Vec<f64> = (0..8).map(|i| 100.0 * 1.1_f64.powi(i)).collect();
+        let kst = bulk::know_sure_thing(
+            &prices,
+            [1, 1, 1, 1],
+            [2, 2, 2, 2],
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(6, kst.len());
+        for value in kst {
+            assert!((value - 100.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn bulk_know_sure_thing_errors() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::know_sure_thing(&prices, [1, 2, 3, 4], [2, 2, 2, 2], model).is_err());
+        assert!(bulk::know_sure_thing(&prices, [1, 0, 1, 1], [1, 1, 1, 1], model).is_err());
+    }
+
+    #[test]
+    fn bulk_coppock_curve() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0];
+        assert_eq!(
+            vec![-3.402531886541024, -5.363695992671797, 0.05884083553986463],
+            bulk::coppock_curve(
+                &prices,
+                3,
+                2,
+                2,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+        assert!(bulk::coppock_curve(
+            &prices,
+            6,
+            2,
+            2,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_detrended_price_oscillator_median() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+        assert_eq!(
+            1.0,
+            single::detrended_price_oscillator(
+                &prices,
+                crate::ConstantModelType::SimpleMovingMedian
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_detrended_price_oscillator_period_error() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::detrended_price_oscillator(&prices, 2, model).is_err());
+        assert!(bulk::detrended_price_oscillator(&prices, 6, model).is_err());
+    }
+
+    #[test]
+    fn bulk_schaff_trend_cycle_bounded() {
+        let prices = vec![
+            100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0, 104.0, 103.0, 105.0, 106.0, 104.0,
+            101.0, 100.0, 102.0,
+        ];
+        let stc = bulk::schaff_trend_cycle(
+            &prices,
+            2,
+            3,
+            3,
+            2,
+            crate::ConstantModelType::ExponentialMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(7, stc.len());
+        assert!(stc.iter().all(|value| (0.0..=100.0).contains(value)));
+    }
+
+    #[test]
+    fn bulk_schaff_trend_cycle_flat_keeps_previous() {
+        let prices = vec![100.0; 10];
+        assert_eq!(
+            vec![0.0, 0.0],
+            bulk::schaff_trend_cycle(
+                &prices,
+                2,
+                3,
+                3,
+                2,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_schaff_trend_cycle_errors() {
+        let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0, 100.0, 102.0, 104.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::schaff_trend_cycle(&prices, 2, 3, 3, 2, model).is_err());
+        assert!(bulk::schaff_trend_cycle(&prices, 3, 2, 2, 2, model).is_err());
+        assert!(bulk::schaff_trend_cycle(&prices, 2, 3, 0, 2, model).is_err());
+    }
 }
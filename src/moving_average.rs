@@ -385,7 +385,7 @@ mod tests {
         let simple_ma =
             bulk::moving_average(&prices, crate::MovingAverageType::Simple, period).unwrap();
         assert_eq!(
-            vec![100.39666666666666, 100.456666666666666, 100.36666666666667],
+            vec![100.39666666666666, 100.45666666666666, 100.36666666666667],
             simple_ma
         );
     }
@@ -36,10 +36,8 @@
 
 /// **single**: Functions that return a single value for a slice of prices.
 pub mod single {
-    use crate::basic_indicators::single::{median, mode};
-    use crate::moving_average::single::moving_average;
-    use crate::validation::{assert_non_empty, assert_same_len, unsupported_type};
-    use crate::{ConstantModelType, MovingAverageType};
+    use crate::validation::{assert_non_empty, assert_same_len};
+    use crate::CentralTendency;
 
     /// Calculates the final value and percentage return of a investment
     ///
@@ -143,7 +141,7 @@ pub mod single {
     /// * `close` - Slice of previous closes prices.
     /// * `highs` - Slice of highs
     /// * `lows` - Slice of lows
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -181,7 +179,7 @@ pub mod single {
         close: &[f64],
         highs: &[f64],
         lows: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
         assert_non_empty("close", close)?;
@@ -193,30 +191,7 @@ pub mod single {
             .map(|((c, h), l)| true_range(*c, *h, *l))
             .collect();
 
-        match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(&trs, MovingAverageType::Simple)
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(&trs, MovingAverageType::Smoothed)
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(&trs, MovingAverageType::Exponential)
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                &trs,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-            ),
-            ConstantModelType::SimpleMovingMedian => median(&trs),
-            ConstantModelType::SimpleMovingMode => mode(&trs),
-            _ => Err(unsupported_type("ConstantModelType")),
-        }
+        constant_model_type.central_value(&trs)
     }
 
     /// Calculates the internal bar strength
@@ -254,11 +229,9 @@ pub mod single {
 
 /// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
 pub mod bulk {
-    use crate::basic_indicators::bulk::{median, mode};
-    use crate::moving_average::bulk::moving_average;
     use crate::other_indicators::single;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
-    use crate::{ConstantModelType, MovingAverageType};
+    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
+    use crate::CentralTendency;
 
     /// Calculates the return on investment and percent return
     ///
@@ -360,7 +333,7 @@ pub mod bulk {
     /// * `close` - Slice of previous closes
     /// * `highs` - Slice of highs
     /// * `lows` - Slice of lows
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `period` - Period over which to calculate the ATR
     ///
     /// # Returns
@@ -405,7 +378,7 @@ pub mod bulk {
         close: &[f64],
         highs: &[f64],
         lows: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         period: usize,
     ) -> crate::Result<Vec<f64>> {
         let length = close.len();
@@ -419,7 +392,7 @@ pub mod bulk {
                     &close[i..i + period],
                     &highs[i..i + period],
                     &lows[i..i + period],
-                    constant_model_type,
+                    &constant_model_type,
                 )
             })
             .collect()
@@ -484,7 +457,7 @@ pub mod bulk {
     /// * `open` - Slice of opening prices
     /// * `previous_close` - Slice of closing prices
     /// * `signal_period` - Period yp calculate the signal
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -522,7 +495,7 @@ pub mod bulk {
         open: &[f64],
         previous_close: &[f64],
         signal_period: usize,
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<(f64, f64)>> {
         let length = open.len();
         assert_same_len(&[("open", open), ("previous_close", previous_close)])?;
@@ -533,31 +506,10 @@ pub mod bulk {
             .map(|i| ((open[i] - previous_close[i]) / previous_close[i]) * 100.0)
             .collect();
 
-        let signal_line = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(&pis, MovingAverageType::Simple, signal_period)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(&pis, MovingAverageType::Smoothed, signal_period)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(&pis, MovingAverageType::Exponential, signal_period)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                &pis,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-                signal_period,
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(&pis, signal_period)?,
-            ConstantModelType::SimpleMovingMode => mode(&pis, signal_period)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let signal_line = pis
+            .windows(signal_period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect::<crate::Result<Vec<f64>>>()?;
 
         Ok(signal_line
             .iter()
@@ -767,7 +719,7 @@ mod tests {
                 &close,
                 &high,
                 &low,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -782,7 +734,7 @@ mod tests {
             &close,
             &high,
             &low,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -796,7 +748,7 @@ mod tests {
             &close,
             &high,
             &low,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -810,7 +762,7 @@ mod tests {
             &close,
             &high,
             &low,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -824,7 +776,7 @@ mod tests {
             &close,
             &high,
             &low,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -1111,7 +1063,7 @@ mod tests {
                 &open,
                 &previous_close,
                 signal_period,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -1126,7 +1078,7 @@ mod tests {
             &open,
             &previous_close,
             signal_period,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -1140,7 +1092,7 @@ mod tests {
             &open,
             &previous_close,
             signal_period,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -1154,7 +1106,7 @@ mod tests {
             &open,
             &previous_close,
             signal_period,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         )
         .is_err());
     }
@@ -0,0 +1,764 @@
+//! # Pairs Trading
+//!
+//! The `pairs` module provides the building blocks of a statistical arbitrage
+//! workflow between two assets: hedge ratios, spreads, spread z-scores,
+//! cointegration testing and the half-life of mean reversion.
+//!
+//! ## When to Use
+//! Use pairs functions when you want to:
+//! - Estimate how much of one asset hedges another
+//! - Build a spread and measure how stretched it is
+//! - Test whether two assets are cointegrated before trading them as a pair
+//! - Estimate how long a spread takes to mean revert
+//!
+//! ## Structure
+//! - **single**: Functions that return a single value for a slice of prices.
+//! - **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`half_life`](bulk::half_life): Rolling half-life of mean reversion
+//! - [`hedge_ratio`](bulk::hedge_ratio): Rolling OLS hedge ratio and intercept
+//! - [`spread`](bulk::spread): Spread between two assets for a hedge ratio
+//! - [`spread_z_score`](bulk::spread_z_score): Rolling z-score of a spread
+//!
+//! ### Single
+//! - [`augmented_dickey_fuller`](single::augmented_dickey_fuller): ADF unit root test
+//! - [`engle_granger`](single::engle_granger): Engle-Granger cointegration test
+//! - [`half_life`](single::half_life): Half-life of mean reversion
+//! - [`hedge_ratio`](single::hedge_ratio): OLS hedge ratio and intercept
+//! - [`spread_z_score`](single::spread_z_score): Z-score of the latest spread value
+//!
+//! ## API Details
+//! - Hedge ratios regress `prices_asset_a` on `prices_asset_b`: `a = intercept + hedge_ratio * b`.
+//! - Critical values follow MacKinnon (2010) response surfaces for a model with a constant.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+/// Result of an Augmented Dickey-Fuller unit root test.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UnitRootTest {
+    /// t-statistic of the lagged level coefficient
+    pub statistic: f64,
+    /// MacKinnon critical values at the 1%, 5% and 10% significance levels
+    pub critical_values: [f64; 3],
+}
+
+/// Result of an Engle-Granger cointegration test.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CointegrationTest {
+    /// OLS hedge ratio of asset a on asset b
+    pub hedge_ratio: f64,
+    /// OLS intercept of asset a on asset b
+    pub intercept: f64,
+    /// Unit root test of the regression residuals (the spread)
+    pub unit_root: UnitRootTest,
+}
+
+/// **single**: Functions that return a single value for a slice of prices
+pub mod single {
+    use crate::basic_indicators::single::{mean, variance};
+    use crate::linear_algebra::ordinary_least_squares;
+    use crate::pairs::{CointegrationTest, UnitRootTest};
+    use crate::validation::{assert_min_length, assert_non_empty, assert_same_len};
+    use crate::{CentralTendency, Deviation, TechnicalIndicatorError};
+
+    /// MacKinnon (2010) response surface for one variable with a constant
+    const ADF_CRITICAL_VALUES: [[f64; 4]; 3] = [
+        [-3.43035, -6.5393, -16.786, -79.433],
+        [-2.86154, -2.8903, -4.234, -40.040],
+        [-2.56677, -1.5384, -2.809, 0.0],
+    ];
+
+    /// MacKinnon (2010) response surface for two variables with a constant
+    const ENGLE_GRANGER_CRITICAL_VALUES: [[f64; 4]; 3] = [
+        [-3.89644, -10.9519, -22.527, 0.0],
+        [-3.33613, -6.1101, -6.823, 0.0],
+        [-3.04445, -4.2412, -2.720, 0.0],
+    ];
+
+    /// Calculates the OLS hedge ratio of asset a on asset b.
+    ///
+    /// Fits `a = intercept + hedge_ratio * b` by ordinary least squares.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (hedge ratio, intercept)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    /// * Fewer than 2 prices are provided
+    /// * `prices_asset_b` is constant
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_a = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let prices_b = vec![50.0, 51.0, 51.5, 50.5, 49.5];
+    ///
+    /// let hedge_ratio =
+    ///     centaur_technical_indicators::pairs::single::hedge_ratio(&prices_a, &prices_b).unwrap();
+    /// assert_eq!((2.0, 0.0), hedge_ratio);
+    /// ```
+    pub fn hedge_ratio(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+    ) -> crate::Result<(f64, f64)> {
+        assert_same_len(&[
+            ("prices_asset_a", prices_asset_a),
+            ("prices_asset_b", prices_asset_b),
+        ])?;
+        assert_min_length("prices_asset_a", 2, prices_asset_a.len())?;
+
+        let mean_a = mean(prices_asset_a)?;
+        let mean_b = mean(prices_asset_b)?;
+        let variance_b = variance(prices_asset_b)?;
+        if variance_b == 0.0 {
+            return Err(TechnicalIndicatorError::InvalidValue {
+                name: "prices_asset_b variance".to_string(),
+                value: variance_b,
+                reason: "prices_asset_b cannot be constant".to_string(),
+            });
+        }
+        let covariance = prices_asset_a
+            .iter()
+            .zip(prices_asset_b)
+            .map(|(a, b)| (a - mean_a) * (b - mean_b))
+            .sum::<f64>()
+            / prices_asset_a.len() as f64;
+        let hedge_ratio = covariance / variance_b;
+        Ok((hedge_ratio, mean_a - hedge_ratio * mean_b))
+    }
+
+    /// Calculates the z-score of the latest value of a spread.
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - Slice of spread values
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    ///
+    /// # Returns
+    ///
+    /// The distance of the last spread value from its center, in deviations
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `spread.is_empty()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let spread = vec![0.5, -0.2, 0.1, -0.4, 1.0];
+    ///
+    /// let z_score = centaur_technical_indicators::pairs::single::spread_z_score(
+    ///     &spread,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     centaur_technical_indicators::DeviationModel::StandardDeviation
+    /// ).unwrap();
+    /// assert_eq!(1.5936381457791913, z_score);
+    /// ```
+    pub fn spread_z_score(
+        spread: &[f64],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+    ) -> crate::Result<f64> {
+        assert_non_empty("spread", spread)?;
+        let center = constant_model_type.central_value(spread)?;
+        let deviation = deviation_model.deviation(spread)?;
+        Ok((spread[spread.len() - 1] - center) / deviation)
+    }
+
+    /// Runs an Augmented Dickey-Fuller test with a constant.
+    ///
+    /// Regresses the first difference of the series on a constant, the lagged
+    /// level and `lags` lagged differences. A statistic below the critical
+    /// value rejects the unit root, meaning the series is mean reverting.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices (or spread values)
+    /// * `lags` - Number of lagged differences to include
+    ///
+    /// # Returns
+    ///
+    /// A [`UnitRootTest`] with the ADF statistic and MacKinnon critical values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.len()` < `2 * lags + 4`
+    /// * The regressors are collinear (e.g. constant prices)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![
+    ///     1.0, -0.8, 0.6, -0.9, 0.7, -0.5, 0.8, -1.0, 0.4, -0.6, 0.9, -0.7,
+    /// ];
+    ///
+    /// let adf = centaur_technical_indicators::pairs::single::augmented_dickey_fuller(
+    ///     &prices,
+    ///     1
+    /// ).unwrap();
+    /// assert_eq!(-1.9934341360471894, adf.statistic);
+    /// assert_eq!([-4.331573, -3.2329499999999998, -2.7487000000000004], adf.critical_values);
+    /// ```
+    pub fn augmented_dickey_fuller(prices: &[f64], lags: usize) -> crate::Result<UnitRootTest> {
+        let (statistic, observations) = dickey_fuller(prices, lags, true)?;
+        Ok(UnitRootTest {
+            statistic,
+            critical_values: critical_values(&ADF_CRITICAL_VALUES, observations),
+        })
+    }
+
+    /// Runs the Engle-Granger two step cointegration test.
+    ///
+    /// Asset a is regressed on asset b, then the residuals (the spread) are
+    /// tested for a unit root with an Augmented Dickey-Fuller regression.
+    /// Critical values account for the estimated hedge ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    /// * `lags` - Number of lagged differences in the ADF regression
+    ///
+    /// # Returns
+    ///
+    /// A [`CointegrationTest`] with the hedge ratio, intercept and residual unit root test
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    /// * `prices_asset_a.len()` < `2 * lags + 4`
+    /// * `prices_asset_b` is constant
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_b = vec![
+    ///     50.0, 50.5, 51.2, 51.0, 51.8, 52.5, 52.1, 53.0, 53.6, 53.2, 54.1, 54.8,
+    /// ];
+    /// let noise = vec![
+    ///     0.3, -0.2, 0.1, -0.3, 0.2, -0.1, 0.3, -0.2, 0.1, -0.3, 0.2, -0.1,
+    /// ];
+    /// let prices_a: Vec<f64> = prices_b.iter().zip(&noise).map(|(b, e)| 2.0 * b + 5.0 + e).collect();
+    ///
+    /// let test = centaur_technical_indicators::pairs::single::engle_granger(
+    ///     &prices_a,
+    ///     &prices_b,
+    ///     0
+    /// ).unwrap();
+    /// assert_eq!(1.9834960803190762, test.hedge_ratio);
+    /// assert!(test.unit_root.statistic < test.unit_root.critical_values[0]);
+    /// ```
+    pub fn engle_granger(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+        lags: usize,
+    ) -> crate::Result<CointegrationTest> {
+        let (hedge_ratio, intercept) = hedge_ratio(prices_asset_a, prices_asset_b)?;
+        let residuals: Vec<f64> = prices_asset_a
+            .iter()
+            .zip(prices_asset_b)
+            .map(|(a, b)| a - intercept - hedge_ratio * b)
+            .collect();
+        let (statistic, observations) = dickey_fuller(&residuals, lags, false)?;
+        Ok(CointegrationTest {
+            hedge_ratio,
+            intercept,
+            unit_root: UnitRootTest {
+                statistic,
+                critical_values: critical_values(&ENGLE_GRANGER_CRITICAL_VALUES, observations),
+            },
+        })
+    }
+
+    /// Calculates the half-life of mean reversion of a spread.
+    ///
+    /// Fits `spread[t] - spread[t-1] = c + lambda * spread[t-1]` and returns
+    /// `-ln(2) / lambda`, the number of observations for a deviation to halve.
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - Slice of spread values
+    ///
+    /// # Returns
+    ///
+    /// The half-life in observations, or `f64::INFINITY` if the spread does not mean revert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `spread.len()` < 3
+    /// * `spread` is constant
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let spread = vec![1.0, 0.5, 0.25, 0.125, 0.0625];
+    ///
+    /// let half_life = centaur_technical_indicators::pairs::single::half_life(&spread).unwrap();
+    /// assert_eq!(1.3862943611198906, half_life);
+    /// ```
+    pub fn half_life(spread: &[f64]) -> crate::Result<f64> {
+        assert_min_length("spread", 3, spread.len())?;
+        let differences: Vec<f64> = spread.windows(2).map(|w| w[1] - w[0]).collect();
+        let (lambda, _) = hedge_ratio(&differences, &spread[..spread.len() - 1])?;
+        if lambda >= 0.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(-std::f64::consts::LN_2 / lambda)
+    }
+
+    /// Dickey-Fuller regression statistic and number of observations used
+    fn dickey_fuller(prices: &[f64], lags: usize, constant: bool) -> crate::Result<(f64, usize)> {
+        assert_min_length("prices", 2 * lags + 4, prices.len())?;
+        let differences: Vec<f64> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let (regressors, targets): (Vec<Vec<f64>>, Vec<f64>) = (lags..differences.len())
+            .map(|t| {
+                let mut row = Vec::with_capacity(lags + 2);
+                if constant {
+                    row.push(1.0);
+                }
+                row.push(prices[t]);
+                row.extend((1..=lags).map(|lag| differences[t - lag]));
+                (row, differences[t])
+            })
+            .unzip();
+        let observations = targets.len();
+        let (coefficients, standard_errors) = ordinary_least_squares(&regressors, &targets)
+            .ok_or_else(|| TechnicalIndicatorError::Custom {
+                message: "Dickey-Fuller regressors are collinear".to_string(),
+            })?;
+        let level = usize::from(constant);
+        Ok((coefficients[level] / standard_errors[level], observations))
+    }
+
+    /// Evaluates a MacKinnon response surface at the 1%, 5% and 10% levels
+    fn critical_values(surface: &[[f64; 4]; 3], observations: usize) -> [f64; 3] {
+        let t = observations as f64;
+        surface.map(|b| b[0] + b[1] / t + b[2] / t.powi(2) + b[3] / t.powi(3))
+    }
+}
+
+/// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
+pub mod bulk {
+    use crate::pairs::single;
+    use crate::validation::{assert_non_empty, assert_period, assert_same_len};
+    use crate::{CentralTendency, Deviation};
+
+    /// Calculates the rolling OLS hedge ratio of asset a on asset b
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    /// * `period` - Period over which to calculate the hedge ratio
+    ///
+    /// # Returns
+    ///
+    /// A vector of (hedge ratio, intercept) tuples
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    /// * `period` < 2 or `period` > `prices_asset_a.len()`
+    /// * `prices_asset_b` is constant over a window
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_a = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+    /// let prices_b = vec![50.0, 51.0, 51.5, 50.5, 49.0];
+    ///
+    /// let hedge_ratio =
+    ///     centaur_technical_indicators::pairs::bulk::hedge_ratio(&prices_a, &prices_b, 4).unwrap();
+    /// assert_eq!(vec![(2.0, 0.0), (1.5714285714285714, 21.89285714285714)], hedge_ratio);
+    /// ```
+    #[inline]
+    pub fn hedge_ratio(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        assert_same_len(&[
+            ("prices_asset_a", prices_asset_a),
+            ("prices_asset_b", prices_asset_b),
+        ])?;
+        assert_period(period, prices_asset_a.len())?;
+        prices_asset_a
+            .windows(period)
+            .zip(prices_asset_b.windows(period))
+            .map(|(a, b)| single::hedge_ratio(a, b))
+            .collect()
+    }
+
+    /// Calculates the spread between two assets for a hedge ratio
+    ///
+    /// # Arguments
+    ///
+    /// * `prices_asset_a` - Slice of prices of the dependent asset
+    /// * `prices_asset_b` - Slice of prices of the hedging asset
+    /// * `hedge_ratio` - Units of asset b per unit of asset a
+    /// * `intercept` - Constant removed from the spread (0.0 for none)
+    ///
+    /// # Returns
+    ///
+    /// A vector of `a - intercept - hedge_ratio * b`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices_asset_a.is_empty()`
+    /// * `prices_asset_a.len()` != `prices_asset_b.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices_a = vec![100.0, 102.0, 103.0];
+    /// let prices_b = vec![50.0, 51.0, 51.0];
+    ///
+    /// let spread =
+    ///     centaur_technical_indicators::pairs::bulk::spread(&prices_a, &prices_b, 2.0, 0.0).unwrap();
+    /// assert_eq!(vec![0.0, 0.0, 1.0], spread);
+    /// ```
+    #[inline]
+    pub fn spread(
+        prices_asset_a: &[f64],
+        prices_asset_b: &[f64],
+        hedge_ratio: f64,
+        intercept: f64,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[
+            ("prices_asset_a", prices_asset_a),
+            ("prices_asset_b", prices_asset_b),
+        ])?;
+        assert_non_empty("prices_asset_a", prices_asset_a)?;
+        Ok(prices_asset_a
+            .iter()
+            .zip(prices_asset_b)
+            .map(|(a, b)| a - intercept - hedge_ratio * b)
+            .collect())
+    }
+
+    /// Calculates the rolling z-score of a spread
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - Slice of spread values
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
+    /// * `deviation_model` - Variant of [`DeviationModel`](crate::DeviationModel) or any [`Deviation`]
+    /// * `period` - Period over which to calculate the z-score
+    ///
+    /// # Returns
+    ///
+    /// A vector of z-scores
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` == 0 or `period` > `spread.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let spread = vec![0.5, -0.2, 0.1, -0.4, 1.0, 0.3];
+    ///
+    /// let z_score = centaur_technical_indicators::pairs::bulk::spread_z_score(
+    ///     &spread,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+    ///     centaur_technical_indicators::DeviationModel::StandardDeviation,
+    ///     5
+    /// ).unwrap();
+    /// assert_eq!(vec![1.5936381457791913, 0.2891673346702096], z_score);
+    /// ```
+    #[inline]
+    pub fn spread_z_score(
+        spread: &[f64],
+        constant_model_type: impl CentralTendency,
+        deviation_model: impl Deviation,
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_period(period, spread.len())?;
+        spread
+            .windows(period)
+            .map(|window| single::spread_z_score(window, &constant_model_type, &deviation_model))
+            .collect()
+    }
+
+    /// Calculates the rolling half-life of mean reversion of a spread
+    ///
+    /// # Arguments
+    ///
+    /// * `spread` - Slice of spread values
+    /// * `period` - Period over which to calculate the half-life
+    ///
+    /// # Returns
+    ///
+    /// A vector of half-lives in observations (`f64::INFINITY` where the spread does not mean revert)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 3 or `period` > `spread.len()`
+    /// * `spread` is constant over a window
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let spread = vec![1.0, 0.5, 0.25, 0.125, 0.0625, 0.03125];
+    ///
+    /// let half_life = centaur_technical_indicators::pairs::bulk::half_life(&spread, 5).unwrap();
+    /// assert_eq!(vec![1.3862943611198906, 1.3862943611198906], half_life);
+    /// ```
+    #[inline]
+    pub fn half_life(spread: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_period(period, spread.len())?;
+        spread.windows(period).map(single::half_life).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_hedge_ratio() {
+        let prices_a = vec![100.46, 100.53, 100.38, 100.19, 100.21];
+        let prices_b = vec![74.71, 71.98, 68.33, 63.6, 65.92];
+        assert_eq!(
+            (0.030314333152713525, 98.26509993111281),
+            single::hedge_ratio(&prices_a, &prices_b).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_hedge_ratio_length_error() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 71.98];
+        assert!(single::hedge_ratio(&prices_a, &prices_b).is_err());
+    }
+
+    #[test]
+    fn single_hedge_ratio_constant_error() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 74.71, 74.71];
+        assert!(single::hedge_ratio(&prices_a, &prices_b).is_err());
+    }
+
+    #[test]
+    fn single_hedge_ratio_too_short_error() {
+        assert!(single::hedge_ratio(&[100.0], &[50.0]).is_err());
+    }
+
+    #[test]
+    fn single_spread_z_score() {
+        let spread = vec![0.5, -0.2, 0.1, -0.4, 1.0];
+        assert_eq!(
+            1.5936381457791913,
+            single::spread_z_score(
+                &spread,
+                crate::ConstantModelType::SimpleMovingAverage,
+                crate::DeviationModel::StandardDeviation
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            2.0454545454545454,
+            single::spread_z_score(
+                &spread,
+                crate::ConstantModelType::SimpleMovingMedian,
+                crate::DeviationModel::MeanAbsoluteDeviation
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn single_spread_z_score_error() {
+        assert!(single::spread_z_score(
+            &[],
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_augmented_dickey_fuller_stationary() {
+        let prices = vec![
+            1.0, -0.8, 0.6, -0.9, 0.7, -0.5, 0.8, -1.0, 0.4, -0.6, 0.9, -0.7,
+        ];
+        let adf = single::augmented_dickey_fuller(&prices, 0).unwrap();
+        assert!(adf.statistic < adf.critical_values[0]);
+        assert!(adf.critical_values[0] < adf.critical_values[1]);
+        assert!(adf.critical_values[1] < adf.critical_values[2]);
+    }
+
+    #[test]
+    fn single_augmented_dickey_fuller_trending() {
+        let prices = vec![
+            100.0, 101.2, 102.1, 103.5, 104.2, 105.8, 106.1, 107.9, 108.4, 109.8, 110.5, 111.9,
+        ];
+        let adf = single::augmented_dickey_fuller(&prices, 1).unwrap();
+        assert!(adf.statistic > adf.critical_values[2]);
+    }
+
+    #[test]
+    fn single_augmented_dickey_fuller_length_error() {
+        let prices = vec![1.0, -0.8, 0.6, -0.9, 0.7];
+        assert!(single::augmented_dickey_fuller(&prices, 1).is_err());
+    }
+
+    #[test]
+    fn single_augmented_dickey_fuller_constant_error() {
+        let prices = vec![1.0; 10];
+        assert!(single::augmented_dickey_fuller(&prices, 1).is_err());
+    }
+
+    #[test]
+    fn single_engle_granger_not_cointegrated() {
+        let prices_a = vec![
+            100.0, 101.2, 102.1, 101.5, 103.2, 104.8, 104.1, 106.9, 107.4, 106.8, 108.5, 109.9,
+        ];
+        let prices_b = vec![
+            50.0, 49.5, 50.2, 51.0, 50.8, 50.5, 52.1, 51.0, 51.6, 53.2, 52.1, 51.8,
+        ];
+        let test = single::engle_granger(&prices_a, &prices_b, 0).unwrap();
+        assert!(test.unit_root.statistic > test.unit_root.critical_values[0]);
+    }
+
+    #[test]
+    fn single_engle_granger_lagged_critical_values() {
+        let prices_a = vec![
+            100.0, 101.2, 102.1, 101.5, 103.2, 104.8, 104.1, 106.9, 107.4, 106.8, 108.5, 109.9,
+        ];
+        let prices_b = vec![
+            50.0, 49.5, 50.2, 51.0, 50.8, 50.5, 52.1, 51.0, 51.6, 53.2, 52.1, 51.8,
+        ];
+        // Two lags leave 12 - 1 - 2 = 9 observations in the ADF regression
+        let test = single::engle_granger(&prices_a, &prices_b, 2).unwrap();
+        assert_eq!(
+            [-5.391428888888889, -4.099264567901234, -3.5492746913580246],
+            test.unit_root.critical_values
+        );
+    }
+
+    #[test]
+    fn single_engle_granger_error() {
+        let prices_a = vec![100.0, 101.2, 102.1, 101.5, 103.2];
+        let prices_b = vec![50.0, 49.5, 50.2, 51.0];
+        assert!(single::engle_granger(&prices_a, &prices_b, 0).is_err());
+    }
+
+    #[test]
+    fn single_half_life() {
+        let spread = vec![1.0, 0.5, 0.25, 0.125, 0.0625];
+        assert_eq!(1.3862943611198906, single::half_life(&spread).unwrap());
+    }
+
+    #[test]
+    fn single_half_life_not_mean_reverting() {
+        let spread = vec![1.0, 2.0, 4.0, 8.0, 16.0];
+        assert_eq!(f64::INFINITY, single::half_life(&spread).unwrap());
+    }
+
+    #[test]
+    fn single_half_life_error() {
+        assert!(single::half_life(&[1.0, 0.5]).is_err());
+    }
+
+    #[test]
+    fn bulk_hedge_ratio() {
+        let prices_a = vec![100.46, 100.53, 100.38, 100.19, 100.21];
+        let prices_b = vec![74.71, 71.98, 68.33, 63.6, 65.92];
+        let rolling = bulk::hedge_ratio(&prices_a, &prices_b, 4).unwrap();
+        assert_eq!(
+            vec![
+                single::hedge_ratio(&prices_a[..4], &prices_b[..4]).unwrap(),
+                single::hedge_ratio(&prices_a[1..], &prices_b[1..]).unwrap()
+            ],
+            rolling
+        );
+    }
+
+    #[test]
+    fn bulk_hedge_ratio_period_error() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 71.98, 68.33];
+        assert!(bulk::hedge_ratio(&prices_a, &prices_b, 4).is_err());
+    }
+
+    #[test]
+    fn bulk_spread() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 71.98, 68.33];
+        assert_eq!(
+            vec![26.25, 29.049999999999997, 32.55],
+            bulk::spread(&prices_a, &prices_b, 1.0, -0.5).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_spread_error() {
+        let prices_a = vec![100.46, 100.53, 100.38];
+        let prices_b = vec![74.71, 71.98];
+        assert!(bulk::spread(&prices_a, &prices_b, 1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn bulk_spread_z_score() {
+        let spread = vec![0.5, -0.2, 0.1, -0.4, 1.0, 0.3];
+        assert_eq!(
+            vec![1.5936381457791913, 0.2891673346702096],
+            bulk::spread_z_score(
+                &spread,
+                crate::ConstantModelType::SimpleMovingAverage,
+                crate::DeviationModel::StandardDeviation,
+                5
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_spread_z_score_error() {
+        let spread = vec![0.5, -0.2, 0.1];
+        assert!(bulk::spread_z_score(
+            &spread,
+            crate::ConstantModelType::SimpleMovingAverage,
+            crate::DeviationModel::StandardDeviation,
+            5
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_half_life() {
+        let spread = vec![1.0, 0.5, 0.25, 0.125, 0.0625, 0.03125];
+        assert_eq!(
+            vec![1.3862943611198906, 1.3862943611198906],
+            bulk::half_life(&spread, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_half_life_error() {
+        let spread = vec![1.0, 0.5, 0.25];
+        assert!(bulk::half_life(&spread, 4).is_err());
+    }
+}
@@ -0,0 +1,690 @@
+//! # Regression Indicators
+//!
+//! The `regression_indicators` module fits a least squares line to prices and
+//! exposes the fit as indicators: slope, intercept, R², time series forecast,
+//! standard error bands and regression channels.
+//!
+//! ## When to Use
+//! Use regression indicators when you want to:
+//! - Measure the direction and steepness of a trend over a moving window
+//! - Judge how well a straight line explains recent prices
+//! - Project the recent trend forward (time series forecast, linear regression moving average)
+//! - Build bands around the trend line
+//!
+//! ## Structure
+//! - **single**: Functions that return a single value for a slice of prices.
+//! - **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`slope`](bulk::slope): Rolling regression slope
+//! - [`intercept`](bulk::intercept): Rolling regression intercept
+//! - [`r_squared`](bulk::r_squared): Rolling coefficient of determination
+//! - [`forecast`](bulk::forecast): Rolling time series forecast
+//! - [`standard_error_bands`](bulk::standard_error_bands): Rolling standard error bands
+//! - [`regression_channel`](bulk::regression_channel): Rolling regression channel
+//!
+//! ### Single
+//! - [`slope`](single::slope): Regression slope
+//! - [`intercept`](single::intercept): Regression intercept
+//! - [`r_squared`](single::r_squared): Coefficient of determination
+//! - [`forecast`](single::forecast): Time series forecast
+//! - [`standard_error_bands`](single::standard_error_bands): Standard error bands
+//! - [`regression_channel`](single::regression_channel): Regression channel
+//!
+//! ## API Details
+//! - Prices are regressed on their position in the window, `x = 0..period`, so the intercept is
+//!   the fitted value at the first price of the window (as in
+//!   [`overall_trend`](crate::chart_trends::overall_trend)).
+//! - Bulk slope, intercept, R², forecast and standard error bands update running sums as the
+//!   window moves, so they are O(n) regardless of the period.
+//! - The bulk regression channel rescans each window for the price furthest from its line, so
+//!   it is O(n * period).
+//! - A window of identical prices has an R² of 1.0.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+/// Least squares line fitted to a window of prices, with `x` running from 0 to `n - 1`.
+#[derive(Debug, Copy, Clone)]
+struct Fit {
+    n: usize,
+    slope: f64,
+    intercept: f64,
+    r_squared: f64,
+    /// Sum of squared residuals
+    sse: f64,
+}
+
+impl Fit {
+    /// Builds the fit from the sums of y, x·y and y² over a window of `n` prices, where
+    /// `offset` has been subtracted from every y to limit cancellation in the sums.
+    fn from_sums(n: usize, offset: f64, sum_y: f64, sum_xy: f64, sum_yy: f64) -> Fit {
+        let length = n as f64;
+        let sum_x = length * (length - 1.0) / 2.0;
+        let sum_xx = (length - 1.0) * length * (2.0 * length - 1.0) / 6.0;
+        let s_xx = sum_xx - sum_x * sum_x / length;
+        let s_xy = sum_xy - sum_x * sum_y / length;
+        let mut s_yy = sum_yy - sum_y * sum_y / length;
+        // Running sums leave rounding noise where the prices are flat
+        if s_yy <= f64::EPSILON * sum_yy {
+            s_yy = 0.0;
+        }
+
+        let slope = if s_yy == 0.0 { 0.0 } else { s_xy / s_xx };
+        let intercept = offset + (sum_y - slope * sum_x) / length;
+        let sse = (s_yy - slope * s_xy).max(0.0);
+        let r_squared = if s_yy == 0.0 { 1.0 } else { 1.0 - sse / s_yy };
+        Fit {
+            n,
+            slope,
+            intercept,
+            r_squared,
+            sse,
+        }
+    }
+
+    /// Fitted value `bars_ahead` bars after the last price of the window.
+    fn forecast(&self, bars_ahead: usize) -> f64 {
+        self.intercept + self.slope * (self.n - 1 + bars_ahead) as f64
+    }
+
+    /// Standard error of the estimate.
+    fn standard_error(&self) -> f64 {
+        (self.sse / (self.n - 2) as f64).sqrt()
+    }
+
+    /// Bands of `multiplier` standard errors around the last fitted value.
+    fn standard_error_bands(&self, multiplier: f64) -> (f64, f64, f64) {
+        let end = self.forecast(0);
+        let offset = multiplier * self.standard_error();
+        (end - offset, end, end + offset)
+    }
+
+    /// Channel around the last fitted value, as wide as the furthest price from the line.
+    fn channel(&self, prices: &[f64]) -> (f64, f64, f64) {
+        let end = self.forecast(0);
+        let width = prices
+            .iter()
+            .enumerate()
+            .map(|(x, y)| (y - (self.intercept + self.slope * x as f64)).abs())
+            .fold(0.0, f64::max);
+        (end - width, end, end + width)
+    }
+}
+
+/// Sums of y, x·y and y² over the prices less `offset`.
+fn sums(prices: &[f64], offset: f64) -> (f64, f64, f64) {
+    prices
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0, 0.0), |(sum_y, sum_xy, sum_yy), (x, &y)| {
+            let y = y - offset;
+            (sum_y + y, sum_xy + x as f64 * y, sum_yy + y * y)
+        })
+}
+
+/// Fits a line to all prices.
+fn fit(prices: &[f64]) -> Fit {
+    let offset = prices[0];
+    let (sum_y, sum_xy, sum_yy) = sums(prices, offset);
+    Fit::from_sums(prices.len(), offset, sum_y, sum_xy, sum_yy)
+}
+
+/// Fits a line to every window of `period` prices, sliding the sums along the prices.
+fn rolling_fits(prices: &[f64], period: usize) -> Vec<Fit> {
+    let offset = prices[0];
+    let (mut sum_y, mut sum_xy, mut sum_yy) = sums(&prices[..period], offset);
+    let mut fits = Vec::with_capacity(prices.len() - period + 1);
+    fits.push(Fit::from_sums(period, offset, sum_y, sum_xy, sum_yy));
+    for i in period..prices.len() {
+        let (leaving, entering) = (prices[i - period] - offset, prices[i] - offset);
+        sum_y += entering - leaving;
+        // Every remaining price moves one step closer to the start of the window
+        sum_xy += period as f64 * entering - sum_y;
+        sum_yy += entering * entering - leaving * leaving;
+        fits.push(Fit::from_sums(period, offset, sum_y, sum_xy, sum_yy));
+    }
+    fits
+}
+
+/// **single**: Functions that return a single value for a slice of prices
+pub mod single {
+    use super::fit;
+    use crate::validation::{assert_min_length, assert_positive};
+
+    /// Calculates the slope of the least squares line through the prices
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    ///
+    /// # Returns
+    ///
+    /// Change in the fitted price per bar
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let slope = centaur_technical_indicators::regression_indicators::single::slope(&prices).unwrap();
+    /// assert_eq!(0.7, slope);
+    /// ```
+    #[inline]
+    pub fn slope(prices: &[f64]) -> crate::Result<f64> {
+        assert_min_length("prices", 2, prices.len())?;
+        Ok(fit(prices).slope)
+    }
+
+    /// Calculates the intercept of the least squares line through the prices
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    ///
+    /// # Returns
+    ///
+    /// Fitted value at the first price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let intercept =
+    ///     centaur_technical_indicators::regression_indicators::single::intercept(&prices).unwrap();
+    /// assert_eq!(100.6, intercept);
+    /// ```
+    #[inline]
+    pub fn intercept(prices: &[f64]) -> crate::Result<f64> {
+        assert_min_length("prices", 2, prices.len())?;
+        Ok(fit(prices).intercept)
+    }
+
+    /// Calculates the coefficient of determination (R²) of the least squares line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    ///
+    /// # Returns
+    ///
+    /// Share of the price variance explained by the line, between 0.0 and 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let r_squared =
+    ///     centaur_technical_indicators::regression_indicators::single::r_squared(&prices).unwrap();
+    /// assert_eq!(0.49, r_squared);
+    /// ```
+    #[inline]
+    pub fn r_squared(prices: &[f64]) -> crate::Result<f64> {
+        assert_min_length("prices", 2, prices.len())?;
+        Ok(fit(prices).r_squared)
+    }
+
+    /// Calculates the time series forecast, the least squares line extended past the
+    /// last price
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `bars_ahead` - Number of bars after the last price to project to. 0 gives the
+    ///   linear regression moving average, 1 the standard time series forecast.
+    ///
+    /// # Returns
+    ///
+    /// Fitted value `bars_ahead` bars after the last price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let forecast =
+    ///     centaur_technical_indicators::regression_indicators::single::forecast(&prices, 1).unwrap();
+    /// assert_eq!(104.1, forecast);
+    /// ```
+    #[inline]
+    pub fn forecast(prices: &[f64], bars_ahead: usize) -> crate::Result<f64> {
+        assert_min_length("prices", 2, prices.len())?;
+        Ok(fit(prices).forecast(bars_ahead))
+    }
+
+    /// Calculates standard error bands around the end of the least squares line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `multiplier` - Number of standard errors between the line and each band
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (lower_band, fitted value at the last price, upper_band)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `prices.len()` < 3
+    /// * `multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let bands = centaur_technical_indicators::regression_indicators::single::standard_error_bands(
+    ///     &prices,
+    ///     2.0
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     (100.79231903791893, 103.39999999999999, 106.00768096208105),
+    ///     bands
+    /// );
+    /// ```
+    #[inline]
+    pub fn standard_error_bands(prices: &[f64], multiplier: f64) -> crate::Result<(f64, f64, f64)> {
+        assert_min_length("prices", 3, prices.len())?;
+        assert_positive("multiplier", multiplier)?;
+        Ok(fit(prices).standard_error_bands(multiplier))
+    }
+
+    /// Calculates the regression channel: lines parallel to the least squares line
+    /// through the prices furthest from it
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (lower_channel, fitted value at the last price, upper_channel)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `prices.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let channel =
+    ///     centaur_technical_indicators::regression_indicators::single::regression_channel(
+    ///         &prices
+    ///     ).unwrap();
+    /// assert_eq!((101.7, 103.39999999999999, 105.09999999999998), channel);
+    /// ```
+    #[inline]
+    pub fn regression_channel(prices: &[f64]) -> crate::Result<(f64, f64, f64)> {
+        assert_min_length("prices", 2, prices.len())?;
+        Ok(fit(prices).channel(prices))
+    }
+}
+
+/// **bulk**: Functions that compute values of a slice of prices over a period and return a vector
+pub mod bulk {
+    use super::rolling_fits;
+    use crate::validation::{assert_min_period, assert_positive};
+
+    /// Calculates the rolling slope of the least squares line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    ///
+    /// # Returns
+    ///
+    /// A vector of slopes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 2
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let slope =
+    ///     centaur_technical_indicators::regression_indicators::bulk::slope(&prices, 3).unwrap();
+    /// assert_eq!(vec![1.5, -0.5, 0.5], slope);
+    /// ```
+    #[inline]
+    pub fn slope(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_min_period(period, 2, prices.len())?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .map(|fit| fit.slope)
+            .collect())
+    }
+
+    /// Calculates the rolling intercept of the least squares line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    ///
+    /// # Returns
+    ///
+    /// A vector of intercepts, the fitted value at the first price of each window
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 2
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let intercept =
+    ///     centaur_technical_indicators::regression_indicators::bulk::intercept(&prices, 3).unwrap();
+    /// assert_eq!(
+    ///     vec![100.16666666666667, 102.5, 102.16666666666667],
+    ///     intercept
+    /// );
+    /// ```
+    #[inline]
+    pub fn intercept(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_min_period(period, 2, prices.len())?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .map(|fit| fit.intercept)
+            .collect())
+    }
+
+    /// Calculates the rolling coefficient of determination (R²) of the least squares line
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    ///
+    /// # Returns
+    ///
+    /// A vector of R² values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 2
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let r_squared =
+    ///     centaur_technical_indicators::regression_indicators::bulk::r_squared(&prices, 3).unwrap();
+    /// assert_eq!(
+    ///     vec![0.9642857142857144, 0.25, 0.1071428571428571],
+    ///     r_squared
+    /// );
+    /// ```
+    #[inline]
+    pub fn r_squared(prices: &[f64], period: usize) -> crate::Result<Vec<f64>> {
+        assert_min_period(period, 2, prices.len())?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .map(|fit| fit.r_squared)
+            .collect())
+    }
+
+    /// Calculates the rolling time series forecast
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    /// * `bars_ahead` - Number of bars after the last price of each window to project to.
+    ///   0 gives the linear regression moving average, 1 the standard time series forecast.
+    ///
+    /// # Returns
+    ///
+    /// A vector of forecasts
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 2
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let forecast =
+    ///     centaur_technical_indicators::regression_indicators::bulk::forecast(&prices, 3, 1)
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     vec![104.66666666666667, 101.0, 103.66666666666667],
+    ///     forecast
+    /// );
+    /// ```
+    #[inline]
+    pub fn forecast(prices: &[f64], period: usize, bars_ahead: usize) -> crate::Result<Vec<f64>> {
+        assert_min_period(period, 2, prices.len())?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .map(|fit| fit.forecast(bars_ahead))
+            .collect())
+    }
+
+    /// Calculates rolling standard error bands
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    /// * `multiplier` - Number of standard errors between the line and each band
+    ///
+    /// # Returns
+    ///
+    /// A vector of (lower_band, linear regression moving average, upper_band) tuples
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 3
+    /// * `period` > `prices.len()`
+    /// * `multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let bands =
+    ///     centaur_technical_indicators::regression_indicators::bulk::standard_error_bands(
+    ///         &prices,
+    ///         3,
+    ///         2.0
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (102.35017008573895, 103.16666666666667, 103.98316324759439),
+    ///         (99.05051025721683, 101.5, 103.94948974278317),
+    ///         (99.08418376202805, 103.16666666666667, 107.2491495713053)
+    ///     ],
+    ///     bands
+    /// );
+    /// ```
+    #[inline]
+    pub fn standard_error_bands(
+        prices: &[f64],
+        period: usize,
+        multiplier: f64,
+    ) -> crate::Result<Vec<(f64, f64, f64)>> {
+        assert_min_period(period, 3, prices.len())?;
+        assert_positive("multiplier", multiplier)?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .map(|fit| fit.standard_error_bands(multiplier))
+            .collect())
+    }
+
+    /// Calculates the rolling regression channel
+    ///
+    /// The channel width depends on every residual in the window, so unlike the other
+    /// bulk regression indicators this is O(n * period).
+    ///
+    /// # Arguments
+    ///
+    /// * `prices` - Slice of prices
+    /// * `period` - Period over which to fit the line
+    ///
+    /// # Returns
+    ///
+    /// A vector of (lower_channel, linear regression moving average, upper_channel) tuples
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `period` < 2
+    /// * `period` > `prices.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let prices = vec![100.0, 102.0, 103.0, 101.0, 104.0];
+    /// let channel =
+    ///     centaur_technical_indicators::regression_indicators::bulk::regression_channel(
+    ///         &prices,
+    ///         3
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (102.83333333333334, 103.16666666666667, 103.5),
+    ///         (100.5, 101.5, 102.5),
+    ///         (101.5, 103.16666666666667, 104.83333333333334)
+    ///     ],
+    ///     channel
+    /// );
+    /// ```
+    #[inline]
+    pub fn regression_channel(
+        prices: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<(f64, f64, f64)>> {
+        assert_min_period(period, 2, prices.len())?;
+        Ok(rolling_fits(prices, period)
+            .iter()
+            .zip(prices.windows(period))
+            .map(|(fit, window)| fit.channel(window))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_slope_matches_overall_trend() {
+        let prices = vec![100.46, 100.53, 100.38, 100.19, 100.21];
+        let (slope, intercept) = crate::chart_trends::overall_trend(&prices).unwrap();
+        assert!((slope - single::slope(&prices).unwrap()).abs() < 1e-12);
+        assert!((intercept - single::intercept(&prices).unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn single_r_squared_perfect_line() {
+        let prices = vec![100.0, 102.0, 104.0, 106.0];
+        assert_eq!(1.0, single::r_squared(&prices).unwrap());
+        assert_eq!(108.0, single::forecast(&prices, 1).unwrap());
+    }
+
+    #[test]
+    fn single_flat_prices() {
+        let prices = vec![100.0, 100.0, 100.0];
+        assert_eq!(0.0, single::slope(&prices).unwrap());
+        assert_eq!(1.0, single::r_squared(&prices).unwrap());
+        assert_eq!(
+            (100.0, 100.0, 100.0),
+            single::standard_error_bands(&prices, 2.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_slope_too_short() {
+        assert!(single::slope(&[100.0]).is_err());
+    }
+
+    #[test]
+    fn single_standard_error_bands_too_short() {
+        assert!(single::standard_error_bands(&[100.0, 101.0], 2.0).is_err());
+    }
+
+    #[test]
+    fn single_standard_error_bands_non_positive_multiplier() {
+        assert!(single::standard_error_bands(&[100.0, 101.0, 103.0], 0.0).is_err());
+    }
+
+    #[test]
+    fn bulk_rolling_sums_match_single() {
+        let prices = vec![
+            100.46, 100.53, 100.38, 100.19, 100.21, 100.32, 100.28, 100.49, 100.86, 101.03,
+        ];
+        let period = 4;
+        let slopes = bulk::slope(&prices, period).unwrap();
+        let intercepts = bulk::intercept(&prices, period).unwrap();
+        let r_squared = bulk::r_squared(&prices, period).unwrap();
+        let forecasts = bulk::forecast(&prices, period, 2).unwrap();
+        let bands = bulk::standard_error_bands(&prices, period, 2.0).unwrap();
+        let channels = bulk::regression_channel(&prices, period).unwrap();
+        assert_eq!(7, slopes.len());
+        for (i, window) in prices.windows(period).enumerate() {
+            assert!((slopes[i] - single::slope(window).unwrap()).abs() < 1e-9);
+            assert!((intercepts[i] - single::intercept(window).unwrap()).abs() < 1e-9);
+            assert!((r_squared[i] - single::r_squared(window).unwrap()).abs() < 1e-9);
+            assert!((forecasts[i] - single::forecast(window, 2).unwrap()).abs() < 1e-9);
+            let single_bands = single::standard_error_bands(window, 2.0).unwrap();
+            assert!((bands[i].0 - single_bands.0).abs() < 1e-9);
+            assert!((bands[i].2 - single_bands.2).abs() < 1e-9);
+            let single_channel = single::regression_channel(window).unwrap();
+            assert!((channels[i].0 - single_channel.0).abs() < 1e-9);
+            assert!((channels[i].2 - single_channel.2).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn bulk_flat_prices() {
+        let prices = vec![100.0, 102.0, 100.0, 100.0, 100.0];
+        assert_eq!(vec![2.0, -2.0, 0.0, 0.0], bulk::slope(&prices, 2).unwrap());
+        assert_eq!(1.0, bulk::r_squared(&prices, 3).unwrap()[2]);
+    }
+
+    #[test]
+    fn bulk_slope_period_too_short() {
+        assert!(bulk::slope(&[100.0, 101.0], 1).is_err());
+    }
+
+    #[test]
+    fn bulk_slope_period_too_long() {
+        assert!(bulk::slope(&[100.0, 101.0], 3).is_err());
+    }
+
+    #[test]
+    fn bulk_standard_error_bands_period_too_short() {
+        assert!(bulk::standard_error_bands(&[100.0, 101.0, 103.0], 2, 2.0).is_err());
+    }
+}
@@ -0,0 +1,1072 @@
+//! # Sessions
+//!
+//! The `sessions` module adds trading calendars to timestamped bars: session
+//! open and close times, UTC offsets and holidays. It splits bars by session,
+//! measures the gaps between sessions and anchors indicators to session starts.
+//!
+//! ## When to Use
+//! Use session functions when you want to:
+//! - Group intraday bars into trading sessions and drop bars outside of them
+//! - Measure overnight gaps (for example to feed the positivity indicator)
+//! - Calculate a VWAP that resets at every session open
+//! - Calculate floor pivot points from the previous session
+//!
+//! ## Structure
+//! - [`Session`]: Trading session definition.
+//! - [`SessionSeries`]: Timestamped bars tied to a [`Session`], with the session helpers as methods.
+//! - **single**: Functions that return a single value for one session.
+//! - **bulk**: Functions that compute values over a slice of timestamped bars and return a vector.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`anchor_to_session`](bulk::anchor_to_session): Runs any indicator per session, restarting at each open
+//! - [`session_gaps`](bulk::session_gaps): Opens and previous closes of each session
+//! - [`session_pivots`](bulk::session_pivots): Pivot points from the previous session
+//! - [`session_vwap`](bulk::session_vwap): VWAP anchored to the session open
+//! - [`split_by_session`](bulk::split_by_session): Index ranges of each session
+//!
+//! ### Single
+//! - [`pivot_points`](single::pivot_points): Floor pivot points
+//!
+//! ## API Details
+//! - Timestamps are Unix timestamps in seconds, mark the start of each bar and must increase.
+//!   They pair with the other fields of [`Bars`].
+//! - Sessions use a fixed UTC offset; split the history at daylight saving changes if needed.
+//! - A session that closes before it opens (e.g. 18:00 to 17:00) spans midnight and belongs to
+//!   the day it closes on.
+//! - Bulk functions return one value per bar, `None` for bars outside any session.
+//! - [`anchor_to_session`](bulk::anchor_to_session) and [`SessionSeries::anchored`] take the same
+//!   `Fn(&Bars) -> Result<Vec<T>>` closures as
+//!   [`multi_timeframe`](crate::timeframes::bulk::multi_timeframe), so any bulk indicator can be
+//!   anchored to the session open.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+use std::ops::Range;
+
+use crate::bars::Bars;
+use crate::validation::assert_same_len;
+use crate::TechnicalIndicatorError;
+
+/// Number of seconds in a day
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Trading session definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    /// Session open, in seconds after local midnight
+    pub open: i64,
+    /// Session close, in seconds after local midnight. Earlier than `open` for
+    /// sessions spanning midnight
+    pub close: i64,
+    /// Offset of local exchange time from UTC in seconds (e.g. `-5 * 3600` for New York in winter)
+    pub utc_offset: i64,
+    /// Local dates without a session, as days since 1970-01-01 (see [`day_number`])
+    pub holidays: Vec<i64>,
+}
+
+impl Default for Session {
+    /// Continuous trading in UTC, with a session per calendar day
+    fn default() -> Self {
+        Self {
+            open: 0,
+            close: SECONDS_PER_DAY,
+            utc_offset: 0,
+            holidays: Vec::new(),
+        }
+    }
+}
+
+impl Session {
+    /// Returns the local date (days since 1970-01-01) of the session containing
+    /// `timestamp`, or `None` if `timestamp` falls outside of every session
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::{day_number, Session};
+    ///
+    /// let session = Session {
+    ///     open: 34_200,
+    ///     close: 57_600,
+    ///     utc_offset: -5 * 3_600,
+    ///     holidays: vec![day_number(2024, 1, 1)],
+    /// };
+    /// // 2024-01-02 14:30 UTC is 09:30 in New York
+    /// assert_eq!(Some(day_number(2024, 1, 2)), session.session_day(1_704_205_800));
+    /// // 2024-01-01 14:30 UTC is a holiday
+    /// assert_eq!(None, session.session_day(1_704_119_400));
+    /// ```
+    pub fn session_day(&self, timestamp: i64) -> Option<i64> {
+        let local = timestamp + self.utc_offset;
+        let day = local.div_euclid(SECONDS_PER_DAY);
+        let time = local.rem_euclid(SECONDS_PER_DAY);
+        let session_day = if self.open < self.close {
+            (time >= self.open && time < self.close).then_some(day)
+        } else if time >= self.open {
+            Some(day + 1)
+        } else if time < self.close {
+            Some(day)
+        } else {
+            None
+        }?;
+        (!self.holidays.contains(&session_day)).then_some(session_day)
+    }
+}
+
+/// Floor pivot points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PivotPoints {
+    pub pivot: f64,
+    pub support_1: f64,
+    pub support_2: f64,
+    pub support_3: f64,
+    pub resistance_1: f64,
+    pub resistance_2: f64,
+    pub resistance_3: f64,
+}
+
+/// Timestamped bars tied to a trading session.
+///
+/// The bars are validated and split into sessions once, so the session helpers
+/// and [`anchored`](SessionSeries::anchored) indicators run without passing the
+/// timestamps and session around.
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::bars::Bars;
+/// use centaur_technical_indicators::sessions::{Session, SessionSeries};
+///
+/// let hour = 3_600;
+/// let day = 24 * hour;
+/// let bars = Bars {
+///     timestamps: vec![9 * hour, 12 * hour, 18 * hour, day + 9 * hour, day + 13 * hour],
+///     open: vec![100.0, 101.0, 102.0, 104.0, 105.0],
+///     high: vec![101.0, 102.0, 103.0, 105.0, 106.0],
+///     low: vec![99.0, 100.0, 101.0, 103.0, 104.0],
+///     close: vec![101.0, 102.0, 102.0, 105.0, 104.0],
+///     volume: vec![100.0, 200.0, 50.0, 100.0, 300.0],
+///     trades: vec![10, 20, 5, 10, 30],
+/// };
+/// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+///
+/// let series = SessionSeries::new(bars, session).unwrap();
+/// assert_eq!(&[0..2, 3..5], series.ranges());
+/// assert_eq!((vec![104.0], vec![102.0]), series.gaps());
+///
+/// // Two bar moving average that restarts at every session open
+/// let average = series.anchored(|bars| {
+///     centaur_technical_indicators::moving_average::bulk::moving_average(
+///         &bars.close,
+///         centaur_technical_indicators::MovingAverageType::Simple,
+///         2,
+///     )
+/// }).unwrap();
+/// assert_eq!(vec![None, Some(101.5), None, None, Some(104.5)], average);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionSeries {
+    bars: Bars,
+    session: Session,
+    ranges: Vec<Range<usize>>,
+}
+
+impl SessionSeries {
+    /// Creates a series from bars and the session they trade in
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `bars.is_empty()`
+    /// * The fields of `bars` are not all the same length
+    /// * `bars.timestamps` are not increasing
+    /// * `session.open` or `session.close` is not within a day, or they are equal
+    pub fn new(bars: Bars, session: Session) -> crate::Result<Self> {
+        assert_same_len(&[
+            ("open", &bars.open),
+            ("high", &bars.high),
+            ("low", &bars.low),
+            ("close", &bars.close),
+            ("volume", &bars.volume),
+        ])?;
+        assert_bars(&bars.timestamps, bars.open.len())?;
+        if bars.trades.len() != bars.open.len() {
+            return Err(TechnicalIndicatorError::MismatchedLength {
+                names: vec![
+                    ("trades".to_string(), bars.trades.len()),
+                    ("bars".to_string(), bars.open.len()),
+                ],
+            });
+        }
+        let ranges = bulk::split_by_session(&bars.timestamps, &session)?;
+        Ok(Self {
+            bars,
+            session,
+            ranges,
+        })
+    }
+
+    /// Bars of the series
+    pub fn bars(&self) -> &Bars {
+        &self.bars
+    }
+
+    /// Session the bars trade in
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Index ranges of each session, skipping bars outside of sessions
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    /// Bars of each session, skipping bars outside of sessions
+    pub fn session_bars(&self) -> Vec<Bars> {
+        self.ranges
+            .iter()
+            .map(|range| slice_bars(&self.bars, range.clone()))
+            .collect()
+    }
+
+    /// Opens of each session and closes of the session before it, see
+    /// [`session_gaps`](bulk::session_gaps)
+    pub fn gaps(&self) -> (Vec<f64>, Vec<f64>) {
+        gaps_of(&self.ranges, &self.bars.open, &self.bars.close)
+    }
+
+    /// VWAP anchored to each session open, see [`session_vwap`](bulk::session_vwap)
+    pub fn vwap(&self) -> Vec<Option<f64>> {
+        vwap_of(
+            &self.ranges,
+            &self.bars.high,
+            &self.bars.low,
+            &self.bars.close,
+            &self.bars.volume,
+        )
+    }
+
+    /// Pivot points from the previous session, see [`session_pivots`](bulk::session_pivots)
+    pub fn pivots(&self) -> Vec<Option<PivotPoints>> {
+        pivots_of(
+            &self.ranges,
+            &self.bars.high,
+            &self.bars.low,
+            &self.bars.close,
+        )
+    }
+
+    /// Runs an indicator on the bars of each session separately, see
+    /// [`anchor_to_session`](bulk::anchor_to_session)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `indicator` returns an error
+    /// * `indicator` returns more values than the session has bars
+    pub fn anchored<T, F>(&self, indicator: F) -> crate::Result<Vec<Option<T>>>
+    where
+        F: Fn(&Bars) -> crate::Result<Vec<T>>,
+    {
+        let mut anchored: Vec<Option<T>> = std::iter::repeat_with(|| None)
+            .take(self.bars.len())
+            .collect();
+        for range in &self.ranges {
+            let values = indicator(&slice_bars(&self.bars, range.clone()))?;
+            if values.len() > range.len() {
+                return Err(TechnicalIndicatorError::MismatchedLength {
+                    names: vec![
+                        ("indicator".to_string(), values.len()),
+                        ("session bars".to_string(), range.len()),
+                    ],
+                });
+            }
+            let start = range.end - values.len();
+            for (slot, value) in anchored[start..range.end].iter_mut().zip(values) {
+                *slot = Some(value);
+            }
+        }
+        Ok(anchored)
+    }
+}
+
+/// Converts a calendar date to the number of days since 1970-01-01
+///
+/// # Arguments
+///
+/// * `year` - Year
+/// * `month` - Month (1 to 12)
+/// * `day` - Day of the month
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(0, centaur_technical_indicators::sessions::day_number(1970, 1, 1));
+/// assert_eq!(19_724, centaur_technical_indicators::sessions::day_number(2024, 1, 2));
+/// ```
+pub fn day_number(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Validates the session open and close times
+fn assert_session(session: &Session) -> crate::Result<()> {
+    for (name, value) in [("open", session.open), ("close", session.close)] {
+        if !(0..=SECONDS_PER_DAY).contains(&value) {
+            return Err(TechnicalIndicatorError::InvalidValue {
+                name: name.to_string(),
+                value: value as f64,
+                reason: format!("must be between 0 and {}", SECONDS_PER_DAY),
+            });
+        }
+    }
+    if session.open == session.close {
+        return Err(TechnicalIndicatorError::InvalidValue {
+            name: "close".to_string(),
+            value: session.close as f64,
+            reason: "cannot be equal to open".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Validates that there is one timestamp per bar
+fn assert_bars(timestamps: &[i64], length: usize) -> crate::Result<()> {
+    if timestamps.len() != length {
+        return Err(TechnicalIndicatorError::MismatchedLength {
+            names: vec![
+                ("timestamps".to_string(), timestamps.len()),
+                ("bars".to_string(), length),
+            ],
+        });
+    }
+    Ok(())
+}
+
+/// Copies the bars within `range`
+fn slice_bars(bars: &Bars, range: Range<usize>) -> Bars {
+    Bars {
+        timestamps: bars.timestamps[range.clone()].to_vec(),
+        open: bars.open[range.clone()].to_vec(),
+        high: bars.high[range.clone()].to_vec(),
+        low: bars.low[range.clone()].to_vec(),
+        close: bars.close[range.clone()].to_vec(),
+        volume: bars.volume[range.clone()].to_vec(),
+        trades: bars.trades[range].to_vec(),
+    }
+}
+
+/// Opens of each session and closes of the session before it
+fn gaps_of(ranges: &[Range<usize>], open: &[f64], close: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    ranges
+        .windows(2)
+        .map(|w| (open[w[1].start], close[w[0].end - 1]))
+        .unzip()
+}
+
+/// Typical price VWAP that restarts at every session
+fn vwap_of(
+    ranges: &[Range<usize>],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    volume: &[f64],
+) -> Vec<Option<f64>> {
+    let mut vwap = vec![None; high.len()];
+    for range in ranges {
+        let (mut value, mut total_volume) = (0.0, 0.0);
+        for i in range.clone() {
+            value += (high[i] + low[i] + close[i]) / 3.0 * volume[i];
+            total_volume += volume[i];
+            if total_volume > 0.0 {
+                vwap[i] = Some(value / total_volume);
+            }
+        }
+    }
+    vwap
+}
+
+/// Pivot points of each session applied to the bars of the next one
+fn pivots_of(
+    ranges: &[Range<usize>],
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+) -> Vec<Option<PivotPoints>> {
+    let mut pivots = vec![None; high.len()];
+    for w in ranges.windows(2) {
+        let previous = w[0].clone();
+        let session_high = high[previous.clone()]
+            .iter()
+            .fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+        let session_low = low[previous.clone()]
+            .iter()
+            .fold(f64::INFINITY, |a, b| a.min(*b));
+        let points = single::pivot_points(session_high, session_low, close[previous.end - 1]);
+        for pivot in &mut pivots[w[1].clone()] {
+            *pivot = Some(points);
+        }
+    }
+    pivots
+}
+
+/// **single**: Functions that return a single value for one session
+pub mod single {
+    use crate::sessions::PivotPoints;
+
+    /// Calculates floor pivot points from a session's high, low and close
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Session high
+    /// * `low` - Session low
+    /// * `close` - Session close
+    ///
+    /// # Returns
+    ///
+    /// [`PivotPoints`] for the following session
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let pivots = centaur_technical_indicators::sessions::single::pivot_points(110.0, 100.0, 105.0);
+    /// assert_eq!(105.0, pivots.pivot);
+    /// assert_eq!((100.0, 110.0), (pivots.support_1, pivots.resistance_1));
+    /// assert_eq!((95.0, 115.0), (pivots.support_2, pivots.resistance_2));
+    /// assert_eq!((90.0, 120.0), (pivots.support_3, pivots.resistance_3));
+    /// ```
+    #[inline]
+    pub fn pivot_points(high: f64, low: f64, close: f64) -> PivotPoints {
+        let pivot = (high + low + close) / 3.0;
+        PivotPoints {
+            pivot,
+            support_1: 2.0 * pivot - high,
+            support_2: pivot - (high - low),
+            support_3: low - 2.0 * (high - pivot),
+            resistance_1: 2.0 * pivot - low,
+            resistance_2: pivot + (high - low),
+            resistance_3: high + 2.0 * (pivot - low),
+        }
+    }
+}
+
+/// **bulk**: Functions that compute values over a slice of timestamped bars and return a vector
+pub mod bulk {
+    use std::ops::Range;
+
+    use crate::bars::Bars;
+    use crate::sessions::{
+        assert_bars, assert_session, gaps_of, pivots_of, vwap_of, PivotPoints, Session,
+        SessionSeries,
+    };
+    use crate::validation::{assert_increasing, assert_non_empty, assert_same_len};
+
+    /// Splits timestamped bars into sessions
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar timestamps
+    /// * `session` - Session definition
+    ///
+    /// # Returns
+    ///
+    /// A vector of index ranges, one per session, skipping bars outside of sessions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * `timestamps` are not increasing
+    /// * `session.open` or `session.close` is not within a day, or they are equal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::Session;
+    ///
+    /// let hour = 3_600;
+    /// let day = 24 * hour;
+    /// let timestamps = vec![9 * hour, 12 * hour, 18 * hour, day + 9 * hour, day + 13 * hour];
+    /// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+    ///
+    /// let sessions =
+    ///     centaur_technical_indicators::sessions::bulk::split_by_session(&timestamps, &session)
+    ///         .unwrap();
+    /// assert_eq!(vec![0..2, 3..5], sessions);
+    /// ```
+    pub fn split_by_session(
+        timestamps: &[i64],
+        session: &Session,
+    ) -> crate::Result<Vec<Range<usize>>> {
+        assert_non_empty("timestamps", timestamps)?;
+        assert_increasing("timestamps", timestamps)?;
+        assert_session(session)?;
+
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut current: Option<(i64, usize)> = None;
+        for (i, &timestamp) in timestamps.iter().enumerate() {
+            let day = session.session_day(timestamp);
+            if let Some((current_day, start)) = current {
+                if day != Some(current_day) {
+                    ranges.push(start..i);
+                    current = None;
+                }
+            }
+            if let (Some(day), None) = (day, current) {
+                current = Some((day, i));
+            }
+        }
+        if let Some((_, start)) = current {
+            ranges.push(start..timestamps.len());
+        }
+        Ok(ranges)
+    }
+
+    /// Runs an indicator on the bars of each session separately, so it restarts
+    /// at every session open
+    ///
+    /// The indicator receives the [`Bars`] of one session at a time. Its values are
+    /// aligned to the last bars of that session, as bulk indicators return one value
+    /// per complete period. Return an empty vector for sessions that are too short.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar timestamps
+    /// * `open` - Slice of opens
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    /// * `volume` - Slice of volumes
+    /// * `session` - Session definition
+    /// * `indicator` - Function computing the indicator on the [`Bars`] of one session, whose
+    ///   trade counts are 0 as they are not passed in
+    ///
+    /// # Returns
+    ///
+    /// A vector with one value per bar, `None` outside of sessions and before the
+    /// indicator's first value in each session
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `timestamps` are not increasing
+    /// * The session is invalid
+    /// * `indicator` returns an error, or more values than the session has bars
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::Session;
+    ///
+    /// let hour = 3_600;
+    /// let day = 24 * hour;
+    /// let timestamps = vec![9 * hour, 10 * hour, 11 * hour, day + 9 * hour, day + 10 * hour];
+    /// let prices = vec![100.0, 102.0, 104.0, 110.0, 108.0];
+    /// let volume = vec![100.0; 5];
+    /// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+    ///
+    /// let average = centaur_technical_indicators::sessions::bulk::anchor_to_session(
+    ///     &timestamps,
+    ///     &prices,
+    ///     &prices,
+    ///     &prices,
+    ///     &prices,
+    ///     &volume,
+    ///     &session,
+    ///     |bars| centaur_technical_indicators::moving_average::bulk::moving_average(
+    ///         &bars.close,
+    ///         centaur_technical_indicators::MovingAverageType::Simple,
+    ///         2,
+    ///     ),
+    /// ).unwrap();
+    /// assert_eq!(vec![None, Some(101.0), Some(103.0), None, Some(109.0)], average);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn anchor_to_session<T, F>(
+        timestamps: &[i64],
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        session: &Session,
+        indicator: F,
+    ) -> crate::Result<Vec<Option<T>>>
+    where
+        F: Fn(&Bars) -> crate::Result<Vec<T>>,
+    {
+        let bars = Bars {
+            timestamps: timestamps.to_vec(),
+            open: open.to_vec(),
+            high: high.to_vec(),
+            low: low.to_vec(),
+            close: close.to_vec(),
+            volume: volume.to_vec(),
+            trades: vec![0; open.len()],
+        };
+        SessionSeries::new(bars, session.clone())?.anchored(indicator)
+    }
+
+    /// Calculates the open of each session and the close of the session before it
+    ///
+    /// The output pairs up with
+    /// [`positivity_indicator`](crate::other_indicators::bulk::positivity_indicator).
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar timestamps
+    /// * `open` - Slice of opens
+    /// * `close` - Slice of closes
+    /// * `session` - Session definition
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (session opens, previous session closes), one value per session
+    /// after the first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `timestamps` are not increasing
+    /// * The session is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::Session;
+    ///
+    /// let hour = 3_600;
+    /// let day = 24 * hour;
+    /// let timestamps = vec![9 * hour, 12 * hour, day + 9 * hour, day + 13 * hour];
+    /// let open = vec![100.0, 101.0, 103.0, 102.0];
+    /// let close = vec![101.0, 102.0, 102.5, 101.0];
+    /// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+    ///
+    /// let (session_opens, previous_closes) =
+    ///     centaur_technical_indicators::sessions::bulk::session_gaps(
+    ///         &timestamps, &open, &close, &session
+    ///     ).unwrap();
+    /// assert_eq!(vec![103.0], session_opens);
+    /// assert_eq!(vec![102.0], previous_closes);
+    /// ```
+    pub fn session_gaps(
+        timestamps: &[i64],
+        open: &[f64],
+        close: &[f64],
+        session: &Session,
+    ) -> crate::Result<(Vec<f64>, Vec<f64>)> {
+        assert_same_len(&[("open", open), ("close", close)])?;
+        assert_bars(timestamps, open.len())?;
+        let ranges = split_by_session(timestamps, session)?;
+        Ok(gaps_of(&ranges, open, close))
+    }
+
+    /// Calculates the volume weighted average price anchored to each session open
+    ///
+    /// Uses the typical price `(high + low + close) / 3` of each bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar timestamps
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    /// * `volume` - Slice of volumes
+    /// * `session` - Session definition
+    ///
+    /// # Returns
+    ///
+    /// A vector with one VWAP per bar, `None` outside of sessions and until the
+    /// session has traded volume
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `timestamps` are not increasing
+    /// * The session is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::Session;
+    ///
+    /// let hour = 3_600;
+    /// let day = 24 * hour;
+    /// let timestamps = vec![9 * hour, 12 * hour, 18 * hour, day + 9 * hour];
+    /// let prices = vec![100.0, 103.0, 104.0, 110.0];
+    /// let volume = vec![100.0, 200.0, 50.0, 100.0];
+    /// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+    ///
+    /// let vwap = centaur_technical_indicators::sessions::bulk::session_vwap(
+    ///     &timestamps, &prices, &prices, &prices, &volume, &session
+    /// ).unwrap();
+    /// assert_eq!(vec![Some(100.0), Some(102.0), None, Some(110.0)], vwap);
+    /// ```
+    pub fn session_vwap(
+        timestamps: &[i64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        session: &Session,
+    ) -> crate::Result<Vec<Option<f64>>> {
+        assert_same_len(&[
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_bars(timestamps, high.len())?;
+        let ranges = split_by_session(timestamps, session)?;
+        Ok(vwap_of(&ranges, high, low, close, volume))
+    }
+
+    /// Calculates floor pivot points from the previous session for every bar
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar timestamps
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    /// * `session` - Session definition
+    ///
+    /// # Returns
+    ///
+    /// A vector with one [`PivotPoints`] per bar, `None` outside of sessions and
+    /// during the first session
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()`
+    /// * The slices are not all the same length
+    /// * `timestamps` are not increasing
+    /// * The session is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::sessions::Session;
+    ///
+    /// let hour = 3_600;
+    /// let day = 24 * hour;
+    /// let timestamps = vec![9 * hour, 12 * hour, day + 9 * hour];
+    /// let high = vec![108.0, 110.0, 107.0];
+    /// let low = vec![100.0, 104.0, 103.0];
+    /// let close = vec![106.0, 105.0, 104.0];
+    /// let session = Session { open: 9 * hour, close: 17 * hour, ..Session::default() };
+    ///
+    /// let pivots = centaur_technical_indicators::sessions::bulk::session_pivots(
+    ///     &timestamps, &high, &low, &close, &session
+    /// ).unwrap();
+    /// assert_eq!(None, pivots[1]);
+    /// assert_eq!(105.0, pivots[2].unwrap().pivot);
+    /// ```
+    pub fn session_pivots(
+        timestamps: &[i64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        session: &Session,
+    ) -> crate::Result<Vec<Option<PivotPoints>>> {
+        assert_same_len(&[("high", high), ("low", low), ("close", close)])?;
+        assert_bars(timestamps, high.len())?;
+        let ranges = split_by_session(timestamps, session)?;
+        Ok(pivots_of(&ranges, high, low, close))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3_600;
+
+    fn new_york() -> Session {
+        Session {
+            open: 9 * HOUR + 1_800,
+            close: 16 * HOUR,
+            utc_offset: -5 * HOUR,
+            holidays: vec![day_number(2024, 1, 15)],
+        }
+    }
+
+    #[test]
+    fn day_number_leap_year() {
+        assert_eq!(day_number(2024, 2, 28) + 2, day_number(2024, 3, 1));
+        assert_eq!(-1, day_number(1969, 12, 31));
+    }
+
+    #[test]
+    fn session_day_open_and_close_bounds() {
+        let session = new_york();
+        let day = day_number(2024, 1, 2);
+        let open = day * SECONDS_PER_DAY + 14 * HOUR + 1_800;
+        let close = day * SECONDS_PER_DAY + 21 * HOUR;
+        assert_eq!(None, session.session_day(open - 1));
+        assert_eq!(Some(day), session.session_day(open));
+        assert_eq!(Some(day), session.session_day(close - 1));
+        assert_eq!(None, session.session_day(close));
+    }
+
+    #[test]
+    fn session_day_holiday() {
+        let session = new_york();
+        let holiday = day_number(2024, 1, 15) * SECONDS_PER_DAY + 15 * HOUR;
+        assert_eq!(None, session.session_day(holiday));
+    }
+
+    #[test]
+    fn session_day_overnight() {
+        let session = Session {
+            open: 18 * HOUR,
+            close: 17 * HOUR,
+            ..Session::default()
+        };
+        assert_eq!(Some(1), session.session_day(19 * HOUR));
+        assert_eq!(Some(1), session.session_day(SECONDS_PER_DAY + 16 * HOUR));
+        assert_eq!(None, session.session_day(SECONDS_PER_DAY + 17 * HOUR + 1));
+    }
+
+    #[test]
+    fn default_session_is_calendar_day() {
+        let session = Session::default();
+        assert_eq!(Some(0), session.session_day(0));
+        assert_eq!(Some(1), session.session_day(SECONDS_PER_DAY));
+    }
+
+    #[test]
+    fn split_by_session_overnight() {
+        let session = Session {
+            open: 18 * HOUR,
+            close: 17 * HOUR,
+            ..Session::default()
+        };
+        let timestamps = vec![
+            19 * HOUR,
+            SECONDS_PER_DAY + 10 * HOUR,
+            SECONDS_PER_DAY + 17 * HOUR + 1_800,
+            SECONDS_PER_DAY + 18 * HOUR,
+        ];
+        assert_eq!(
+            vec![0..2, 3..4],
+            bulk::split_by_session(&timestamps, &session).unwrap()
+        );
+    }
+
+    #[test]
+    fn split_by_session_errors() {
+        let session = Session {
+            open: 9 * HOUR,
+            close: 9 * HOUR,
+            ..Session::default()
+        };
+        assert!(bulk::split_by_session(&[0, 1], &session).is_err());
+        let session = Session {
+            open: -1,
+            ..Session::default()
+        };
+        assert!(bulk::split_by_session(&[0, 1], &session).is_err());
+        assert!(bulk::split_by_session(&[1, 0], &Session::default()).is_err());
+        assert!(bulk::split_by_session(&[], &Session::default()).is_err());
+    }
+
+    #[test]
+    fn session_gaps_feed_positivity_indicator() {
+        let timestamps: Vec<i64> = (0..8).map(|day| day * SECONDS_PER_DAY).collect();
+        let open = vec![100.0, 101.0, 99.0, 102.0, 103.0, 101.0, 104.0, 105.0];
+        let close = vec![100.5, 100.0, 101.0, 102.5, 102.0, 103.0, 104.5, 104.0];
+        let (session_opens, previous_closes) =
+            bulk::session_gaps(&timestamps, &open, &close, &Session::default()).unwrap();
+        assert_eq!(open[1..].to_vec(), session_opens);
+        assert_eq!(close[..7].to_vec(), previous_closes);
+        let positivity = crate::other_indicators::bulk::positivity_indicator(
+            &session_opens,
+            &previous_closes,
+            3,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(5, positivity.len());
+    }
+
+    #[test]
+    fn session_gaps_mismatched_length() {
+        assert!(bulk::session_gaps(&[0], &[1.0, 2.0], &[1.0, 2.0], &Session::default()).is_err());
+    }
+
+    #[test]
+    fn session_vwap_zero_volume() {
+        let timestamps = vec![0, HOUR];
+        let prices = vec![100.0, 101.0];
+        let volume = vec![0.0, 10.0];
+        assert_eq!(
+            vec![None, Some(101.0)],
+            bulk::session_vwap(
+                &timestamps,
+                &prices,
+                &prices,
+                &prices,
+                &volume,
+                &Session::default()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn session_pivots_use_previous_session_only() {
+        let timestamps = vec![0, HOUR, SECONDS_PER_DAY, SECONDS_PER_DAY + HOUR];
+        let high = vec![108.0, 110.0, 200.0, 300.0];
+        let low = vec![100.0, 104.0, 50.0, 40.0];
+        let close = vec![106.0, 105.0, 150.0, 120.0];
+        let pivots =
+            bulk::session_pivots(&timestamps, &high, &low, &close, &Session::default()).unwrap();
+        let expected = single::pivot_points(110.0, 100.0, 105.0);
+        assert_eq!(vec![None, None, Some(expected), Some(expected)], pivots);
+    }
+
+    fn two_day_bars() -> Bars {
+        let day = day_number(2024, 1, 2) * SECONDS_PER_DAY;
+        Bars {
+            timestamps: vec![
+                day + 14 * HOUR + 1_800,
+                day + 16 * HOUR,
+                day + 20 * HOUR,
+                day + 22 * HOUR,
+                day + SECONDS_PER_DAY + 14 * HOUR + 1_800,
+                day + SECONDS_PER_DAY + 17 * HOUR,
+                day + SECONDS_PER_DAY + 19 * HOUR,
+            ],
+            open: vec![100.0, 101.0, 103.0, 104.0, 106.0, 105.0, 107.0],
+            high: vec![102.0, 104.0, 105.0, 105.0, 107.0, 108.0, 109.0],
+            low: vec![99.0, 100.0, 102.0, 103.0, 104.0, 104.0, 106.0],
+            close: vec![101.0, 103.0, 104.0, 104.5, 105.0, 104.0, 108.0],
+            volume: vec![500.0, 300.0, 400.0, 50.0, 600.0, 200.0, 300.0],
+            trades: vec![50, 30, 40, 5, 60, 20, 30],
+        }
+    }
+
+    #[test]
+    fn session_series_matches_bulk_functions() {
+        let bars = two_day_bars();
+        let session = new_york();
+        let series = SessionSeries::new(bars.clone(), session.clone()).unwrap();
+        assert_eq!(&[0..3, 4..7], series.ranges());
+        assert_eq!(
+            bulk::session_gaps(&bars.timestamps, &bars.open, &bars.close, &session).unwrap(),
+            series.gaps()
+        );
+        assert_eq!(
+            bulk::session_vwap(
+                &bars.timestamps,
+                &bars.high,
+                &bars.low,
+                &bars.close,
+                &bars.volume,
+                &session
+            )
+            .unwrap(),
+            series.vwap()
+        );
+        assert_eq!(
+            bulk::session_pivots(
+                &bars.timestamps,
+                &bars.high,
+                &bars.low,
+                &bars.close,
+                &session
+            )
+            .unwrap(),
+            series.pivots()
+        );
+        let session_bars = series.session_bars();
+        assert_eq!(2, session_bars.len());
+        assert_eq!(vec![106.0, 105.0, 107.0], session_bars[1].open);
+    }
+
+    #[test]
+    fn session_series_errors() {
+        let mut bars = two_day_bars();
+        bars.volume.pop();
+        assert!(SessionSeries::new(bars, new_york()).is_err());
+        let mut bars = two_day_bars();
+        bars.timestamps.pop();
+        assert!(SessionSeries::new(bars, new_york()).is_err());
+        let mut bars = two_day_bars();
+        bars.trades.pop();
+        assert!(SessionSeries::new(bars, new_york()).is_err());
+        let session = Session {
+            close: 9 * HOUR + 1_800,
+            ..new_york()
+        };
+        assert!(SessionSeries::new(two_day_bars(), session).is_err());
+        assert!(SessionSeries::new(Bars::default(), new_york()).is_err());
+    }
+
+    #[test]
+    fn anchor_to_session_restarts_at_session_open() {
+        let bars = two_day_bars();
+        let rsi = bulk::anchor_to_session(
+            &bars.timestamps,
+            &bars.open,
+            &bars.high,
+            &bars.low,
+            &bars.close,
+            &bars.volume,
+            &new_york(),
+            |bars| {
+                crate::momentum_indicators::bulk::relative_strength_index(
+                    &bars.close,
+                    crate::ConstantModelType::SimpleMovingAverage,
+                    3,
+                )
+            },
+        )
+        .unwrap();
+        assert_eq!(7, rsi.len());
+        assert_eq!(None, rsi[3]);
+        assert_eq!(vec![None, None, Some(100.0)], rsi[..3].to_vec());
+        assert_eq!(vec![None, None, Some(80.0)], rsi[4..].to_vec());
+    }
+
+    #[test]
+    fn anchored_short_sessions_are_skipped() {
+        let series = SessionSeries::new(two_day_bars(), new_york()).unwrap();
+        let values = series
+            .anchored(|bars| {
+                if bars.len() < 4 {
+                    return Ok(Vec::new());
+                }
+                crate::basic_indicators::bulk::mean(&bars.close, 4)
+            })
+            .unwrap();
+        assert_eq!(vec![None; 7], values);
+    }
+
+    #[test]
+    fn anchored_errors() {
+        let series = SessionSeries::new(two_day_bars(), new_york()).unwrap();
+        assert!(series
+            .anchored(|bars| crate::basic_indicators::bulk::mean(&bars.close, 4))
+            .is_err());
+        assert!(series
+            .anchored(|bars| Ok(vec![0.0; bars.len() + 1]))
+            .is_err());
+    }
+}
@@ -5,6 +5,7 @@
 //! ## When to Use
 //! Use these indicators when you want to:
 //! - Compute Relative Vigor Index for market conviction analysis
+//! - Confirm price moves with volume (Chaikin Money Flow, Force Index, Klinger, Volume Oscillator)
 //!
 //! ## Structure
 //! - **single**: Functions that return a single value for a slice of prices.
@@ -18,12 +19,24 @@
 //! - [`positive_volume_index`](bulk::positive_volume_index): Calculates the Positive Volume Index (PVI)
 //! - [`negative_volume_index`](bulk::negative_volume_index): Calculates the Negative Volume Index (NVI)
 //! - [`relative_vigor_index`](bulk::relative_vigor_index): Calculates the Relative Vigor Index (RVI)
+//! - [`chaikin_money_flow`](bulk::chaikin_money_flow): Calculates the Chaikin Money Flow (CMF)
+//! - [`force_index`](bulk::force_index): Calculates Elder's Force Index
+//! - [`elder_ray`](bulk::elder_ray): Calculates Elder Ray bull and bear power
+//! - [`ease_of_movement`](bulk::ease_of_movement): Calculates the Ease of Movement (EMV)
+//! - [`klinger_volume_oscillator`](bulk::klinger_volume_oscillator): Calculates the Klinger Volume Oscillator (KVO)
+//! - [`volume_oscillator`](bulk::volume_oscillator): Calculates the Volume Oscillator
 //!
 //! ### Single
 //!
 //! - [`accumulation_distribution`](single::accumulation_distribution): Calculates the Accumulation/Distribution value
 //! - [`volume_index`](single::volume_index): Generic calculation for use in PVI/NVI.
 //! - [`relative_vigor_index`](single::relative_vigor_index): Calculates the Relative Vigor Index (RVI)
+//! - [`chaikin_money_flow`](single::chaikin_money_flow): Calculates the Chaikin Money Flow (CMF)
+//! - [`force_index`](single::force_index): Calculates Elder's Force Index
+//! - [`elder_ray`](single::elder_ray): Calculates Elder Ray bull and bear power
+//! - [`ease_of_movement`](single::ease_of_movement): Calculates the Ease of Movement (EMV)
+//! - [`klinger_volume_oscillator`](single::klinger_volume_oscillator): Calculates the Klinger Volume Oscillator (KVO)
+//! - [`volume_oscillator`](single::volume_oscillator): Calculates the Volume Oscillator
 //!
 //! ## API Details
 //! - Functions in `bulk` operate on slices and return vectors of results.
@@ -34,12 +47,12 @@
 
 /// **single**: Functions that return a single value for a slice of prices.
 pub mod single {
-    use crate::basic_indicators::single::{median, mode};
-    use crate::moving_average::single::moving_average;
     use crate::validation::{
-        assert_min_period, assert_non_empty, assert_same_len, unsupported_type,
+        assert_min_length, assert_min_period, assert_non_empty, assert_period, assert_positive,
+        assert_same_len,
     };
-    use crate::{ConstantModelType, MovingAverageType, Result};
+    use crate::CentralTendency;
+    use crate::Result;
 
     /// Calculates the accumulation distribution
     ///
@@ -150,7 +163,7 @@ pub mod single {
     /// * `high` - Slice of highs
     /// * `low` - Slice of lows
     /// * `close` - Slice of closing prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -183,7 +196,7 @@ pub mod single {
         high: &[f64],
         low: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> Result<f64> {
         let length = open.len();
         assert_same_len(&[
@@ -223,52 +236,401 @@ pub mod single {
             );
         }
 
-        let (smoothed_numerator, smoothed_denominator) = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => (
-                moving_average(&numerator, MovingAverageType::Simple)?,
-                moving_average(&denominator, MovingAverageType::Simple)?,
-            ),
-            ConstantModelType::SmoothedMovingAverage => (
-                moving_average(&numerator, MovingAverageType::Smoothed)?,
-                moving_average(&denominator, MovingAverageType::Smoothed)?,
-            ),
-            ConstantModelType::ExponentialMovingAverage => (
-                moving_average(&numerator, MovingAverageType::Exponential)?,
-                moving_average(&denominator, MovingAverageType::Exponential)?,
-            ),
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => (
-                moving_average(
-                    &numerator,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-                moving_average(
-                    &denominator,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-            ),
-            ConstantModelType::SimpleMovingMedian => (median(&numerator)?, median(&denominator)?),
-            ConstantModelType::SimpleMovingMode => (mode(&numerator)?, mode(&denominator)?),
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let (smoothed_numerator, smoothed_denominator) = (
+            constant_model_type.central_value(&numerator)?,
+            constant_model_type.central_value(&denominator)?,
+        );
 
         Ok(smoothed_numerator / smoothed_denominator)
     }
+
+    /// Calculates the Chaikin Money Flow (CMF)
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value, 0.0 when the total volume is 0.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()` != `volume.len()`
+    /// * `close.is_empty()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0];
+    /// let low = vec![99.0, 99.0, 100.0];
+    /// let close = vec![102.0, 100.0, 103.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0];
+    ///
+    /// let chaikin_money_flow =
+    ///     centaur_technical_indicators::strength_indicators::single::chaikin_money_flow(
+    ///         &high,
+    ///         &low,
+    ///         &close,
+    ///         &volume
+    ///     ).unwrap();
+    /// assert_eq!(0.06486486486486487, chaikin_money_flow);
+    /// ```
+    pub fn chaikin_money_flow(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+    ) -> Result<f64> {
+        assert_same_len(&[
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_non_empty("close", close)?;
+
+        let money_flow_volume: f64 = (0..close.len())
+            .filter(|&i| high[i] != low[i])
+            .map(|i| accumulation_distribution(high[i], low[i], close[i], volume[i], 0.0))
+            .sum();
+        let total_volume: f64 = volume.iter().sum();
+        if total_volume == 0.0 {
+            return Ok(0.0);
+        }
+        Ok(money_flow_volume / total_volume)
+    }
+
+    /// Calculates Elder's Force Index
+    ///
+    /// The average of the price change multiplied by the volume. The first value of
+    /// each slice only provides the previous close, so the period is `close.len() - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.len()` != `volume.len()`
+    /// * `close.len()` < 2
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let close = vec![100.0, 102.0, 101.0, 104.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+    ///
+    /// let force_index = centaur_technical_indicators::strength_indicators::single::force_index(
+    ///     &close,
+    ///     &volume,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(1500.0, force_index);
+    /// ```
+    pub fn force_index(
+        close: &[f64],
+        volume: &[f64],
+        constant_model_type: impl CentralTendency,
+    ) -> Result<f64> {
+        assert_same_len(&[("close", close), ("volume", volume)])?;
+        assert_min_length("close", 2, close.len())?;
+        let forces: Vec<f64> = (1..close.len())
+            .map(|i| (close[i] - close[i - 1]) * volume[i])
+            .collect();
+        constant_model_type.central_value(&forces)
+    }
+
+    /// Calculates Elder Ray bull and bear power
+    ///
+    /// The distance of the latest high and low from the average close.
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (bull power, bear power)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()`
+    /// * `close.is_empty()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0];
+    /// let low = vec![99.0, 99.0, 100.0];
+    /// let close = vec![102.0, 100.0, 103.0];
+    ///
+    /// let elder_ray = centaur_technical_indicators::strength_indicators::single::elder_ray(
+    ///     &high,
+    ///     &low,
+    ///     &close,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!((3.3333333333333286, -1.6666666666666714), elder_ray);
+    /// ```
+    pub fn elder_ray(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        constant_model_type: impl CentralTendency,
+    ) -> Result<(f64, f64)> {
+        assert_same_len(&[("high", high), ("low", low), ("close", close)])?;
+        assert_non_empty("close", close)?;
+        let average = constant_model_type.central_value(close)?;
+        Ok((high[high.len() - 1] - average, low[low.len() - 1] - average))
+    }
+
+    /// Calculates the Ease of Movement (EMV)
+    ///
+    /// The average of the midpoint move divided by the box ratio
+    /// `(volume / volume_divisor) / (high - low)`. The first value of each slice only
+    /// provides the previous midpoint, so the period is `high.len() - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `volume` - Slice of volumes
+    /// * `volume_divisor` - Scale applied to the volume (typically 100,000,000)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `volume.len()`
+    /// * `high.len()` < 2
+    /// * `volume_divisor` <= 0.0
+    /// * Any volume after the first is <= 0.0
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0];
+    /// let low = vec![99.0, 99.0, 100.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0];
+    ///
+    /// let ease_of_movement =
+    ///     centaur_technical_indicators::strength_indicators::single::ease_of_movement(
+    ///         &high,
+    ///         &low,
+    ///         &volume,
+    ///         1000.0,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(3.666666666666667, ease_of_movement);
+    /// ```
+    pub fn ease_of_movement(
+        high: &[f64],
+        low: &[f64],
+        volume: &[f64],
+        volume_divisor: f64,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<f64> {
+        assert_same_len(&[("high", high), ("low", low), ("volume", volume)])?;
+        assert_min_length("high", 2, high.len())?;
+        assert_positive("volume_divisor", volume_divisor)?;
+        for &bar_volume in &volume[1..] {
+            assert_positive("volume", bar_volume)?;
+        }
+        let movements: Vec<f64> = (1..high.len())
+            .map(|i| {
+                let distance = (high[i] + low[i]) / 2.0 - (high[i - 1] + low[i - 1]) / 2.0;
+                distance * (high[i] - low[i]) / (volume[i] / volume_divisor)
+            })
+            .collect();
+        constant_model_type.central_value(&movements)
+    }
+
+    /// Calculates the Klinger Volume Oscillator (KVO)
+    ///
+    /// The difference between a short and a long average of the volume force. The
+    /// volume force is computed from the start of the slices and the first value
+    /// only provides the previous bar, so the long period is `close.len() - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `short_period` - Short period (typically 34)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()` != `volume.len()`
+    /// * `close.len()` < 2
+    /// * `short_period` == 0 or `short_period` > `close.len() - 1`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0, 104.0, 106.0];
+    /// let low = vec![99.0, 99.0, 100.0, 101.0, 102.0];
+    /// let close = vec![102.0, 100.0, 103.0, 102.0, 105.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1100.0];
+    ///
+    /// let klinger_volume_oscillator =
+    ///     centaur_technical_indicators::strength_indicators::single::klinger_volume_oscillator(
+    ///         &high,
+    ///         &low,
+    ///         &close,
+    ///         &volume,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(-3839.285714285714, klinger_volume_oscillator);
+    /// ```
+    pub fn klinger_volume_oscillator(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        short_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<f64> {
+        let volume_force = volume_force(high, low, close, volume)?;
+        assert_period(short_period, volume_force.len())?;
+        Ok(
+            constant_model_type
+                .central_value(&volume_force[volume_force.len() - short_period..])?
+                - constant_model_type.central_value(&volume_force)?,
+        )
+    }
+
+    /// Calculates the Volume Oscillator
+    ///
+    /// The percentage difference between a short and a long average of volume. The
+    /// long period is the length of the slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Slice of volumes
+    /// * `short_period` - Short period (typically 5)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `volume.is_empty()`
+    /// * `short_period` == 0 or `short_period` > `volume.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1100.0];
+    ///
+    /// let volume_oscillator =
+    ///     centaur_technical_indicators::strength_indicators::single::volume_oscillator(
+    ///         &volume,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(-12.280701754385966, volume_oscillator);
+    /// ```
+    pub fn volume_oscillator(
+        volume: &[f64],
+        short_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<f64> {
+        assert_non_empty("volume", volume)?;
+        assert_period(short_period, volume.len())?;
+        let short_average =
+            constant_model_type.central_value(&volume[volume.len() - short_period..])?;
+        let long_average = constant_model_type.central_value(volume)?;
+        Ok(100.0 * (short_average - long_average) / long_average)
+    }
+
+    /// Klinger volume force for every bar after the first.
+    ///
+    /// The trend compares `high + low + close` to the previous bar and the
+    /// cumulative measurement restarts when the trend changes.
+    pub(crate) fn volume_force(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+    ) -> Result<Vec<f64>> {
+        assert_same_len(&[
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_min_length("close", 2, close.len())?;
+
+        let mut volume_force = Vec::with_capacity(close.len() - 1);
+        let mut previous_trend = 0.0;
+        let mut previous_movement = high[0] - low[0];
+        let mut cumulative_movement = 0.0;
+        for i in 1..close.len() {
+            let trend = if high[i] + low[i] + close[i] > high[i - 1] + low[i - 1] + close[i - 1] {
+                1.0
+            } else {
+                -1.0
+            };
+            let movement = high[i] - low[i];
+            cumulative_movement = if trend == previous_trend {
+                cumulative_movement + movement
+            } else {
+                previous_movement + movement
+            };
+            let ratio = if cumulative_movement == 0.0 {
+                0.0
+            } else {
+                movement / cumulative_movement
+            };
+            volume_force.push(volume[i] * (2.0 * ratio - 1.0).abs() * trend * 100.0);
+            previous_trend = trend;
+            previous_movement = movement;
+        }
+        Ok(volume_force)
+    }
 }
 
 /// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
 pub mod bulk {
     use crate::strength_indicators::single;
     use crate::validation::{assert_non_empty, assert_period, assert_same_len};
-    use crate::{ConstantModelType, Result};
+    use crate::{CentralTendency, Result};
 
     /// Calculates the accumulation distribution
     ///
@@ -485,7 +847,7 @@ pub mod bulk {
     /// * `high` - Slice of highs
     /// * `low` - Slice of lows
     /// * `close` - Slice of closing prices
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `period` - Period over which to calculate the RVI
     ///
     /// # Returns
@@ -526,7 +888,7 @@ pub mod bulk {
         high: &[f64],
         low: &[f64],
         close: &[f64],
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
         period: usize,
     ) -> Result<Vec<f64>> {
         let length = open.len();
@@ -554,11 +916,382 @@ pub mod bulk {
                 &high[i..i + period],
                 &low[i..i + period],
                 &close[i..i + period],
-                constant_model_type,
+                &constant_model_type,
             )?);
         }
         Ok(rvis)
     }
+
+    /// Calculates the Chaikin Money Flow (CMF)
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `period` - Period over which to calculate the CMF (typically 20)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()` != `volume.len()`
+    /// * `period` == 0 or `period` > `close.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0, 104.0];
+    /// let low = vec![99.0, 99.0, 100.0, 101.0];
+    /// let close = vec![102.0, 100.0, 103.0, 102.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+    ///
+    /// let chaikin_money_flow =
+    ///     centaur_technical_indicators::strength_indicators::bulk::chaikin_money_flow(
+    ///         &high,
+    ///         &low,
+    ///         &close,
+    ///         &volume,
+    ///         3
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![0.06486486486486487, -0.15555555555555556],
+    ///     chaikin_money_flow
+    /// );
+    /// ```
+    pub fn chaikin_money_flow(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        period: usize,
+    ) -> Result<Vec<f64>> {
+        assert_same_len(&[
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_period(period, close.len())?;
+        (0..=close.len() - period)
+            .map(|i| {
+                single::chaikin_money_flow(
+                    &high[i..i + period],
+                    &low[i..i + period],
+                    &close[i..i + period],
+                    &volume[i..i + period],
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates Elder's Force Index
+    ///
+    /// # Arguments
+    ///
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `period` - Smoothing period (typically 13, 1 for the raw force)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the `period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.len()` != `volume.len()`
+    /// * `period` == 0 or `period` >= `close.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let close = vec![100.0, 102.0, 101.0, 104.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+    ///
+    /// let force_index = centaur_technical_indicators::strength_indicators::bulk::force_index(
+    ///     &close,
+    ///     &volume,
+    ///     2,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(vec![900.0, 750.0], force_index);
+    /// ```
+    pub fn force_index(
+        close: &[f64],
+        volume: &[f64],
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<Vec<f64>> {
+        assert_same_len(&[("close", close), ("volume", volume)])?;
+        assert_period(period + 1, close.len())?;
+        close
+            .windows(period + 1)
+            .zip(volume.windows(period + 1))
+            .map(|(c, v)| single::force_index(c, v, &constant_model_type))
+            .collect()
+    }
+
+    /// Calculates Elder Ray bull and bear power
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period of the close average (typically 13)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of (bull power, bear power) tuples
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()`
+    /// * `period` == 0 or `period` > `close.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0, 104.0];
+    /// let low = vec![99.0, 99.0, 100.0, 101.0];
+    /// let close = vec![102.0, 100.0, 103.0, 102.0];
+    ///
+    /// let elder_ray = centaur_technical_indicators::strength_indicators::bulk::elder_ray(
+    ///     &high,
+    ///     &low,
+    ///     &close,
+    ///     3,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (3.3333333333333286, -1.6666666666666714),
+    ///         (2.3333333333333286, -0.6666666666666714)
+    ///     ],
+    ///     elder_ray
+    /// );
+    /// ```
+    pub fn elder_ray(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<Vec<(f64, f64)>> {
+        assert_same_len(&[("high", high), ("low", low), ("close", close)])?;
+        assert_period(period, close.len())?;
+        (0..=close.len() - period)
+            .map(|i| {
+                single::elder_ray(
+                    &high[i..i + period],
+                    &low[i..i + period],
+                    &close[i..i + period],
+                    &constant_model_type,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Ease of Movement (EMV)
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `volume` - Slice of volumes
+    /// * `volume_divisor` - Scale applied to the volume (typically 100,000,000)
+    /// * `period` - Smoothing period (typically 14)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the `period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `volume.len()`
+    /// * `period` == 0 or `period` >= `high.len()`
+    /// * `volume_divisor` <= 0.0
+    /// * Any volume after the first is <= 0.0
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0, 104.0];
+    /// let low = vec![99.0, 99.0, 100.0, 101.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+    ///
+    /// let ease_of_movement =
+    ///     centaur_technical_indicators::strength_indicators::bulk::ease_of_movement(
+    ///         &high,
+    ///         &low,
+    ///         &volume,
+    ///         1000.0,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(vec![3.666666666666667, 4.166666666666667], ease_of_movement);
+    /// ```
+    pub fn ease_of_movement(
+        high: &[f64],
+        low: &[f64],
+        volume: &[f64],
+        volume_divisor: f64,
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<Vec<f64>> {
+        assert_same_len(&[("high", high), ("low", low), ("volume", volume)])?;
+        assert_period(period + 1, high.len())?;
+        (0..high.len() - period)
+            .map(|i| {
+                let end = i + period + 1;
+                single::ease_of_movement(
+                    &high[i..end],
+                    &low[i..end],
+                    &volume[i..end],
+                    volume_divisor,
+                    &constant_model_type,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Klinger Volume Oscillator (KVO)
+    ///
+    /// The volume force is computed once over the whole series, so values can
+    /// differ from [`single::klinger_volume_oscillator`] on the same window.
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `volume` - Slice of volumes
+    /// * `short_period` - Short period (typically 34)
+    /// * `long_period` - Long period (typically 55)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the `long_period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `high.len()` != `low.len()` != `close.len()` != `volume.len()`
+    /// * `short_period` == 0 or `short_period` > `long_period`
+    /// * `long_period` >= `close.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let high = vec![103.0, 102.0, 105.0, 104.0, 106.0];
+    /// let low = vec![99.0, 99.0, 100.0, 101.0, 102.0];
+    /// let close = vec![102.0, 100.0, 103.0, 102.0, 105.0];
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1100.0];
+    ///
+    /// let klinger_volume_oscillator =
+    ///     centaur_technical_indicators::strength_indicators::bulk::klinger_volume_oscillator(
+    ///         &high,
+    ///         &low,
+    ///         &close,
+    ///         &volume,
+    ///         2,
+    ///         3,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![8392.857142857145, -11130.952380952382],
+    ///     klinger_volume_oscillator
+    /// );
+    /// ```
+    pub fn klinger_volume_oscillator(
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<Vec<f64>> {
+        let volume_force = single::volume_force(high, low, close, volume)?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period, volume_force.len())?;
+        volume_force
+            .windows(long_period)
+            .map(|window| {
+                Ok(
+                    constant_model_type.central_value(&window[long_period - short_period..])?
+                        - constant_model_type.central_value(window)?,
+                )
+            })
+            .collect()
+    }
+
+    /// Calculates the Volume Oscillator
+    ///
+    /// # Arguments
+    ///
+    /// * `volume` - Slice of volumes
+    /// * `short_period` - Short period (typically 5)
+    /// * `long_period` - Long period (typically 10)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `volume.is_empty()`
+    /// * `short_period` == 0, `short_period` > `long_period` or `long_period` > `volume.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1100.0];
+    ///
+    /// let volume_oscillator =
+    ///     centaur_technical_indicators::strength_indicators::bulk::volume_oscillator(
+    ///         &volume,
+    ///         2,
+    ///         4,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![-8.695652173913043, -14.893617021276595],
+    ///     volume_oscillator
+    /// );
+    /// ```
+    pub fn volume_oscillator(
+        volume: &[f64],
+        short_period: usize,
+        long_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> Result<Vec<f64>> {
+        assert_non_empty("volume", volume)?;
+        assert_period(short_period, long_period)?;
+        assert_period(long_period, volume.len())?;
+        volume
+            .windows(long_period)
+            .map(|window| single::volume_oscillator(window, short_period, &constant_model_type))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -898,7 +1631,7 @@ mod tests {
                 &high,
                 &low,
                 &close,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -1189,4 +1922,172 @@ mod tests {
         )
         .is_err());
     }
+
+    #[test]
+    fn single_chaikin_money_flow_flat_bar_ignored() {
+        let high = vec![103.0, 100.0];
+        let low = vec![99.0, 100.0];
+        let close = vec![103.0, 100.0];
+        let volume = vec![1000.0, 1000.0];
+        assert_eq!(
+            0.5,
+            single::chaikin_money_flow(&high, &low, &close, &volume).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_chaikin_money_flow_no_volume() {
+        let high = vec![103.0, 102.0];
+        let low = vec![99.0, 99.0];
+        let close = vec![102.0, 100.0];
+        let volume = vec![0.0, 0.0];
+        assert_eq!(
+            0.0,
+            single::chaikin_money_flow(&high, &low, &close, &volume).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_chaikin_money_flow_error() {
+        let high = vec![103.0, 102.0];
+        let low = vec![99.0];
+        assert!(single::chaikin_money_flow(&high, &low, &high, &high).is_err());
+        assert!(single::chaikin_money_flow(&[], &[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn bulk_chaikin_money_flow_error() {
+        let high = vec![103.0, 102.0, 105.0];
+        assert!(bulk::chaikin_money_flow(&high, &high, &high, &high, 4).is_err());
+    }
+
+    #[test]
+    fn single_force_index_median() {
+        let close = vec![100.0, 102.0, 101.0, 104.0];
+        let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+        assert_eq!(
+            2700.0,
+            single::force_index(
+                &close,
+                &volume,
+                crate::ConstantModelType::SimpleMovingMedian
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_force_index_error() {
+        let close = vec![100.0, 102.0, 101.0, 104.0];
+        let volume = vec![1000.0, 1500.0, 1200.0, 900.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::force_index(&close, &volume, 4, model).is_err());
+        assert!(bulk::force_index(&close, &volume[1..], 1, model).is_err());
+    }
+
+    #[test]
+    fn bulk_elder_ray() {
+        let high = vec![103.0, 102.0, 105.0, 104.0];
+        let low = vec![99.0, 99.0, 100.0, 101.0];
+        let close = vec![102.0, 100.0, 103.0, 102.0];
+        let result = bulk::elder_ray(
+            &high,
+            &low,
+            &close,
+            1,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![(1.0, -3.0), (2.0, -1.0), (2.0, -3.0), (2.0, -1.0)],
+            result
+        );
+    }
+
+    #[test]
+    fn single_ease_of_movement_zero_volume_error() {
+        let high = vec![103.0, 102.0, 105.0];
+        let low = vec![99.0, 99.0, 100.0];
+        let volume = vec![1000.0, 0.0, 1200.0];
+        assert!(single::ease_of_movement(
+            &high,
+            &low,
+            &volume,
+            1000.0,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_ease_of_movement_divisor_error() {
+        let high = vec![103.0, 102.0, 105.0];
+        let low = vec![99.0, 99.0, 100.0];
+        let volume = vec![1000.0, 1500.0, 1200.0];
+        assert!(single::ease_of_movement(
+            &high,
+            &low,
+            &volume,
+            0.0,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn bulk_ease_of_movement_rising() {
+        let high = vec![101.0, 102.0, 103.0, 104.0];
+        let low = vec![99.0, 100.0, 101.0, 102.0];
+        let volume = vec![1000.0, 1000.0, 1000.0, 1000.0];
+        assert_eq!(
+            vec![2.0, 2.0, 2.0],
+            bulk::ease_of_movement(
+                &high,
+                &low,
+                &volume,
+                1000.0,
+                1,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn single_volume_force() {
+        let high = vec![103.0, 102.0, 105.0, 104.0, 106.0];
+        let low = vec![99.0, 99.0, 100.0, 101.0, 102.0];
+        let close = vec![102.0, 100.0, 103.0, 102.0, 105.0];
+        let volume = vec![1000.0, 1500.0, 1200.0, 900.0, 1100.0];
+        assert_eq!(
+            vec![-21428.571428571435, 30000.0, -22500.0, 15714.285714285708],
+            single::volume_force(&high, &low, &close, &volume).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_klinger_volume_oscillator_error() {
+        let high = vec![103.0, 102.0, 105.0, 104.0, 106.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::klinger_volume_oscillator(&high, &high, &high, &high, 2, 5, model).is_err());
+        assert!(bulk::klinger_volume_oscillator(&high, &high, &high, &high, 3, 2, model).is_err());
+    }
+
+    #[test]
+    fn single_volume_oscillator_constant_volume() {
+        let volume = vec![1000.0; 5];
+        assert_eq!(
+            0.0,
+            single::volume_oscillator(&volume, 2, crate::ConstantModelType::SimpleMovingAverage)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_volume_oscillator_error() {
+        let volume = vec![1000.0, 1500.0, 1200.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::volume_oscillator(&volume, 2, 4, model).is_err());
+        assert!(bulk::volume_oscillator(&volume, 3, 2, model).is_err());
+    }
 }
@@ -0,0 +1,456 @@
+//! # Timeframes
+//!
+//! The `timeframes` module resamples OHLCV bars to a coarser timeframe and aligns
+//! higher timeframe indicators back onto the original bars without look-ahead.
+//!
+//! ## When to Use
+//! Use timeframe functions when you want to:
+//! - Build daily bars from hourly bars (or any coarser timeframe from a finer one)
+//! - Use a higher timeframe indicator as a filter or feature on lower timeframe bars
+//! - Backtest multi-timeframe strategies without leaking unfinished higher timeframe bars
+//!
+//! ## Structure
+//! - **bulk**: Functions that resample or align slices of bars and return vectors.
+//!
+//! ## Included Indicators
+//!
+//! ### Bulk
+//! - [`forward_fill`](bulk::forward_fill): Aligns higher timeframe values onto lower timeframe bars
+//! - [`multi_timeframe`](bulk::multi_timeframe): Resamples, runs an indicator and aligns it back
+//! - [`resample`](bulk::resample): Aggregates OHLCV bars into a coarser timeframe
+//!
+//! ## API Details
+//! - Timestamps mark the start of each bar, are integers in any unit and must increase.
+//! - Higher timeframe bars are aligned to multiples of the interval, like [`time_bars`](crate::bars::bulk::time_bars).
+//! - A higher timeframe bar only becomes visible to lower timeframe bars starting at or after its end,
+//!   so a lower timeframe bar never sees the higher timeframe bar it belongs to.
+//! - Indicator values are aligned to the end of the higher timeframe bars, matching the bulk
+//!   functions of the other modules that drop their warm-up period.
+//! - See each function for further details, panics, and usage examples.
+//!
+//! ---
+
+/// **bulk**: Functions that resample or align slices of bars and return vectors
+pub mod bulk {
+    use crate::bars::{Bar, Bars};
+    use crate::validation::{assert_increasing, assert_non_empty, assert_same_len};
+    use crate::TechnicalIndicatorError;
+
+    /// Aggregates OHLCV bars into bars of a coarser timeframe
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar start timestamps
+    /// * `open` - Slice of opens
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    /// * `volume` - Slice of volumes
+    /// * `interval` - Duration of the coarser bars in timestamp units
+    ///
+    /// # Returns
+    ///
+    /// [`Bars`] with one bar per interval that contains bars
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `open.is_empty()`
+    /// * The slices are not all the same length
+    /// * `interval` <= 0
+    /// * `timestamps` are not increasing
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 1, 2, 3, 4];
+    /// let open = vec![10.0, 11.0, 12.0, 11.0, 10.0];
+    /// let high = vec![11.5, 12.5, 12.5, 11.5, 11.0];
+    /// let low = vec![9.5, 10.5, 11.0, 10.0, 9.0];
+    /// let close = vec![11.0, 12.0, 11.0, 10.0, 10.5];
+    /// let volume = vec![100.0, 200.0, 150.0, 120.0, 80.0];
+    ///
+    /// let bars = centaur_technical_indicators::timeframes::bulk::resample(
+    ///     &timestamps, &open, &high, &low, &close, &volume, 3
+    /// ).unwrap();
+    /// assert_eq!(vec![0, 3], bars.timestamps);
+    /// assert_eq!(vec![10.0, 11.0], bars.open);
+    /// assert_eq!(vec![12.5, 11.5], bars.high);
+    /// assert_eq!(vec![9.5, 9.0], bars.low);
+    /// assert_eq!(vec![11.0, 10.5], bars.close);
+    /// assert_eq!(vec![450.0, 200.0], bars.volume);
+    /// ```
+    pub fn resample(
+        timestamps: &[i64],
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        interval: i64,
+    ) -> crate::Result<Bars> {
+        assert_same_len(&[
+            ("open", open),
+            ("high", high),
+            ("low", low),
+            ("close", close),
+            ("volume", volume),
+        ])?;
+        assert_non_empty("open", open)?;
+        assert_timestamps("timestamps", timestamps, open.len())?;
+        assert_interval(interval)?;
+
+        let mut bars = Bars::default();
+        let mut current: Option<Bar> = None;
+        for i in 0..open.len() {
+            let start = timestamps[i].div_euclid(interval) * interval;
+            current = match current {
+                Some(mut bar) if bar.timestamp == start => {
+                    bar.high = bar.high.max(high[i]);
+                    bar.low = bar.low.min(low[i]);
+                    bar.close = close[i];
+                    bar.volume += volume[i];
+                    bar.trades += 1;
+                    Some(bar)
+                }
+                previous => {
+                    if let Some(bar) = previous {
+                        bars.push(bar);
+                    }
+                    Some(Bar {
+                        timestamp: start,
+                        open: open[i],
+                        high: high[i],
+                        low: low[i],
+                        close: close[i],
+                        volume: volume[i],
+                        trades: 1,
+                    })
+                }
+            };
+        }
+        if let Some(bar) = current {
+            bars.push(bar);
+        }
+        Ok(bars)
+    }
+
+    /// Aligns higher timeframe values onto lower timeframe bars
+    ///
+    /// Each lower timeframe bar gets the value of the latest higher timeframe bar
+    /// that ended at or before the lower timeframe bar started.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of lower timeframe bar start timestamps
+    /// * `higher_timestamps` - Slice of higher timeframe bar start timestamps
+    /// * `interval` - Duration of the higher timeframe bars in timestamp units
+    /// * `values` - Higher timeframe values, aligned to the end of `higher_timestamps`
+    ///
+    /// # Returns
+    ///
+    /// A vector with one value per lower timeframe bar, `None` until the first
+    /// higher timeframe value is complete
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `timestamps.is_empty()` or `higher_timestamps.is_empty()`
+    /// * `values.len()` > `higher_timestamps.len()`
+    /// * `interval` <= 0
+    /// * Either set of timestamps is not increasing
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let higher_timestamps = vec![0, 3, 6];
+    /// let values = vec![11.0, 10.5, 10.0];
+    ///
+    /// let aligned = centaur_technical_indicators::timeframes::bulk::forward_fill(
+    ///     &timestamps, &higher_timestamps, 3, &values
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![None, None, None, Some(11.0), Some(11.0), Some(11.0), Some(10.5)],
+    ///     aligned
+    /// );
+    /// ```
+    pub fn forward_fill<T: Copy>(
+        timestamps: &[i64],
+        higher_timestamps: &[i64],
+        interval: i64,
+        values: &[T],
+    ) -> crate::Result<Vec<Option<T>>> {
+        assert_non_empty("timestamps", timestamps)?;
+        assert_non_empty("higher_timestamps", higher_timestamps)?;
+        assert_timestamps("timestamps", timestamps, timestamps.len())?;
+        assert_timestamps(
+            "higher_timestamps",
+            higher_timestamps,
+            higher_timestamps.len(),
+        )?;
+        assert_interval(interval)?;
+        if values.len() > higher_timestamps.len() {
+            return Err(TechnicalIndicatorError::Custom {
+                message: format!(
+                    "values ({}) cannot be longer than higher_timestamps ({})",
+                    values.len(),
+                    higher_timestamps.len()
+                ),
+            });
+        }
+
+        let offset = higher_timestamps.len() - values.len();
+        let mut completed = 0;
+        Ok(timestamps
+            .iter()
+            .map(|&timestamp| {
+                while completed < higher_timestamps.len()
+                    && higher_timestamps[completed] + interval <= timestamp
+                {
+                    completed += 1;
+                }
+                if completed > offset {
+                    Some(values[completed - 1 - offset])
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Resamples bars to a higher timeframe, runs an indicator on them and
+    /// aligns the result back onto the original bars
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - Slice of bar start timestamps
+    /// * `open` - Slice of opens
+    /// * `high` - Slice of highs
+    /// * `low` - Slice of lows
+    /// * `close` - Slice of closes
+    /// * `volume` - Slice of volumes
+    /// * `interval` - Duration of the higher timeframe bars in timestamp units
+    /// * `indicator` - Function computing the indicator on the higher timeframe [`Bars`]
+    ///
+    /// # Returns
+    ///
+    /// A vector with one value per original bar, `None` until the first higher
+    /// timeframe value is complete
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * [`resample`] or [`forward_fill`] fails
+    /// * `indicator` returns an error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let timestamps = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let prices = vec![10.0, 11.0, 12.0, 11.0, 10.0, 10.5, 11.5];
+    /// let volume = vec![100.0; 7];
+    ///
+    /// let aligned = centaur_technical_indicators::timeframes::bulk::multi_timeframe(
+    ///     &timestamps,
+    ///     &prices,
+    ///     &prices,
+    ///     &prices,
+    ///     &prices,
+    ///     &volume,
+    ///     3,
+    ///     |bars| centaur_technical_indicators::moving_average::bulk::moving_average(
+    ///         &bars.close,
+    ///         centaur_technical_indicators::MovingAverageType::Simple,
+    ///         2,
+    ///     ),
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![None, None, None, None, None, None, Some(11.25)],
+    ///     aligned
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn multi_timeframe<T, F>(
+        timestamps: &[i64],
+        open: &[f64],
+        high: &[f64],
+        low: &[f64],
+        close: &[f64],
+        volume: &[f64],
+        interval: i64,
+        indicator: F,
+    ) -> crate::Result<Vec<Option<T>>>
+    where
+        T: Copy,
+        F: Fn(&Bars) -> crate::Result<Vec<T>>,
+    {
+        let bars = resample(timestamps, open, high, low, close, volume, interval)?;
+        let values = indicator(&bars)?;
+        forward_fill(timestamps, &bars.timestamps, interval, &values)
+    }
+
+    /// Validates that there is one increasing timestamp per bar
+    fn assert_timestamps(name: &str, timestamps: &[i64], length: usize) -> crate::Result<()> {
+        if timestamps.len() != length {
+            return Err(TechnicalIndicatorError::MismatchedLength {
+                names: vec![
+                    (name.to_string(), timestamps.len()),
+                    ("bars".to_string(), length),
+                ],
+            });
+        }
+        assert_increasing(name, timestamps)
+    }
+
+    /// Validates that the interval is positive
+    fn assert_interval(interval: i64) -> crate::Result<()> {
+        if interval <= 0 {
+            return Err(TechnicalIndicatorError::InvalidValue {
+                name: "interval".to_string(),
+                value: interval as f64,
+                reason: "must be positive".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moving_average::bulk::moving_average;
+    use crate::MovingAverageType;
+
+    const HOUR: i64 = 3_600;
+    const DAY: i64 = 24 * HOUR;
+
+    /// Hourly bars for six days, 09:00 to 16:00
+    fn hourly() -> (Vec<i64>, Vec<f64>) {
+        let timestamps: Vec<i64> = (0..6)
+            .flat_map(|day| (9..17).map(move |hour| day * DAY + hour * HOUR))
+            .collect();
+        let prices = (0..timestamps.len())
+            .map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.1)
+            .collect();
+        (timestamps, prices)
+    }
+
+    fn daily_sma(timestamps: &[i64], prices: &[f64]) -> Vec<Option<f64>> {
+        let volume = vec![1.0; prices.len()];
+        bulk::multi_timeframe(
+            timestamps,
+            prices,
+            prices,
+            prices,
+            prices,
+            &volume,
+            DAY,
+            |bars| moving_average(&bars.close, MovingAverageType::Simple, 2),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn resample_hourly_to_daily() {
+        let (timestamps, prices) = hourly();
+        let volume = vec![1.0; prices.len()];
+        let bars = bulk::resample(
+            &timestamps,
+            &prices,
+            &prices,
+            &prices,
+            &prices,
+            &volume,
+            DAY,
+        )
+        .unwrap();
+        assert_eq!(6, bars.len());
+        assert_eq!(
+            vec![0, DAY, 2 * DAY, 3 * DAY, 4 * DAY, 5 * DAY],
+            bars.timestamps
+        );
+        assert_eq!(vec![8.0; 6], bars.volume);
+        assert_eq!(prices[7], bars.close[0]);
+        assert_eq!(prices[8], bars.open[1]);
+    }
+
+    #[test]
+    fn resample_skips_empty_intervals() {
+        let timestamps = vec![0, 1, 7];
+        let prices = vec![1.0, 2.0, 3.0];
+        let bars =
+            bulk::resample(&timestamps, &prices, &prices, &prices, &prices, &prices, 3).unwrap();
+        assert_eq!(vec![0, 6], bars.timestamps);
+    }
+
+    #[test]
+    fn resample_errors() {
+        let prices = vec![1.0, 2.0];
+        assert!(bulk::resample(&[0, 1], &prices, &prices, &prices, &prices, &prices, 0).is_err());
+        assert!(bulk::resample(&[1, 1], &prices, &prices, &prices, &prices, &prices, 2).is_err());
+        assert!(bulk::resample(&[0], &prices, &prices, &prices, &prices, &prices, 2).is_err());
+        assert!(
+            bulk::resample(&[0, 1], &prices, &prices, &prices, &prices[..1], &prices, 2).is_err()
+        );
+    }
+
+    #[test]
+    fn forward_fill_values_longer_than_bars_error() {
+        assert!(bulk::forward_fill(&[0, 1], &[0], 2, &[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn forward_fill_tuples() {
+        let aligned = bulk::forward_fill(&[0, 2, 4], &[0, 2], 2, &[(1.0, 2.0)]).unwrap();
+        assert_eq!(vec![None, None, Some((1.0, 2.0))], aligned);
+    }
+
+    #[test]
+    fn no_look_ahead_within_current_day() {
+        let (timestamps, prices) = hourly();
+        let aligned = daily_sma(&timestamps, &prices);
+        for (i, value) in aligned.iter().enumerate() {
+            if let Some(value) = value {
+                let day = timestamps[i] / DAY;
+                let previous_closes = [
+                    prices[(day as usize - 1) * 8 - 1],
+                    prices[day as usize * 8 - 1],
+                ];
+                assert_eq!((previous_closes[0] + previous_closes[1]) / 2.0, *value);
+            }
+        }
+    }
+
+    #[test]
+    fn no_look_ahead_when_future_changes() {
+        let (timestamps, prices) = hourly();
+        let baseline = daily_sma(&timestamps, &prices);
+        for cutoff in 1..prices.len() {
+            let mut changed = prices.clone();
+            for price in changed.iter_mut().skip(cutoff) {
+                *price *= 2.0;
+            }
+            let aligned = daily_sma(&timestamps, &changed);
+            assert_eq!(baseline[..cutoff], aligned[..cutoff]);
+        }
+    }
+
+    #[test]
+    fn no_look_ahead_truncated_history() {
+        let (timestamps, prices) = hourly();
+        let full = daily_sma(&timestamps, &prices);
+        for end in 9..=prices.len() {
+            let truncated = daily_sma(&timestamps[..end], &prices[..end]);
+            assert_eq!(full[..end], truncated[..]);
+        }
+    }
+
+    #[test]
+    fn values_only_appear_after_higher_bar_closes() {
+        let (timestamps, prices) = hourly();
+        let aligned = daily_sma(&timestamps, &prices);
+        let first = aligned.iter().position(|v| v.is_some()).unwrap();
+        assert_eq!(2 * DAY + 9 * HOUR, timestamps[first]);
+    }
+}
@@ -9,6 +9,7 @@
 //! - Quantify the strength or consistency of a trend for strategy design or signal filtering
 //! - Calculate trend-following stop-and-reverse systems (e.g., Parabolic SAR)
 //! - Explore relationships between price and volume for trend confirmation
+//! - Decide whether a market is trending or ranging (Choppiness, Vortex, Random Walk Index)
 //!
 //! ## Structure
 //! - **single**: Functions that return a single value for a slice of prices.
@@ -26,6 +27,11 @@
 //! - [`directional_movement_system`](bulk::directional_movement_system): Computes Directional Movement (+DI, -DI, ADX, ADXR)
 //! - [`volume_price_trend`](bulk::volume_price_trend): Computes the Volume Price Trend
 //! - [`true_strength_index`](bulk::true_strength_index): Computes the True Strength Index (TSI)
+//! - [`vortex_indicator`](bulk::vortex_indicator): Computes the Vortex Indicator (VI+, VI-)
+//! - [`mass_index`](bulk::mass_index): Computes Dorsey's Mass Index
+//! - [`choppiness_index`](bulk::choppiness_index): Computes the Choppiness Index
+//! - [`balance_of_power`](bulk::balance_of_power): Computes the Balance of Power
+//! - [`random_walk_index`](bulk::random_walk_index): Computes the Random Walk Index (high, low)
 //!
 //! ### Single
 //!
@@ -37,6 +43,11 @@
 //! - [`short_parabolic_time_price_system`](single::short_parabolic_time_price_system): Computes Parabolic SAR for short positions
 //! - [`volume_price_trend`](single::volume_price_trend): Computes the Volume Price Trend
 //! - [`true_strength_index`](single::true_strength_index): Computes the True Strength Index (TSI)
+//! - [`vortex_indicator`](single::vortex_indicator): Computes the Vortex Indicator (VI+, VI-)
+//! - [`mass_index`](single::mass_index): Computes Dorsey's Mass Index
+//! - [`choppiness_index`](single::choppiness_index): Computes the Choppiness Index
+//! - [`balance_of_power`](single::balance_of_power): Computes the Balance of Power
+//! - [`random_walk_index`](single::random_walk_index): Computes the Random Walk Index (high, low)
 //!
 //! ## API Details
 //! - See function-level documentation for arguments, panics, and usage examples.
@@ -45,15 +56,12 @@
 
 /// **single**: Functions that return a single value for a slice of prices.
 pub mod single {
-    use crate::basic_indicators::bulk::median as bulk_median;
-    use crate::basic_indicators::bulk::mode as bulk_mode;
-    use crate::basic_indicators::single::median as single_median;
-    use crate::basic_indicators::single::mode as single_mode;
     use crate::basic_indicators::single::{max, min};
-    use crate::moving_average::bulk::moving_average as bulk_ma;
-    use crate::moving_average::single::moving_average as single_ma;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
-    use crate::{ConstantModelType, MovingAverageType};
+    use crate::other_indicators::single::true_range;
+    use crate::validation::{
+        assert_min_length, assert_non_empty, assert_period, assert_positive_usize, assert_same_len,
+    };
+    use crate::CentralTendency;
 
     /// Calculates the Aroon up
     ///
@@ -329,9 +337,9 @@ pub mod single {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `first_constant_model` - Variant of [`ConstantModelType`]
+    /// * `first_constant_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `first_period` - Period over which to apply the first smoothing
-    /// * `second_constant_model` - Variant of [`ConstantModelType`]
+    /// * `second_constant_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Errors
     ///
@@ -354,9 +362,9 @@ pub mod single {
     /// ```
     pub fn true_strength_index(
         prices: &[f64],
-        first_constant_model: ConstantModelType,
+        first_constant_model: impl CentralTendency,
         first_period: usize,
-        second_constant_model: ConstantModelType,
+        second_constant_model: impl CentralTendency,
     ) -> crate::Result<f64> {
         assert_non_empty("prices", prices)?;
         let length = prices.len();
@@ -370,122 +378,306 @@ pub mod single {
             abs_price_momentum.push(diff.abs());
         }
 
-        let (initial_smoothing, abs_initial_smoothing) = match first_constant_model {
-            ConstantModelType::SimpleMovingAverage => (
-                bulk_ma(&price_momentum, MovingAverageType::Simple, first_period)?,
-                bulk_ma(&abs_price_momentum, MovingAverageType::Simple, first_period)?,
-            ),
-            ConstantModelType::SmoothedMovingAverage => (
-                bulk_ma(&price_momentum, MovingAverageType::Smoothed, first_period)?,
-                bulk_ma(
-                    &abs_price_momentum,
-                    MovingAverageType::Smoothed,
-                    first_period,
-                )?,
-            ),
-            ConstantModelType::ExponentialMovingAverage => (
-                bulk_ma(
-                    &price_momentum,
-                    MovingAverageType::Exponential,
-                    first_period,
-                )?,
-                bulk_ma(
-                    &abs_price_momentum,
-                    MovingAverageType::Exponential,
-                    first_period,
-                )?,
-            ),
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => (
-                bulk_ma(
-                    &price_momentum,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                    first_period,
-                )?,
-                bulk_ma(
-                    &abs_price_momentum,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                    first_period,
-                )?,
-            ),
-            ConstantModelType::SimpleMovingMedian => (
-                bulk_median(&price_momentum, first_period)?,
-                bulk_median(&abs_price_momentum, first_period)?,
-            ),
-            ConstantModelType::SimpleMovingMode => (
-                bulk_mode(&price_momentum, first_period)?,
-                bulk_mode(&abs_price_momentum, first_period)?,
-            ),
-            _ => return Err(unsupported_type("ConstantModelType")),
+        let first_smoothing = |momentum: &[f64]| {
+            momentum
+                .windows(first_period)
+                .map(|window| first_constant_model.central_value(window))
+                .collect::<crate::Result<Vec<f64>>>()
         };
+        let initial_smoothing = first_smoothing(&price_momentum)?;
+        let abs_initial_smoothing = first_smoothing(&abs_price_momentum)?;
 
-        let (second_smoothing, abs_second_smoothing) = match second_constant_model {
-            ConstantModelType::SimpleMovingAverage => (
-                single_ma(&initial_smoothing, MovingAverageType::Simple)?,
-                single_ma(&abs_initial_smoothing, MovingAverageType::Simple)?,
-            ),
-            ConstantModelType::SmoothedMovingAverage => (
-                single_ma(&initial_smoothing, MovingAverageType::Smoothed)?,
-                single_ma(&abs_initial_smoothing, MovingAverageType::Smoothed)?,
-            ),
-            ConstantModelType::ExponentialMovingAverage => (
-                single_ma(&initial_smoothing, MovingAverageType::Exponential)?,
-                single_ma(&abs_initial_smoothing, MovingAverageType::Exponential)?,
-            ),
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => (
-                single_ma(
-                    &initial_smoothing,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-                single_ma(
-                    &abs_initial_smoothing,
-                    MovingAverageType::Personalised {
-                        alpha_num,
-                        alpha_den,
-                    },
-                )?,
-            ),
-            ConstantModelType::SimpleMovingMedian => (
-                single_median(&initial_smoothing)?,
-                single_median(&abs_initial_smoothing)?,
-            ),
-            ConstantModelType::SimpleMovingMode => (
-                single_mode(&initial_smoothing)?,
-                single_mode(&abs_initial_smoothing)?,
-            ),
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let second_smoothing = second_constant_model.central_value(&initial_smoothing)?;
+        let abs_second_smoothing = second_constant_model.central_value(&abs_initial_smoothing)?;
         if abs_second_smoothing == 0.0 {
             Ok(0.0)
         } else {
             Ok(second_smoothing / abs_second_smoothing)
         }
     }
+
+    /// Calculates the Vortex Indicator
+    ///
+    /// The first value of each slice only provides the previous high, low and close,
+    /// so the period is `close.len() - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (VI+, VI-), (1.0, 1.0) when the true range sum is 0.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `close.len()` < 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let vortex = centaur_technical_indicators::trend_indicators::single::vortex_indicator(
+    ///     &highs, &lows, &close
+    /// ).unwrap();
+    /// assert_eq!((1.0, 0.9090909090909091), vortex);
+    /// ```
+    pub fn vortex_indicator(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+    ) -> crate::Result<(f64, f64)> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_length("close", 2, close.len())?;
+
+        let mut positive_movement = 0.0;
+        let mut negative_movement = 0.0;
+        let mut true_range_sum = 0.0;
+        for i in 1..close.len() {
+            positive_movement += (highs[i] - lows[i - 1]).abs();
+            negative_movement += (lows[i] - highs[i - 1]).abs();
+            true_range_sum += true_range(close[i - 1], highs[i], lows[i]);
+        }
+        if true_range_sum == 0.0 {
+            return Ok((1.0, 1.0));
+        }
+        Ok((
+            positive_movement / true_range_sum,
+            negative_movement / true_range_sum,
+        ))
+    }
+
+    /// Calculates Dorsey's Mass Index
+    ///
+    /// Sums the ratio of a single and a double smoothed high-low range. The sum
+    /// period is `highs.len() - 2 * (smoothing_period - 1)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `smoothing_period` - Period of each smoothing (typically 9)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `smoothing_period` == 0
+    /// * `highs.len()` < `2 * smoothing_period - 1`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+    ///
+    /// let mass_index = centaur_technical_indicators::trend_indicators::single::mass_index(
+    ///     &highs,
+    ///     &lows,
+    ///     2,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(3.090909090909091, mass_index);
+    /// ```
+    pub fn mass_index(
+        highs: &[f64],
+        lows: &[f64],
+        smoothing_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<f64> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_positive_usize("smoothing_period", smoothing_period)?;
+        assert_min_length("highs", 2 * smoothing_period - 1, highs.len())?;
+
+        let ranges: Vec<f64> = highs.iter().zip(lows).map(|(h, l)| h - l).collect();
+        let single_smoothed = ranges
+            .windows(smoothing_period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect::<crate::Result<Vec<f64>>>()?;
+        let double_smoothed = single_smoothed
+            .windows(smoothing_period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect::<crate::Result<Vec<f64>>>()?;
+        Ok(single_smoothed[smoothing_period - 1..]
+            .iter()
+            .zip(&double_smoothed)
+            .map(|(single, double)| single / double)
+            .sum())
+    }
+
+    /// Calculates the Choppiness Index
+    ///
+    /// Close to 100 when the market is ranging and close to 0 when it is trending.
+    /// The first value of each slice only provides the previous close, so the
+    /// period is `close.len() - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value, 100.0 when the high-low range is 0.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `close.len()` < 3
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let choppiness_index =
+    ///     centaur_technical_indicators::trend_indicators::single::choppiness_index(
+    ///         &highs, &lows, &close
+    ///     ).unwrap();
+    /// assert_eq!(56.87517618749675, choppiness_index);
+    /// ```
+    pub fn choppiness_index(highs: &[f64], lows: &[f64], close: &[f64]) -> crate::Result<f64> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_length("close", 3, close.len())?;
+
+        let period = close.len() - 1;
+        let true_range_sum: f64 = (1..close.len())
+            .map(|i| true_range(close[i - 1], highs[i], lows[i]))
+            .sum();
+        let range = max(&highs[1..])? - min(&lows[1..])?;
+        if range == 0.0 {
+            return Ok(100.0);
+        }
+        Ok(100.0 * (true_range_sum / range).log10() / (period as f64).log10())
+    }
+
+    /// Calculates the Balance of Power
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - Opening price
+    /// * `high` - High
+    /// * `low` - Low
+    /// * `close` - Closing price
+    ///
+    /// # Returns
+    ///
+    /// The calculated indicator value, 0.0 when `high` == `low`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let balance_of_power =
+    ///     centaur_technical_indicators::trend_indicators::single::balance_of_power(
+    ///         100.0, 104.0, 99.0, 103.0
+    ///     );
+    /// assert_eq!(0.6, balance_of_power);
+    /// ```
+    #[inline]
+    pub fn balance_of_power(open: f64, high: f64, low: f64, close: f64) -> f64 {
+        if high == low {
+            0.0
+        } else {
+            (close - open) / (high - low)
+        }
+    }
+
+    /// Calculates the Random Walk Index
+    ///
+    /// Compares each move over 2 to `close.len() - 1` periods to the distance a random
+    /// walk would travel, scaled by the average true range over the same lookback.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for the average true range
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (RWI high, RWI low)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `close.len()` < 3
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0];
+    ///
+    /// let random_walk_index =
+    ///     centaur_technical_indicators::trend_indicators::single::random_walk_index(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!((0.5454545454545454, 1.178511301977579), random_walk_index);
+    /// ```
+    pub fn random_walk_index(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<(f64, f64)> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_length("close", 3, close.len())?;
+
+        let last = close.len() - 1;
+        let true_ranges: Vec<f64> = (1..close.len())
+            .map(|i| true_range(close[i - 1], highs[i], lows[i]))
+            .collect();
+        let mut random_walk_high = f64::NEG_INFINITY;
+        let mut random_walk_low = f64::NEG_INFINITY;
+        for lookback in 2..close.len() {
+            let average_true_range =
+                constant_model_type.central_value(&true_ranges[true_ranges.len() - lookback..])?;
+            let random_walk = average_true_range * (lookback as f64).sqrt();
+            random_walk_high =
+                random_walk_high.max((highs[last] - lows[last - lookback]) / random_walk);
+            random_walk_low =
+                random_walk_low.max((highs[last - lookback] - lows[last]) / random_walk);
+        }
+        Ok((random_walk_high, random_walk_low))
+    }
 }
 
 /// **bulk**: Functions that compute values of a slice of prices over a period and return a vector.
 pub mod bulk {
-    use crate::basic_indicators::bulk::{median, mode};
     use crate::basic_indicators::single::{max, min};
-    use crate::moving_average::bulk::moving_average;
     use crate::other_indicators::bulk::true_range;
     use crate::trend_indicators::single;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
-    use crate::{ConstantModelType, MovingAverageType, Position, TechnicalIndicatorError};
+    use crate::validation::{
+        assert_min_period, assert_non_empty, assert_period, assert_positive_usize, assert_same_len,
+    };
+    use crate::CentralTendency;
+    use crate::{Position, TechnicalIndicatorError};
 
     /// Calculates the aroon up
     ///
@@ -871,7 +1063,7 @@ pub mod bulk {
     /// * `lows` - Slice of lows
     /// * `close` - Slice of closing prices
     /// * `period` - Period over which to calculate the DM
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
@@ -949,7 +1141,7 @@ pub mod bulk {
         lows: &[f64],
         close: &[f64],
         period: usize,
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<(f64, f64, f64, f64)>> {
         let length = highs.len();
         assert_non_empty("highs", highs)?;
@@ -999,31 +1191,10 @@ pub mod bulk {
             })
             .collect();
 
-        let adx = match constant_model_type {
-            ConstantModelType::SimpleMovingAverage => {
-                moving_average(&dx, MovingAverageType::Simple, period)?
-            }
-            ConstantModelType::SmoothedMovingAverage => {
-                moving_average(&dx, MovingAverageType::Smoothed, period)?
-            }
-            ConstantModelType::ExponentialMovingAverage => {
-                moving_average(&dx, MovingAverageType::Exponential, period)?
-            }
-            ConstantModelType::PersonalisedMovingAverage {
-                alpha_num,
-                alpha_den,
-            } => moving_average(
-                &dx,
-                MovingAverageType::Personalised {
-                    alpha_num,
-                    alpha_den,
-                },
-                period,
-            )?,
-            ConstantModelType::SimpleMovingMedian => median(&dx, period)?,
-            ConstantModelType::SimpleMovingMode => mode(&dx, period)?,
-            _ => return Err(unsupported_type("ConstantModelType")),
-        };
+        let adx = dx
+            .windows(period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect::<crate::Result<Vec<f64>>>()?;
 
         if adx.len() < period + 1 {
             return Err(TechnicalIndicatorError::InvalidPeriod {
@@ -1133,9 +1304,9 @@ pub mod bulk {
     /// # Arguments
     ///
     /// * `prices` - Slice of prices
-    /// * `first_constant_model` - Variant of [`ConstantModelType`]
+    /// * `first_constant_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `first_period` - Period for first smoothing
-    /// * `second_constant_model` - Variant of [`ConstantModelType`]
+    /// * `second_constant_model` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     /// * `second_period` - Period for second smoothing
     ///
     /// # Errors
@@ -1165,9 +1336,9 @@ pub mod bulk {
     #[inline]
     pub fn true_strength_index(
         prices: &[f64],
-        first_constant_model: ConstantModelType,
+        first_constant_model: impl CentralTendency,
         first_period: usize,
-        second_constant_model: ConstantModelType,
+        second_constant_model: impl CentralTendency,
         second_period: usize,
     ) -> crate::Result<Vec<f64>> {
         assert_non_empty("prices", prices)?;
@@ -1181,13 +1352,300 @@ pub mod bulk {
             .map(|i| {
                 single::true_strength_index(
                     &prices[i..i + period_sum],
-                    first_constant_model,
+                    &first_constant_model,
                     first_period,
-                    second_constant_model,
+                    &second_constant_model,
                 )
             })
             .collect::<crate::Result<Vec<_>>>()
     }
+
+    /// Calculates the Vortex Indicator
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period over which to calculate the indicator (typically 14)
+    ///
+    /// # Returns
+    ///
+    /// A vector of (VI+, VI-) tuples, the first one for the `period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `period` == 0 or `period` >= `close.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+    ///
+    /// let vortex = centaur_technical_indicators::trend_indicators::bulk::vortex_indicator(
+    ///     &highs, &lows, &close, 4
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     vec![(1.0, 0.9090909090909091), (1.0, 0.8333333333333334)],
+    ///     vortex
+    /// );
+    /// ```
+    pub fn vortex_indicator(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_period(period + 1, close.len())?;
+        (0..close.len() - period)
+            .map(|i| {
+                let end = i + period + 1;
+                single::vortex_indicator(&highs[i..end], &lows[i..end], &close[i..end])
+            })
+            .collect()
+    }
+
+    /// Calculates Dorsey's Mass Index
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `smoothing_period` - Period of each smoothing (typically 9)
+    /// * `sum_period` - Number of ratios summed (typically 25)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically exponential moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `highs.is_empty()`
+    /// * `highs.len()` != `lows.len()`
+    /// * `smoothing_period` == 0 or `sum_period` == 0
+    /// * `highs.len()` < `sum_period + 2 * (smoothing_period - 1)`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+    ///
+    /// let mass_index = centaur_technical_indicators::trend_indicators::bulk::mass_index(
+    ///     &highs,
+    ///     &lows,
+    ///     2,
+    ///     3,
+    ///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    /// ).unwrap();
+    /// assert_eq!(vec![3.090909090909091, 3.090909090909091], mass_index);
+    /// ```
+    pub fn mass_index(
+        highs: &[f64],
+        lows: &[f64],
+        smoothing_period: usize,
+        sum_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("highs", highs), ("lows", lows)])?;
+        assert_non_empty("highs", highs)?;
+        assert_positive_usize("smoothing_period", smoothing_period)?;
+        assert_positive_usize("sum_period", sum_period)?;
+        let window = sum_period + 2 * (smoothing_period - 1);
+        assert_period(window, highs.len())?;
+        highs
+            .windows(window)
+            .zip(lows.windows(window))
+            .map(|(h, l)| single::mass_index(h, l, smoothing_period, &constant_model_type))
+            .collect()
+    }
+
+    /// Calculates the Choppiness Index
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period over which to calculate the indicator (typically 14)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values, the first one for the `period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `period` < 2 or `period` >= `close.len()`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+    ///
+    /// let choppiness_index =
+    ///     centaur_technical_indicators::trend_indicators::bulk::choppiness_index(
+    ///         &highs, &lows, &close, 4
+    ///     ).unwrap();
+    /// assert_eq!(vec![56.87517618749675, 63.15172029168969], choppiness_index);
+    /// ```
+    pub fn choppiness_index(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_period(period + 1, 3, close.len())?;
+        (0..close.len() - period)
+            .map(|i| {
+                let end = i + period + 1;
+                single::choppiness_index(&highs[i..end], &lows[i..end], &close[i..end])
+            })
+            .collect()
+    }
+
+    /// Calculates the Balance of Power, smoothed over a period
+    ///
+    /// # Arguments
+    ///
+    /// * `open` - Slice of opening prices
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Smoothing period (typically 14, 1 for the raw values)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] (typically simple moving average)
+    ///
+    /// # Returns
+    ///
+    /// A vector of calculated values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `period` == 0 or `period` > `close.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let open = vec![100.0, 101.0, 103.0, 102.0];
+    /// let highs = vec![104.0, 103.0, 104.0, 102.0];
+    /// let lows = vec![99.0, 100.0, 101.0, 99.0];
+    /// let close = vec![103.0, 102.0, 102.0, 100.0];
+    ///
+    /// let balance_of_power =
+    ///     centaur_technical_indicators::trend_indicators::bulk::balance_of_power(
+    ///         &open,
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(vec![0.4666666666666667, 0.0, -0.5], balance_of_power);
+    /// ```
+    pub fn balance_of_power(
+        open: &[f64],
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<f64>> {
+        assert_same_len(&[
+            ("open", open),
+            ("highs", highs),
+            ("lows", lows),
+            ("close", close),
+        ])?;
+        assert_period(period, close.len())?;
+        let balance_of_power: Vec<f64> = (0..close.len())
+            .map(|i| single::balance_of_power(open[i], highs[i], lows[i], close[i]))
+            .collect();
+        balance_of_power
+            .windows(period)
+            .map(|window| constant_model_type.central_value(window))
+            .collect()
+    }
+
+    /// Calculates the Random Walk Index
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Longest lookback compared to a random walk (typically 14)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] used for the average true range
+    ///
+    /// # Returns
+    ///
+    /// A vector of (RWI high, RWI low) tuples, the first one for the `period + 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The slices are not all the same length
+    /// * `period` < 2 or `period` >= `close.len()`
+    /// * `constant_model_type` is unsupported
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+    /// let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+    /// let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+    ///
+    /// let random_walk_index =
+    ///     centaur_technical_indicators::trend_indicators::bulk::random_walk_index(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         4,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (0.5454545454545454, 1.178511301977579),
+    ///         (0.8081220356417685, 0.5196152422706632)
+    ///     ],
+    ///     random_walk_index
+    /// );
+    /// ```
+    pub fn random_walk_index(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<(f64, f64)>> {
+        assert_same_len(&[("highs", highs), ("lows", lows), ("close", close)])?;
+        assert_min_period(period + 1, 3, close.len())?;
+        (0..close.len() - period)
+            .map(|i| {
+                let end = i + period + 1;
+                single::random_walk_index(
+                    &highs[i..end],
+                    &lows[i..end],
+                    &close[i..end],
+                    &constant_model_type,
+                )
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1777,7 +2235,7 @@ mod tests {
                 &lows,
                 &close,
                 3_usize,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -1800,7 +2258,7 @@ mod tests {
             &lows,
             &close,
             3_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -1822,7 +2280,7 @@ mod tests {
             &lows,
             &close,
             3_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -1844,7 +2302,7 @@ mod tests {
             &lows,
             &close,
             3_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -1860,7 +2318,7 @@ mod tests {
             &lows,
             &close,
             3_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -1882,7 +2340,7 @@ mod tests {
             &lows,
             &close,
             3_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -2047,7 +2505,7 @@ mod tests {
                 &prices,
                 crate::ConstantModelType::SimpleMovingMedian,
                 5_usize,
-                crate::ConstantModelType::SimpleMovingMode
+                crate::ConstantModelType::SimpleMovingMode { precision: 1.0 }
             )
             .unwrap()
         );
@@ -2058,9 +2516,9 @@ mod tests {
         let prices = vec![100.14, 98.98, 99.07, 100.1, 99.96];
         let result = single::true_strength_index(
             &prices,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             5_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -2070,9 +2528,9 @@ mod tests {
         let prices = Vec::new();
         let result = single::true_strength_index(
             &prices,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             5_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
         );
         assert!(result.is_err());
     }
@@ -2100,9 +2558,9 @@ mod tests {
         let prices = vec![100.14, 98.98, 99.07, 100.1, 99.96, 99.52, 101.16];
         let result = bulk::true_strength_index(
             &prices,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             5_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             3_usize,
         );
         assert!(result.is_err());
@@ -2113,11 +2571,211 @@ mod tests {
         let prices = Vec::new();
         let result = bulk::true_strength_index(
             &prices,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             5_usize,
-            crate::ConstantModelType::SimpleMovingMode,
+            crate::ConstantModelType::SimpleMovingMode { precision: 1.0 },
             3_usize,
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn single_vortex_indicator_trending_up() {
+        let highs = vec![101.0, 102.0, 103.0, 104.0];
+        let lows = vec![99.0, 100.0, 101.0, 102.0];
+        let close = vec![100.0, 101.0, 102.0, 103.0];
+        let (positive, negative) = single::vortex_indicator(&highs, &lows, &close).unwrap();
+        assert!(positive > negative);
+        assert_eq!((1.5, 0.5), (positive, negative));
+    }
+
+    #[test]
+    fn single_vortex_indicator_flat() {
+        let prices = vec![100.0; 4];
+        assert_eq!(
+            (1.0, 1.0),
+            single::vortex_indicator(&prices, &prices, &prices).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_vortex_indicator_error() {
+        let highs = vec![101.0];
+        assert!(single::vortex_indicator(&highs, &highs, &highs).is_err());
+        assert!(single::vortex_indicator(&[101.0, 102.0], &[99.0], &[100.0, 101.0]).is_err());
+    }
+
+    #[test]
+    fn bulk_vortex_indicator() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+        let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+        assert_eq!(
+            vec![(1.0, 0.9090909090909091), (1.0, 0.8333333333333334)],
+            bulk::vortex_indicator(&highs, &lows, &close, 4).unwrap()
+        );
+        assert!(bulk::vortex_indicator(&highs, &lows, &close, 6).is_err());
+    }
+
+    #[test]
+    fn single_mass_index_constant_range() {
+        // A constant range gives a ratio of 1 for every summed value
+        let highs = vec![102.0; 7];
+        let lows = vec![100.0; 7];
+        assert_eq!(
+            3.0,
+            single::mass_index(
+                &highs,
+                &lows,
+                3,
+                crate::ConstantModelType::ExponentialMovingAverage
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_mass_index() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+        assert_eq!(
+            vec![3.090909090909091, 3.090909090909091],
+            bulk::mass_index(
+                &highs,
+                &lows,
+                2,
+                3,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+        assert!(bulk::mass_index(
+            &highs,
+            &lows,
+            2,
+            6,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_choppiness_index_trending() {
+        // Gapless trend: the sum of true ranges equals the total range
+        let highs = vec![101.0, 102.0, 103.0, 104.0];
+        let lows = vec![100.0, 101.0, 102.0, 103.0];
+        let close = vec![101.0, 102.0, 103.0, 104.0];
+        assert_eq!(
+            0.0,
+            single::choppiness_index(&highs, &lows, &close).unwrap()
+        );
+    }
+
+    #[test]
+    fn single_choppiness_index_flat() {
+        let prices = vec![100.0; 4];
+        assert_eq!(
+            100.0,
+            single::choppiness_index(&prices, &prices, &prices).unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_choppiness_index() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+        let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+        assert_eq!(
+            vec![56.87517618749675, 63.15172029168969],
+            bulk::choppiness_index(&highs, &lows, &close, 4).unwrap()
+        );
+        assert!(bulk::choppiness_index(&highs, &lows, &close, 1).is_err());
+    }
+
+    #[test]
+    fn single_balance_of_power() {
+        assert_eq!(-1.0, single::balance_of_power(104.0, 104.0, 99.0, 99.0));
+        assert_eq!(0.0, single::balance_of_power(100.0, 100.0, 100.0, 100.0));
+    }
+
+    #[test]
+    fn bulk_balance_of_power_raw() {
+        let open = vec![100.0, 101.0];
+        let highs = vec![104.0, 103.0];
+        let lows = vec![99.0, 100.0];
+        let close = vec![103.0, 102.0];
+        assert_eq!(
+            vec![0.6, 0.3333333333333333],
+            bulk::balance_of_power(
+                &open,
+                &highs,
+                &lows,
+                &close,
+                1,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn bulk_balance_of_power_error() {
+        let open = vec![100.0, 101.0];
+        let highs = vec![104.0, 103.0];
+        let lows = vec![99.0];
+        assert!(bulk::balance_of_power(
+            &open,
+            &highs,
+            &lows,
+            &open,
+            1,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn single_random_walk_index_trending_up() {
+        let highs = vec![101.0, 102.0, 103.0, 104.0];
+        let lows = vec![99.0, 100.0, 101.0, 102.0];
+        let close = vec![100.0, 101.0, 102.0, 103.0];
+        let (high, low) = single::random_walk_index(
+            &highs,
+            &lows,
+            &close,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert!(high > 1.0);
+        assert_eq!(0.0, low);
+    }
+
+    #[test]
+    fn bulk_random_walk_index() {
+        let highs = vec![101.0, 103.0, 104.0, 103.0, 102.0, 104.0];
+        let lows = vec![99.0, 100.0, 102.0, 100.0, 99.0, 101.0];
+        let close = vec![100.0, 102.0, 103.0, 101.0, 100.0, 103.0];
+        assert_eq!(
+            vec![
+                (0.5454545454545454, 1.178511301977579),
+                (0.8081220356417685, 0.5196152422706632)
+            ],
+            bulk::random_walk_index(
+                &highs,
+                &lows,
+                &close,
+                4,
+                crate::ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap()
+        );
+        assert!(bulk::random_walk_index(
+            &highs,
+            &lows,
+            &close,
+            1,
+            crate::ConstantModelType::SimpleMovingAverage
+        )
+        .is_err());
+    }
 }
@@ -1,22 +1,31 @@
+use crate::basic_indicators::single::{
+    absolute_deviation, cauchy_iqr_scale, empirical_quantile_range_from_distribution,
+    kernel_density_mode, laplace_std_equivalent, log_standard_deviation, median, mode,
+    standard_deviation, student_t_adjusted_std,
+};
+use crate::moving_average::single::moving_average;
+use crate::validation::unsupported_type;
+use crate::volatility_indicators::single::ulcer_index;
+
 /// What central value to use for calculations.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum CentralPoint {
     Mean,
     Median,
-    Mode,
+    Mode { precision: f64 },
 }
 
 /// How to aggregate a set of absolute deviations.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum DeviationAggregate {
     Mean,
     Median,
-    Mode,
+    Mode { precision: f64 },
 }
 
 /// Configuration that controls how absolute deviations are computed.
 /// Example: center = Median, aggregate = Median => true MedianAD (median of |x - median|).
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct AbsDevConfig {
     pub center: CentralPoint,
     pub aggregate: DeviationAggregate,
@@ -32,6 +41,9 @@ pub enum MovingAverageType {
 }
 
 /// Determines which constant model to use for a center point.
+///
+/// `SimpleMovingMode` buckets prices to multiples of `precision` (1.0 rounds to whole numbers),
+/// `KernelDensityMode` falls back to Silverman's rule of thumb when `bandwidth` is `None`.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum ConstantModelType {
     SimpleMovingAverage,
@@ -39,7 +51,40 @@ pub enum ConstantModelType {
     ExponentialMovingAverage,
     PersonalisedMovingAverage { alpha_num: f64, alpha_den: f64 },
     SimpleMovingMedian,
-    SimpleMovingMode,
+    SimpleMovingMode { precision: f64 },
+    KernelDensityMode { bandwidth: Option<f64> },
+}
+
+impl CentralTendency for ConstantModelType {
+    fn central_value(&self, prices: &[f64]) -> crate::Result<f64> {
+        match *self {
+            ConstantModelType::SimpleMovingAverage => {
+                moving_average(prices, MovingAverageType::Simple)
+            }
+            ConstantModelType::SmoothedMovingAverage => {
+                moving_average(prices, MovingAverageType::Smoothed)
+            }
+            ConstantModelType::ExponentialMovingAverage => {
+                moving_average(prices, MovingAverageType::Exponential)
+            }
+            ConstantModelType::PersonalisedMovingAverage {
+                alpha_num,
+                alpha_den,
+            } => moving_average(
+                prices,
+                MovingAverageType::Personalised {
+                    alpha_num,
+                    alpha_den,
+                },
+            ),
+            ConstantModelType::SimpleMovingMedian => median(prices),
+            ConstantModelType::SimpleMovingMode { precision } => mode(prices, precision),
+            ConstantModelType::KernelDensityMode { bandwidth } => {
+                kernel_density_mode(prices, bandwidth)
+            }
+            _ => Err(unsupported_type("ConstantModelType")),
+        }
+    }
 }
 
 /// How to measure deviation from a center point.
@@ -48,7 +93,7 @@ pub enum DeviationModel {
     StandardDeviation,
     MeanAbsoluteDeviation,
     MedianAbsoluteDeviation,
-    ModeAbsoluteDeviation,
+    ModeAbsoluteDeviation { precision: f64 },
     CustomAbsoluteDeviation { config: AbsDevConfig },
     UlcerIndex,
     LogStandardDeviation,
@@ -58,9 +103,391 @@ pub enum DeviationModel {
     EmpiricalQuantileRange { low: f64, high: f64, precision: f64 },
 }
 
+impl Deviation for DeviationModel {
+    fn deviation(&self, prices: &[f64]) -> crate::Result<f64> {
+        match *self {
+            DeviationModel::StandardDeviation => standard_deviation(prices),
+            DeviationModel::MeanAbsoluteDeviation => absolute_deviation(
+                prices,
+                AbsDevConfig {
+                    center: CentralPoint::Mean,
+                    aggregate: DeviationAggregate::Mean,
+                },
+            ),
+            DeviationModel::MedianAbsoluteDeviation => absolute_deviation(
+                prices,
+                AbsDevConfig {
+                    center: CentralPoint::Median,
+                    aggregate: DeviationAggregate::Median,
+                },
+            ),
+            DeviationModel::ModeAbsoluteDeviation { precision } => absolute_deviation(
+                prices,
+                AbsDevConfig {
+                    center: CentralPoint::Mode { precision },
+                    aggregate: DeviationAggregate::Mode { precision },
+                },
+            ),
+            DeviationModel::CustomAbsoluteDeviation { config } => {
+                absolute_deviation(prices, config)
+            }
+            DeviationModel::UlcerIndex => ulcer_index(prices),
+            DeviationModel::LogStandardDeviation => log_standard_deviation(prices),
+            DeviationModel::StudentT { df } => student_t_adjusted_std(prices, df),
+            DeviationModel::LaplaceStdEquivalent => laplace_std_equivalent(prices),
+            DeviationModel::CauchyIQRScale => cauchy_iqr_scale(prices),
+            DeviationModel::EmpiricalQuantileRange {
+                low,
+                high,
+                precision,
+            } => empirical_quantile_range_from_distribution(prices, precision, low, high),
+            _ => Err(unsupported_type("DeviationModel")),
+        }
+    }
+}
+
 /// Trade position.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Position {
     Short,
     Long,
 }
+
+/// Side that initiated a trade.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+    Unknown,
+}
+
+/// Price used as the source of an indicator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PriceSource {
+    /// Closing price
+    Close,
+    /// (high + low) / 2
+    MedianPrice,
+    /// (high + low + close) / 3
+    TypicalPrice,
+}
+
+/// A time-aligned Ichimoku Cloud bar. Values that are not defined at the bar are `None`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IchimokuBar {
+    /// Tenkan-sen
+    pub conversion_line: Option<f64>,
+    /// Kijun-sen
+    pub base_line: Option<f64>,
+    /// Senkou span A, computed `displacement` bars earlier
+    pub leading_span_a: Option<f64>,
+    /// Senkou span B, computed `displacement` bars earlier
+    pub leading_span_b: Option<f64>,
+    /// Chikou span, the close `displacement` bars later
+    pub lagging_span: Option<f64>,
+    /// Conversion line crossed above (`Long`) or below (`Short`) the base line on this bar
+    pub tk_cross: Option<Position>,
+    /// Close broke above (`Long`) or below (`Short`) the cloud on this bar
+    pub kumo_breakout: Option<Position>,
+    /// Bullish (`Long`) cloud when span A >= span B, bearish (`Short`) otherwise
+    pub cloud_colour: Option<Position>,
+}
+
+/// A model that reduces a slice of prices to a central value.
+///
+/// Indicators that smooth or center prices accept any `impl CentralTendency`, so a custom model
+/// (a trimmed mean, a Kalman smoother) works anywhere a [`ConstantModelType`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::CentralTendency;
+///
+/// struct TrimmedMean;
+///
+/// impl CentralTendency for TrimmedMean {
+///     fn central_value(&self, prices: &[f64]) -> centaur_technical_indicators::Result<f64> {
+///         let mut sorted = prices.to_vec();
+///         sorted.sort_by(|a, b| a.total_cmp(b));
+///         let trimmed = &sorted[1..sorted.len() - 1];
+///         Ok(trimmed.iter().sum::<f64>() / trimmed.len() as f64)
+///     }
+/// }
+///
+/// let prices = vec![100.0, 102.0, 103.0, 101.0, 150.0];
+/// let envelopes = centaur_technical_indicators::candle_indicators::single::moving_constant_envelopes(
+///     &prices, TrimmedMean, 3.0
+/// ).unwrap();
+/// assert_eq!((98.94, 102.0, 105.06), envelopes);
+/// ```
+pub trait CentralTendency {
+    /// Central value of `prices`
+    fn central_value(&self, prices: &[f64]) -> crate::Result<f64>;
+}
+
+impl<T: CentralTendency + ?Sized> CentralTendency for &T {
+    fn central_value(&self, prices: &[f64]) -> crate::Result<f64> {
+        (**self).central_value(prices)
+    }
+}
+
+/// A model that measures the dispersion of a slice of prices.
+///
+/// Indicators with bands or deviation scaling accept any `impl Deviation`, so a custom model
+/// works anywhere a [`DeviationModel`] does.
+///
+/// # Examples
+///
+/// ```rust
+/// use centaur_technical_indicators::Deviation;
+///
+/// struct Range;
+///
+/// impl Deviation for Range {
+///     fn deviation(&self, prices: &[f64]) -> centaur_technical_indicators::Result<f64> {
+///         let max = prices.iter().copied().fold(f64::MIN, f64::max);
+///         let min = prices.iter().copied().fold(f64::MAX, f64::min);
+///         Ok(max - min)
+///     }
+/// }
+///
+/// let prices = vec![100.0, 102.0, 103.0, 101.0, 99.0];
+/// let bands = centaur_technical_indicators::candle_indicators::single::moving_constant_bands(
+///     &prices,
+///     centaur_technical_indicators::ConstantModelType::SimpleMovingAverage,
+///     Range,
+///     0.5
+/// ).unwrap();
+/// assert_eq!((99.0, 101.0, 103.0), bands);
+/// ```
+pub trait Deviation {
+    /// Deviation of `prices`
+    fn deviation(&self, prices: &[f64]) -> crate::Result<f64>;
+}
+
+impl<T: Deviation + ?Sized> Deviation for &T {
+    fn deviation(&self, prices: &[f64]) -> crate::Result<f64> {
+        (**self).deviation(prices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_indicators;
+    use crate::correlation_indicators;
+    use crate::momentum_indicators;
+
+    /// User-defined equivalent of `ConstantModelType::SimpleMovingAverage`
+    struct Mean;
+
+    impl CentralTendency for Mean {
+        fn central_value(&self, prices: &[f64]) -> crate::Result<f64> {
+            crate::basic_indicators::single::mean(prices)
+        }
+    }
+
+    /// User-defined equivalent of `DeviationModel::StandardDeviation`
+    struct StandardDeviation;
+
+    impl Deviation for StandardDeviation {
+        fn deviation(&self, prices: &[f64]) -> crate::Result<f64> {
+            standard_deviation(prices)
+        }
+    }
+
+    const PRICES: [f64; 8] = [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0, 104.5];
+
+    #[test]
+    fn constant_model_type_central_value() {
+        let prices = [100.0, 102.0, 100.0, 106.0];
+        assert_eq!(
+            102.0,
+            ConstantModelType::SimpleMovingAverage
+                .central_value(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            101.0,
+            ConstantModelType::SimpleMovingMedian
+                .central_value(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            100.0,
+            ConstantModelType::SimpleMovingMode { precision: 1.0 }
+                .central_value(&prices)
+                .unwrap()
+        );
+        assert!(ConstantModelType::SimpleMovingAverage
+            .central_value(&[])
+            .is_err());
+    }
+
+    #[test]
+    fn deviation_model_deviation() {
+        let prices = [100.0, 102.0, 100.0, 106.0];
+        assert_eq!(
+            standard_deviation(&prices).unwrap(),
+            DeviationModel::StandardDeviation
+                .deviation(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            1.0,
+            DeviationModel::MedianAbsoluteDeviation
+                .deviation(&prices)
+                .unwrap()
+        );
+        assert!(DeviationModel::StandardDeviation.deviation(&[]).is_err());
+    }
+
+    #[test]
+    fn mode_models_precision() {
+        let prices = [1.0830, 1.0832, 1.0832, 1.0834, 1.0834];
+        assert_eq!(
+            1.0,
+            ConstantModelType::SimpleMovingMode { precision: 1.0 }
+                .central_value(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            1.0833000000000002,
+            ConstantModelType::SimpleMovingMode { precision: 0.0001 }
+                .central_value(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            0.0,
+            DeviationModel::ModeAbsoluteDeviation { precision: 1.0 }
+                .deviation(&prices)
+                .unwrap()
+        );
+        assert_eq!(
+            0.0001,
+            DeviationModel::ModeAbsoluteDeviation { precision: 0.0001 }
+                .deviation(&prices)
+                .unwrap()
+        );
+        assert!(ConstantModelType::SimpleMovingMode { precision: 0.0 }
+            .central_value(&prices)
+            .is_err());
+    }
+
+    #[test]
+    fn kernel_density_mode_model() {
+        let prices = [100.0, 101.0, 102.0];
+        assert_eq!(
+            101.0,
+            ConstantModelType::KernelDensityMode {
+                bandwidth: Some(1.0)
+            }
+            .central_value(&prices)
+            .unwrap()
+        );
+        assert_eq!(
+            kernel_density_mode(&PRICES, None).unwrap(),
+            ConstantModelType::KernelDensityMode { bandwidth: None }
+                .central_value(&PRICES)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reference_models() {
+        let (mean, standard_deviation) = (Mean, StandardDeviation);
+        let bands = candle_indicators::single::moving_constant_bands(
+            &PRICES,
+            &mean,
+            &standard_deviation,
+            2.0,
+        )
+        .unwrap();
+        assert_eq!(
+            bands,
+            candle_indicators::single::moving_constant_bands(
+                &PRICES,
+                mean,
+                standard_deviation,
+                2.0
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_models_in_indicators() {
+        assert_eq!(
+            momentum_indicators::single::relative_strength_index(
+                &PRICES,
+                ConstantModelType::SimpleMovingAverage
+            )
+            .unwrap(),
+            momentum_indicators::single::relative_strength_index(&PRICES, Mean).unwrap()
+        );
+        assert_eq!(
+            momentum_indicators::bulk::relative_strength_index(
+                &PRICES,
+                ConstantModelType::SimpleMovingAverage,
+                5
+            )
+            .unwrap(),
+            momentum_indicators::bulk::relative_strength_index(&PRICES, Mean, 5).unwrap()
+        );
+        assert_eq!(
+            momentum_indicators::bulk::commodity_channel_index(
+                &PRICES,
+                ConstantModelType::SimpleMovingAverage,
+                DeviationModel::StandardDeviation,
+                0.015,
+                4
+            )
+            .unwrap(),
+            momentum_indicators::bulk::commodity_channel_index(
+                &PRICES,
+                Mean,
+                StandardDeviation,
+                0.015,
+                4
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            candle_indicators::bulk::moving_constant_bands(
+                &PRICES,
+                ConstantModelType::SimpleMovingAverage,
+                DeviationModel::StandardDeviation,
+                2.0,
+                4
+            )
+            .unwrap(),
+            candle_indicators::bulk::moving_constant_bands(
+                &PRICES,
+                Mean,
+                StandardDeviation,
+                2.0,
+                4
+            )
+            .unwrap()
+        );
+        let other: Vec<f64> = PRICES.iter().map(|price| 200.0 - price * 0.5).collect();
+        assert_eq!(
+            correlation_indicators::bulk::correlate_asset_prices(
+                &PRICES,
+                &other,
+                ConstantModelType::SimpleMovingAverage,
+                DeviationModel::StandardDeviation,
+                4
+            )
+            .unwrap(),
+            correlation_indicators::bulk::correlate_asset_prices(
+                &PRICES,
+                &other,
+                Mean,
+                StandardDeviation,
+                4
+            )
+            .unwrap()
+        );
+    }
+}
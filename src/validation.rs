@@ -178,6 +178,50 @@ pub fn assert_min_length(name: &str, min_length: usize, actual_length: usize) ->
     Ok(())
 }
 
+/// Validates that a value is not negative (>= 0)
+///
+/// # Arguments
+///
+/// * `name` - Human-readable name of the value
+/// * `value` - The value to validate
+///
+/// # Errors
+///
+/// Returns `TechnicalIndicatorError::InvalidValue` if the value is negative or NaN
+#[inline]
+pub fn assert_non_negative(name: &str, value: f64) -> crate::Result<()> {
+    if value < 0.0 || value.is_nan() {
+        return Err(crate::TechnicalIndicatorError::InvalidValue {
+            name: name.to_string(),
+            value,
+            reason: "must be greater than or equal to 0".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Validates that a value is finite (not NaN or ±infinity)
+///
+/// # Arguments
+///
+/// * `name` - Human-readable name of the value
+/// * `value` - The value to validate
+///
+/// # Errors
+///
+/// Returns `TechnicalIndicatorError::InvalidValue` if the value is NaN or infinite
+#[inline]
+pub fn assert_finite(name: &str, value: f64) -> crate::Result<()> {
+    if !value.is_finite() {
+        return Err(crate::TechnicalIndicatorError::InvalidValue {
+            name: name.to_string(),
+            value,
+            reason: "must be finite".to_string(),
+        });
+    }
+    Ok(())
+}
+
 /// Validates that a usize value is positive (> 0)
 ///
 /// # Arguments
@@ -200,6 +244,32 @@ pub fn assert_positive_usize(name: &str, value: usize) -> crate::Result<()> {
     Ok(())
 }
 
+/// Validates that timestamps are strictly increasing
+///
+/// # Arguments
+///
+/// * `name` - Human-readable name of the timestamps
+/// * `timestamps` - The timestamps to validate
+///
+/// # Errors
+///
+/// Returns `TechnicalIndicatorError::InvalidValue` for the first timestamp that
+/// is not greater than the one before it
+#[inline]
+pub fn assert_increasing(name: &str, timestamps: &[i64]) -> crate::Result<()> {
+    if let Some(window) = timestamps.windows(2).find(|w| w[1] <= w[0]) {
+        return Err(crate::TechnicalIndicatorError::InvalidValue {
+            name: name.to_string(),
+            value: window[1] as f64,
+            reason: format!(
+                "must be greater than the previous timestamp ({})",
+                window[0]
+            ),
+        });
+    }
+    Ok(())
+}
+
 /// Returns an error indicating that a type variant is not supported
 ///
 /// # Arguments
@@ -326,4 +396,41 @@ mod tests {
             _ => panic!("Expected InvalidPeriod error"),
         }
     }
+
+    #[test]
+    fn test_assert_non_negative_ok() {
+        assert!(assert_non_negative("noise", 0.0).is_ok());
+        assert!(assert_non_negative("noise", 1.5).is_ok());
+    }
+
+    #[test]
+    fn test_assert_non_negative_fail() {
+        assert!(assert_non_negative("noise", -0.1).is_err());
+        assert!(assert_non_negative("noise", f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_assert_finite_ok() {
+        assert!(assert_finite("volume", 0.0).is_ok());
+        assert!(assert_finite("volume", -1.5).is_ok());
+    }
+
+    #[test]
+    fn test_assert_finite_fail() {
+        assert!(assert_finite("volume", f64::INFINITY).is_err());
+        assert!(assert_finite("volume", f64::NEG_INFINITY).is_err());
+        assert!(assert_finite("volume", f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_assert_increasing_ok() {
+        assert!(assert_increasing("timestamps", &[1, 2, 5]).is_ok());
+        assert!(assert_increasing("timestamps", &[]).is_ok());
+    }
+
+    #[test]
+    fn test_assert_increasing_fail() {
+        assert!(assert_increasing("timestamps", &[1, 2, 2]).is_err());
+        assert!(assert_increasing("timestamps", &[3, 1]).is_err());
+    }
 }
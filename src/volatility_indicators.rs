@@ -7,6 +7,7 @@
 //! - Analyze the degree of price fluctuations or drawdowns in an asset
 //! - Quantify downside volatility (Ulcer Index) instead of standard deviation
 //! - Identify changing risk conditions to adjust strategy parameters
+//! - Place volatility based stops (Chandelier Exit, Chande Kroll Stop, ATR trailing stop)
 //!
 //! ## Structure
 //! - **single**: Functions that return a single value for a slice of prices.
@@ -16,6 +17,9 @@
 //!
 //! ## Bulk
 //!
+//! - [`atr_trailing_stop`](bulk::atr_trailing_stop): Ratcheting ATR trailing stop with position
+//! - [`chande_kroll_stop`](bulk::chande_kroll_stop): Chande Kroll long and short stops with position
+//! - [`chandelier_exit`](bulk::chandelier_exit): Chandelier Exit long and short stops with position
 //! - [`ulcer_index`](bulk::ulcer_index): Calculates the Ulcer Index
 //! - [`volatility_system`](bulk::volatility_system): Implements Welles Wilder's volatility system, including ATR and SAR logic
 //!
@@ -73,9 +77,13 @@ pub mod bulk {
     use crate::basic_indicators::single::{max, min};
     use crate::chart_trends::overall_trend;
     use crate::other_indicators::bulk::average_true_range;
-    use crate::validation::{assert_non_empty, assert_period, assert_same_len, unsupported_type};
+    use crate::validation::{
+        assert_non_empty, assert_period, assert_positive, assert_positive_usize, assert_same_len,
+        unsupported_type,
+    };
     use crate::volatility_indicators::single;
-    use crate::{ConstantModelType, Position};
+    use crate::CentralTendency;
+    use crate::Position;
 
     /// Calculates the Ulcer Index
     ///
@@ -125,7 +133,7 @@ pub mod bulk {
     /// * `close` - Slice of closing prices
     /// * `period` - Period over which to calculate the volatility system
     /// * `constant_multiplier` - Mulitplier for ATR
-    /// * `constant_model_type` - Variant of [`ConstantModelType`]
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`]
     ///
     /// # Returns
     ///
@@ -197,7 +205,7 @@ pub mod bulk {
         close: &[f64],
         period: usize,
         constant_multiplier: f64,
-        constant_model_type: ConstantModelType,
+        constant_model_type: impl CentralTendency,
     ) -> crate::Result<Vec<f64>> {
         let length = close.len();
         assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
@@ -255,6 +263,332 @@ pub mod bulk {
         }
         Ok(sars)
     }
+
+    /// Calculates the Chandelier Exit
+    ///
+    /// The long stop hangs a multiple of the ATR below the highest high and the short
+    /// stop sits the same distance above the lowest low. The position flips when the
+    /// close crosses the previous bar's stop for the current position. The first
+    /// position follows the trend of the first `period` closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period of the highest high, lowest low and ATR (typically 22)
+    /// * `constant_multiplier` - Multiplier for the ATR (typically 3.0)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
+    ///
+    /// # Returns
+    ///
+    /// A vector of (long stop, short stop, position) tuples, the first one for the
+    /// `period`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.len()` != `highs.len()` != `lows.len()`
+    /// * `close.is_empty()`
+    /// * `period` == 0 or `period` > `close.len()`
+    /// * `constant_multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::Position;
+    ///
+    /// let highs = vec![102.0, 104.0, 105.0, 103.0, 100.0, 99.0];
+    /// let lows = vec![99.0, 101.0, 102.0, 99.0, 97.0, 96.0];
+    /// let close = vec![101.0, 103.0, 104.0, 100.0, 98.0, 97.0];
+    ///
+    /// let chandelier_exit =
+    ///     centaur_technical_indicators::volatility_indicators::bulk::chandelier_exit(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         3,
+    ///         1.0,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (102.0, 102.0, Position::Long),
+    ///         (101.66666666666667, 102.33333333333333, Position::Short),
+    ///         (101.66666666666667, 100.33333333333333, Position::Short),
+    ///         (99.66666666666667, 99.33333333333333, Position::Short)
+    ///     ],
+    ///     chandelier_exit
+    /// );
+    /// ```
+    pub fn chandelier_exit(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+        constant_multiplier: f64,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<(f64, f64, Position)>> {
+        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
+        assert_non_empty("close", close)?;
+        assert_period(period, close.len())?;
+        assert_positive("constant_multiplier", constant_multiplier)?;
+
+        let atr = average_true_range(close, highs, lows, constant_model_type, period)?;
+        let mut long_stops = Vec::with_capacity(atr.len());
+        let mut short_stops = Vec::with_capacity(atr.len());
+        for (i, atr) in atr.iter().enumerate() {
+            long_stops.push(max(&highs[i..i + period])? - constant_multiplier * atr);
+            short_stops.push(min(&lows[i..i + period])? + constant_multiplier * atr);
+        }
+        let positions = stop_positions(
+            &close[period - 1..],
+            &long_stops,
+            &short_stops,
+            initial_position(&close[..period])?,
+        );
+        Ok(long_stops
+            .into_iter()
+            .zip(short_stops)
+            .zip(positions)
+            .map(|((long_stop, short_stop), position)| (long_stop, short_stop, position))
+            .collect())
+    }
+
+    /// Calculates the Chande Kroll Stop
+    ///
+    /// First stops are a multiple of the ATR from the highest high and lowest low over
+    /// `atr_period`, then the long stop is the highest first long stop and the short
+    /// stop the lowest first short stop over `stop_period`. Positions flip as in
+    /// [`chandelier_exit`].
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `atr_period` - Period of the first stops and ATR (typically 10)
+    /// * `constant_multiplier` - Multiplier for the ATR (typically 1.0)
+    /// * `stop_period` - Period of the final stops (typically 9)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
+    ///
+    /// # Returns
+    ///
+    /// A vector of (long stop, short stop, position) tuples, the first one for the
+    /// `atr_period + stop_period - 1`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.len()` != `highs.len()` != `lows.len()`
+    /// * `close.is_empty()`
+    /// * `atr_period` == 0 or `stop_period` == 0
+    /// * `atr_period + stop_period - 1` > `close.len()`
+    /// * `constant_multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::Position;
+    ///
+    /// let highs = vec![102.0, 104.0, 105.0, 103.0, 100.0, 99.0];
+    /// let lows = vec![99.0, 101.0, 102.0, 99.0, 97.0, 96.0];
+    /// let close = vec![101.0, 103.0, 104.0, 100.0, 98.0, 97.0];
+    ///
+    /// let chande_kroll_stop =
+    ///     centaur_technical_indicators::volatility_indicators::bulk::chande_kroll_stop(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         3,
+    ///         1.0,
+    ///         2,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (102.0, 102.0, Position::Short),
+    ///         (101.66666666666667, 100.33333333333333, Position::Short),
+    ///         (101.66666666666667, 99.33333333333333, Position::Short)
+    ///     ],
+    ///     chande_kroll_stop
+    /// );
+    /// ```
+    pub fn chande_kroll_stop(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        atr_period: usize,
+        constant_multiplier: f64,
+        stop_period: usize,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<(f64, f64, Position)>> {
+        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
+        assert_non_empty("close", close)?;
+        assert_positive_usize("stop_period", stop_period)?;
+        assert_period(atr_period, close.len())?;
+        assert_period(atr_period + stop_period - 1, close.len())?;
+        assert_positive("constant_multiplier", constant_multiplier)?;
+
+        let atr = average_true_range(close, highs, lows, constant_model_type, atr_period)?;
+        let mut first_long_stops = Vec::with_capacity(atr.len());
+        let mut first_short_stops = Vec::with_capacity(atr.len());
+        for (i, atr) in atr.iter().enumerate() {
+            first_long_stops.push(max(&highs[i..i + atr_period])? - constant_multiplier * atr);
+            first_short_stops.push(min(&lows[i..i + atr_period])? + constant_multiplier * atr);
+        }
+        let long_stops = first_long_stops
+            .windows(stop_period)
+            .map(max)
+            .collect::<crate::Result<Vec<f64>>>()?;
+        let short_stops = first_short_stops
+            .windows(stop_period)
+            .map(min)
+            .collect::<crate::Result<Vec<f64>>>()?;
+
+        let first = atr_period + stop_period - 2;
+        let positions = stop_positions(
+            &close[first..],
+            &long_stops,
+            &short_stops,
+            initial_position(&close[..=first])?,
+        );
+        Ok(long_stops
+            .into_iter()
+            .zip(short_stops)
+            .zip(positions)
+            .map(|((long_stop, short_stop), position)| (long_stop, short_stop, position))
+            .collect())
+    }
+
+    /// Calculates an ATR trailing stop
+    ///
+    /// While long the stop trails a multiple of the ATR below the close and can only
+    /// rise; while short it trails above the close and can only fall. A close through
+    /// the previous stop flips the position and restarts the stop on the other side.
+    /// The first position follows the trend of the first `period` closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `highs` - Slice of highs
+    /// * `lows` - Slice of lows
+    /// * `close` - Slice of closing prices
+    /// * `period` - Period of the ATR (typically 14)
+    /// * `constant_multiplier` - Multiplier for the ATR (typically 3.0)
+    /// * `constant_model_type` - Variant of [`ConstantModelType`](crate::ConstantModelType) or any [`CentralTendency`] for the ATR
+    ///
+    /// # Returns
+    ///
+    /// A vector of (stop, position) tuples, the first one for the `period`th price
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * `close.len()` != `highs.len()` != `lows.len()`
+    /// * `close.is_empty()`
+    /// * `period` == 0 or `period` > `close.len()`
+    /// * `constant_multiplier` <= 0.0
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use centaur_technical_indicators::Position;
+    ///
+    /// let highs = vec![102.0, 104.0, 105.0, 103.0, 100.0, 99.0];
+    /// let lows = vec![99.0, 101.0, 102.0, 99.0, 97.0, 96.0];
+    /// let close = vec![101.0, 103.0, 104.0, 100.0, 98.0, 97.0];
+    ///
+    /// let atr_trailing_stop =
+    ///     centaur_technical_indicators::volatility_indicators::bulk::atr_trailing_stop(
+    ///         &highs,
+    ///         &lows,
+    ///         &close,
+    ///         3,
+    ///         1.0,
+    ///         centaur_technical_indicators::ConstantModelType::SimpleMovingAverage
+    ///     ).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (101.0, Position::Long),
+    ///         (103.33333333333333, Position::Short),
+    ///         (101.33333333333333, Position::Short),
+    ///         (100.33333333333333, Position::Short)
+    ///     ],
+    ///     atr_trailing_stop
+    /// );
+    /// ```
+    pub fn atr_trailing_stop(
+        highs: &[f64],
+        lows: &[f64],
+        close: &[f64],
+        period: usize,
+        constant_multiplier: f64,
+        constant_model_type: impl CentralTendency,
+    ) -> crate::Result<Vec<(f64, Position)>> {
+        assert_same_len(&[("close", close), ("highs", highs), ("lows", lows)])?;
+        assert_non_empty("close", close)?;
+        assert_period(period, close.len())?;
+        assert_positive("constant_multiplier", constant_multiplier)?;
+
+        let atr = average_true_range(close, highs, lows, constant_model_type, period)?;
+        let mut position = initial_position(&close[..period])?;
+        let mut stops: Vec<(f64, Position)> = Vec::with_capacity(atr.len());
+        for (i, atr) in atr.iter().enumerate() {
+            let price = close[i + period - 1];
+            let offset = constant_multiplier * atr;
+            let stop = match stops.last() {
+                None => match position {
+                    Position::Long => price - offset,
+                    Position::Short => price + offset,
+                },
+                Some(&(previous_stop, _)) => match position {
+                    Position::Long if price < previous_stop => {
+                        position = Position::Short;
+                        price + offset
+                    }
+                    Position::Long => previous_stop.max(price - offset),
+                    Position::Short if price > previous_stop => {
+                        position = Position::Long;
+                        price - offset
+                    }
+                    Position::Short => previous_stop.min(price + offset),
+                },
+            };
+            stops.push((stop, position));
+        }
+        Ok(stops)
+    }
+
+    /// Short if the closes trend down, long otherwise.
+    pub(crate) fn initial_position(close: &[f64]) -> crate::Result<Position> {
+        if overall_trend(close)?.0 < 0.0 {
+            Ok(Position::Short)
+        } else {
+            Ok(Position::Long)
+        }
+    }
+
+    /// Position at each close, flipping when the close crosses the previous bar's
+    /// stop for the current position.
+    fn stop_positions(
+        close: &[f64],
+        long_stops: &[f64],
+        short_stops: &[f64],
+        initial_position: Position,
+    ) -> Vec<Position> {
+        let mut position = initial_position;
+        let mut positions = Vec::with_capacity(long_stops.len());
+        positions.push(position);
+        for i in 1..long_stops.len() {
+            position = match position {
+                Position::Long if close[i] < long_stops[i - 1] => Position::Short,
+                Position::Short if close[i] > short_stops[i - 1] => Position::Long,
+                position => position,
+            };
+            positions.push(position);
+        }
+        positions
+    }
 }
 
 #[cfg(test)]
@@ -414,4 +748,107 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn bulk_chandelier_exit_flips_back_long() {
+        let highs = vec![102.0, 104.0, 105.0, 103.0, 100.0, 99.0, 104.0, 107.0];
+        let lows = vec![99.0, 101.0, 102.0, 99.0, 97.0, 96.0, 99.0, 104.0];
+        let close = vec![101.0, 103.0, 104.0, 100.0, 98.0, 97.0, 103.0, 106.0];
+        let positions: Vec<crate::Position> = bulk::chandelier_exit(
+            &highs,
+            &lows,
+            &close,
+            3,
+            1.0,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap()
+        .into_iter()
+        .map(|(_, _, position)| position)
+        .collect();
+        assert_eq!(
+            vec![
+                crate::Position::Long,
+                crate::Position::Short,
+                crate::Position::Short,
+                crate::Position::Short,
+                crate::Position::Long,
+                crate::Position::Long
+            ],
+            positions
+        );
+    }
+
+    #[test]
+    fn bulk_chandelier_exit_error() {
+        let highs = vec![102.0, 104.0, 105.0];
+        let lows = vec![99.0, 101.0, 102.0];
+        let close = vec![101.0, 103.0, 104.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::chandelier_exit(&highs, &lows, &close, 4, 1.0, model).is_err());
+        assert!(bulk::chandelier_exit(&highs, &lows, &close, 2, 0.0, model).is_err());
+        assert!(bulk::chandelier_exit(&highs, &lows[1..], &close, 2, 1.0, model).is_err());
+    }
+
+    #[test]
+    fn bulk_chande_kroll_stop_length() {
+        let highs = vec![102.0, 104.0, 105.0, 103.0, 100.0, 99.0, 104.0, 107.0];
+        let lows = vec![99.0, 101.0, 102.0, 99.0, 97.0, 96.0, 99.0, 104.0];
+        let close = vec![101.0, 103.0, 104.0, 100.0, 98.0, 97.0, 103.0, 106.0];
+        let result = bulk::chande_kroll_stop(
+            &highs,
+            &lows,
+            &close,
+            3,
+            1.0,
+            3,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(4, result.len());
+        assert_eq!(crate::Position::Long, result[3].2);
+    }
+
+    #[test]
+    fn bulk_chande_kroll_stop_error() {
+        let highs = vec![102.0, 104.0, 105.0, 103.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::chande_kroll_stop(&highs, &highs, &highs, 3, 1.0, 3, model).is_err());
+        assert!(bulk::chande_kroll_stop(&highs, &highs, &highs, 3, 1.0, 0, model).is_err());
+    }
+
+    #[test]
+    fn bulk_atr_trailing_stop_ratchets() {
+        // Rising closes with a constant range: the long stop can only rise
+        let highs = vec![101.0, 102.0, 103.0, 102.5, 104.0];
+        let lows = vec![99.0, 100.0, 101.0, 100.5, 102.0];
+        let close = vec![100.0, 101.0, 102.0, 101.5, 103.0];
+        let result = bulk::atr_trailing_stop(
+            &highs,
+            &lows,
+            &close,
+            2,
+            1.0,
+            crate::ConstantModelType::SimpleMovingAverage,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![
+                (99.0, crate::Position::Long),
+                (100.0, crate::Position::Long),
+                (100.0, crate::Position::Long),
+                (101.0, crate::Position::Long)
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn bulk_atr_trailing_stop_error() {
+        let highs = vec![101.0, 102.0];
+        let model = crate::ConstantModelType::SimpleMovingAverage;
+        assert!(bulk::atr_trailing_stop(&highs, &highs, &highs, 3, 1.0, model).is_err());
+        assert!(bulk::atr_trailing_stop(&highs, &highs, &highs, 2, -1.0, model).is_err());
+        assert!(bulk::atr_trailing_stop(&[], &[], &[], 1, 1.0, model).is_err());
+    }
 }